@@ -0,0 +1,177 @@
+//! Derive macros for the [`get_checked`](https://github.com/snaphat/get_checked-rs) crate.
+//!
+//! These macros are re-exported by `get_checked` when its `derive` feature is enabled, and
+//! should be used through it.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::{
+    Data, DeriveInput, Error, Fields, Ident, LitStr, Type, parse_macro_input, spanned::Spanned,
+};
+
+/// Derives `get_checked::ReadChecked` for a struct whose fields are all fixed-width integers.
+///
+/// The fields are read in declaration order from consecutive bytes, without padding. Multi-byte
+/// fields are little-endian unless the struct is annotated with `#[checked(endian = "big")]`.
+#[proc_macro_derive(ReadChecked, attributes(checked))]
+pub fn derive_read_checked(input: TokenStream) -> TokenStream
+{
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input).unwrap_or_else(Error::into_compile_error).into()
+}
+
+/// Expands the derive for `input`.
+fn expand(input: &DeriveInput) -> Result<TokenStream2, Error>
+{
+    let endian = endian(input)?;
+    let fields = match &input.data
+    {
+        | Data::Struct(data) => &data.fields,
+        | _ =>
+        {
+            return Err(Error::new(
+                Span::call_site(),
+                "ReadChecked can only be derived for structs",
+            ));
+        },
+    };
+
+    let mut offset = 0usize;
+    let mut reads = Vec::new();
+    for field in fields
+    {
+        let (size, read) = read_field(&field.ty, offset, &endian)?;
+        reads.push(read);
+        offset += size;
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let value = match fields
+    {
+        | Fields::Named(_) =>
+        {
+            let idents = fields.iter().map(|f| &f.ident);
+            quote!(#name { #(#idents: #reads),* })
+        },
+        | Fields::Unnamed(_) => quote!(#name(#(#reads),*)),
+        | Fields::Unit => quote!(#name),
+    };
+
+    // Fieldless structs still check that the (empty) range is in bounds, but read nothing.
+    let bytes = match reads.is_empty()
+    {
+        | true => quote!(_),
+        | false => quote!(bytes),
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::get_checked::ReadChecked for #name #ty_generics #where_clause
+        {
+            const SIZE: usize = #offset;
+
+            #[allow(unused_imports)]
+            fn read_checked(
+                buf: &[u8],
+                offset: usize,
+            ) -> ::core::result::Result<Self, ::get_checked::IndexError>
+            {
+                use ::get_checked::{GetChecked, ReadEndianChecked};
+
+                let end = offset.checked_add(#offset).ok_or_else(|| {
+                    ::get_checked::IndexError::from(::get_checked::IndexErrorKind::EndOverflow())
+                })?;
+                let #bytes: &[u8] = buf.get_checked(offset..end)?;
+                ::core::result::Result::Ok(#value)
+            }
+        }
+    })
+}
+
+/// Parses the byte order from the `#[checked(endian = "...")]` attribute, defaulting to little.
+fn endian(input: &DeriveInput) -> Result<TokenStream2, Error>
+{
+    let mut endian = quote!(::get_checked::Endian::Little);
+    for attr in input.attrs.iter().filter(|attr| attr.path().is_ident("checked"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if !meta.path.is_ident("endian")
+            {
+                return Err(meta.error("expected `endian`"));
+            }
+            let value: LitStr = meta.value()?.parse()?;
+            endian = match value.value().as_str()
+            {
+                | "little" => quote!(::get_checked::Endian::Little),
+                | "big" => quote!(::get_checked::Endian::Big),
+                | "native" => quote!(::get_checked::Endian::NATIVE),
+                | _ =>
+                {
+                    return Err(Error::new(
+                        value.span(),
+                        "expected \"little\", \"big\" or \"native\"",
+                    ));
+                },
+            };
+            Ok(())
+        })?;
+    }
+    Ok(endian)
+}
+
+/// Returns the size of a field of type `ty` and an expression reading it from `bytes` at
+/// `offset`.
+fn read_field(
+    ty: &Type,
+    offset: usize,
+    endian: &TokenStream2,
+) -> Result<(usize, TokenStream2), Error>
+{
+    let name = match ty
+    {
+        | Type::Path(path) if path.qself.is_none() =>
+        {
+            path.path.get_ident().map(ToString::to_string)
+        },
+        | _ => None,
+    };
+    let (size, method, cast) = match name.as_deref()
+    {
+        | Some("u8") => (1, None, None),
+        | Some("i8") => (1, None, Some("i8")),
+        | Some("u16") => (2, Some("read_u16_checked"), None),
+        | Some("i16") => (2, Some("read_u16_checked"), Some("i16")),
+        | Some("u32") => (4, Some("read_u32_checked"), None),
+        | Some("i32") => (4, Some("read_u32_checked"), Some("i32")),
+        | Some("u64") => (8, Some("read_u64_checked"), None),
+        | Some("i64") => (8, Some("read_u64_checked"), Some("i64")),
+        | _ =>
+        {
+            return Err(Error::new(
+                ty.span(),
+                "ReadChecked fields must be one of u8, i8, u16, i16, u32, i32, u64 or i64",
+            ));
+        },
+    };
+
+    let read = match method
+    {
+        | Some(method) =>
+        {
+            let method = Ident::new(method, Span::call_site());
+            quote!(bytes.#method(#offset, #endian)?)
+        },
+        | None => quote!(*bytes.get_checked(#offset)?),
+    };
+    let read = match cast
+    {
+        | Some(cast) =>
+        {
+            let cast = Ident::new(cast, Span::call_site());
+            quote!((#read as #cast))
+        },
+        | None => read,
+    };
+    Ok((size, read))
+}
@@ -0,0 +1,90 @@
+//! Fuzzes sequences of cursor operations, including nested sub-cursors, checking that they never
+//! panic, that the position stays within the window, and that failed operations leave the
+//! cursor where it was.
+#![no_main]
+
+use arbitrary::Arbitrary;
+use get_checked::{Checkpoint, IndexCursor};
+use libfuzzer_sys::fuzz_target;
+
+#[derive(Debug, Arbitrary)]
+enum Op
+{
+    Advance(usize),
+    Lookahead(usize),
+    SubCursor(usize),
+    Return,
+    Checkpoint,
+    Rollback(u8),
+}
+
+#[derive(Debug, Arbitrary)]
+struct Input
+{
+    len: u8,
+    ops: Vec<Op>,
+}
+
+fuzz_target!(|input: Input| {
+    // The cursors entered so far, innermost last, and every checkpoint taken from any of them.
+    let mut cursors = vec![IndexCursor::new(usize::from(input.len))];
+    let mut checkpoints: Vec<Checkpoint> = Vec::new();
+
+    for op in input.ops
+    {
+        let cursor = cursors.last_mut().unwrap();
+        let before = *cursor;
+        let (pos, window) = (before.position(), before.window());
+
+        match op
+        {
+            | Op::Advance(n) =>
+            {
+                let end = pos.checked_add(n).filter(|end| *end <= window.end);
+                match cursor.advance_checked(n)
+                {
+                    | Ok(new) => assert_eq!((Some(new), cursor.position()), (end, new)),
+                    | Err(_) => assert_eq!((end, *cursor), (None, before)),
+                }
+            },
+            | Op::Lookahead(k) =>
+            {
+                let index = pos.checked_add(k).filter(|index| *index < window.end);
+                assert_eq!(cursor.lookahead_checked(k).ok(), index);
+                assert_eq!(*cursor, before);
+            },
+            | Op::SubCursor(n) => match cursor.sub_cursor_checked(n)
+            {
+                | Ok(sub) =>
+                {
+                    assert_eq!((sub.window(), sub.position()), (pos..cursor.position(), pos));
+                    assert!(sub.window().end <= window.end);
+                    cursors.push(sub);
+                },
+                | Err(_) => assert_eq!(*cursor, before),
+            },
+            | Op::Return =>
+            {
+                if cursors.len() > 1
+                {
+                    cursors.pop();
+                }
+            },
+            | Op::Checkpoint => checkpoints.push(cursor.checkpoint()),
+            | Op::Rollback(i) =>
+            {
+                if let Some(checkpoint) = checkpoints.get(usize::from(i) % checkpoints.len().max(1))
+                {
+                    cursor.rollback(*checkpoint);
+                    let clamped = checkpoint.position().max(window.start).min(window.end);
+                    assert_eq!((cursor.position(), cursor.window()), (clamped, window));
+                }
+            },
+        }
+
+        let cursor = cursors.last().unwrap();
+        let window = cursor.window();
+        assert!(window.start <= cursor.position() && cursor.position() <= window.end);
+        assert_eq!((cursor.len(), cursor.is_empty()), (window.len(), window.is_empty()));
+    }
+});
@@ -0,0 +1,59 @@
+//! Fuzzes every index form through `get_checked`, `get_checked_mut` and `try_range`, checking
+//! that they never panic and agree with std `get`.
+#![no_main]
+
+use std::ops::Bound;
+
+use arbitrary::Arbitrary;
+use get_checked::{GetChecked, try_range};
+use libfuzzer_sys::fuzz_target;
+
+#[derive(Debug, Arbitrary)]
+enum IndexForm
+{
+    Index(usize),
+    Range(usize, usize),
+    RangeFrom(usize),
+    RangeFull,
+    RangeInclusive(usize, usize),
+    RangeTo(usize),
+    RangeToInclusive(usize),
+    Bounds(Bound<usize>, Bound<usize>),
+}
+
+#[derive(Debug, Arbitrary)]
+struct Input
+{
+    len:   u8,
+    index: IndexForm,
+}
+
+/// Checks that `get_checked` and `get_checked_mut` succeed exactly when `get` does, returning
+/// the same elements.
+macro_rules! check {
+    ($v:expr, $index:expr) => {{
+        let mut v = $v;
+        let expected = v.get($index).map(|s| s.to_owned());
+        assert_eq!(v.get_checked($index).ok().map(|s| s.to_owned()), expected);
+        assert_eq!(v.get_checked_mut($index).ok().map(|s| s.to_owned()), expected);
+    }};
+}
+
+fuzz_target!(|input: Input| {
+    let v: Vec<u8> = (0..input.len).collect();
+    match input.index
+    {
+        | IndexForm::Index(i) => check!(v, i),
+        | IndexForm::Range(a, b) => check!(v, a..b),
+        | IndexForm::RangeFrom(a) => check!(v, a..),
+        | IndexForm::RangeFull => check!(v, ..),
+        | IndexForm::RangeInclusive(a, b) => check!(v, a..=b),
+        | IndexForm::RangeTo(b) => check!(v, ..b),
+        | IndexForm::RangeToInclusive(b) => check!(v, ..=b),
+        | IndexForm::Bounds(start, end) =>
+        {
+            let range = try_range((start, end), v.len()).ok();
+            assert_eq!(range.map(|r| &v[r]), v.get((start, end)));
+        },
+    }
+});
@@ -0,0 +1,60 @@
+//! Fuzzes the grid views over image buffers, checking that they never panic and return the
+//! same bytes as computing the pixel offset by hand and slicing with std `get`.
+#![no_main]
+
+use arbitrary::Arbitrary;
+use get_checked::{GetPixelChecked, ImageView};
+use libfuzzer_sys::fuzz_target;
+
+#[derive(Debug, Arbitrary)]
+struct Input
+{
+    len:             u16,
+    width:           u8,
+    height:          u8,
+    pitch:           u16,
+    bytes_per_pixel: u8,
+    x:               usize,
+    y:               usize,
+}
+
+/// Returns the bytes of pixel (`x`, `y`) in an image with rows `pitch` bytes apart, or `None`
+/// if it is outside the `width` by `height` image or the buffer.
+fn expected(
+    bytes: &[u8],
+    (x, y): (usize, usize),
+    (width, height, pitch, bpp): (usize, usize, usize, usize),
+) -> Option<&[u8]>
+{
+    if bpp == 0 || x >= width || y >= height
+    {
+        return None;
+    }
+    let start = y.checked_mul(pitch)?.checked_add(x.checked_mul(bpp)?)?;
+    bytes.get(start..start.checked_add(bpp)?)
+}
+
+fuzz_target!(|input: Input| {
+    let bytes: Vec<u8> = (0..input.len).map(|n| n as u8).collect();
+    let (x, y) = (input.x, input.y);
+    let width = usize::from(input.width);
+    let height = usize::from(input.height);
+    let pitch = usize::from(input.pitch);
+    let bpp = usize::from(input.bytes_per_pixel);
+
+    // The packed form has a pitch of one row and as many rows as fit in the buffer.
+    let packed = match width * bpp
+    {
+        | 0 => None,
+        | row => expected(&bytes, (x, y), (width, bytes.len() / row, row, bpp)),
+    };
+    assert_eq!(bytes.pixel_checked(x, y, width, bpp).ok(), packed);
+
+    if let Ok(view) = ImageView::new(&bytes, width, height, pitch, bpp)
+    {
+        assert!(width * bpp <= pitch);
+        let pixel = view.pixel_checked(x, y).ok();
+        assert_eq!(pixel, expected(&bytes, (x, y), (width, height, pitch, bpp)));
+        assert_eq!(view.row_checked(y).is_ok(), y < height);
+    }
+});
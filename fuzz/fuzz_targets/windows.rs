@@ -0,0 +1,46 @@
+//! Fuzzes strided windows and frames, checking that they never panic and yield the same
+//! elements as slicing with std `get`.
+#![no_main]
+
+use arbitrary::Arbitrary;
+use get_checked::{GetFrameChecked, GetWindowChecked};
+use libfuzzer_sys::fuzz_target;
+
+#[derive(Debug, Arbitrary)]
+struct Input
+{
+    len:    u8,
+    start:  usize,
+    size:   usize,
+    stride: usize,
+    frame:  usize,
+}
+
+fuzz_target!(|input: Input| {
+    let v: Vec<u8> = (0..input.len).collect();
+
+    let first = input.start.checked_add(input.size).and_then(|end| v.get(input.start..end));
+    match v.window_by_checked(input.start, input.size, input.stride)
+    {
+        | Ok(windows) =>
+        {
+            assert_eq!(windows.clone().next(), first);
+            for (n, window) in windows.enumerate()
+            {
+                let start = input.start + n * input.stride;
+                assert_eq!(Some(window), v.get(start..start + input.size));
+            }
+        },
+        | Err(_) => assert!(input.size == 0 || input.stride == 0 || first.is_none()),
+    }
+
+    let expected = match input.size
+    {
+        | 0 => None,
+        | channels => input
+            .frame
+            .checked_mul(channels)
+            .and_then(|start| v.get(start..start.checked_add(channels)?)),
+    };
+    assert_eq!(v.frame_checked(input.frame, input.size).ok(), expected);
+});
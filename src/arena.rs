@@ -0,0 +1,158 @@
+use std::{fmt, marker::PhantomData};
+
+use crate::{
+    Error, IndexError,
+    IndexErrorKind::{Bounds, StaleKey},
+};
+
+/// An append-only arena handing out typed [`Handle`]s instead of references.
+///
+/// Clearing the arena starts a new generation, after which handles issued before the clear are
+/// reported as stale rather than silently aliasing newly pushed values.
+///
+/// Only available when the `no_std` feature is disabled.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{ArenaSlice, IndexErrorKind};
+/// let mut arena = ArenaSlice::new();
+/// let a = arena.push("a");
+/// assert_eq!(arena.get_checked(a), Ok(&"a"));
+///
+/// arena.clear();
+/// arena.push("b");
+/// assert_eq!(*arena.get_checked(a).unwrap_err().kind(), IndexErrorKind::StaleKey(0));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ArenaSlice<T>
+{
+    items:      Vec<T>,
+    generation: u64,
+}
+
+/// A typed handle to a value in an [`ArenaSlice`].
+pub struct Handle<T>
+{
+    index:      usize,
+    generation: u64,
+    marker:     PhantomData<fn() -> T>,
+}
+
+impl<T> Handle<T>
+{
+    /// Returns the position of the value within its arena.
+    pub fn index(&self) -> usize
+    {
+        self.index
+    }
+}
+
+impl<T> Clone for Handle<T>
+{
+    fn clone(&self) -> Self
+    {
+        *self
+    }
+}
+
+impl<T> Copy for Handle<T> {}
+
+impl<T> PartialEq for Handle<T>
+{
+    fn eq(&self, other: &Self) -> bool
+    {
+        (self.index, self.generation) == (other.index, other.generation)
+    }
+}
+
+impl<T> Eq for Handle<T> {}
+
+impl<T> fmt::Debug for Handle<T>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        f.debug_struct("Handle")
+            .field("index", &self.index)
+            .field("generation", &self.generation)
+            .finish()
+    }
+}
+
+impl<T> ArenaSlice<T>
+{
+    /// Creates an empty arena.
+    pub fn new() -> Self
+    {
+        ArenaSlice { items: Vec::new(), generation: 0 }
+    }
+
+    /// Returns the number of values in the arena.
+    pub fn len(&self) -> usize
+    {
+        self.items.len()
+    }
+
+    /// Returns `true` if the arena holds no values.
+    pub fn is_empty(&self) -> bool
+    {
+        self.items.is_empty()
+    }
+
+    /// Appends `value` and returns its handle.
+    pub fn push(&mut self, value: T) -> Handle<T>
+    {
+        self.items.push(value);
+        Handle {
+            index:      self.items.len() - 1,
+            generation: self.generation,
+            marker:     PhantomData,
+        }
+    }
+
+    /// Removes all values and starts a new generation, invalidating every issued handle.
+    pub fn clear(&mut self)
+    {
+        self.items.clear();
+        self.generation += 1;
+    }
+
+    /// Returns the values of the arena in insertion order.
+    pub fn as_slice(&self) -> &[T]
+    {
+        &self.items
+    }
+
+    /// Returns a reference to the value for `handle`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `StaleKey` if `handle` was issued before the arena was
+    /// last cleared, or `Bounds` if it does not refer to a value in this arena.
+    pub fn get_checked(&self, handle: Handle<T>) -> Result<&T, IndexError>
+    {
+        let index = self.validate(handle)?;
+        Ok(&self.items[index])
+    }
+
+    /// Returns a mutable reference to the value for `handle`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `StaleKey` if `handle` was issued before the arena was
+    /// last cleared, or `Bounds` if it does not refer to a value in this arena.
+    pub fn get_checked_mut(&mut self, handle: Handle<T>) -> Result<&mut T, IndexError>
+    {
+        let index = self.validate(handle)?;
+        Ok(&mut self.items[index])
+    }
+
+    fn validate(&self, handle: Handle<T>) -> Result<usize, IndexError>
+    {
+        match handle.index
+        {
+            | _ if handle.generation != self.generation => Err(Error::new(StaleKey(handle.index))),
+            | _ if handle.index >= self.len() => Err(Error::new(Bounds(handle.index, self.len()))),
+            | _ => Ok(handle.index),
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use core::{ops::RangeBounds, str};
+
+use crate::{Error, IndexError, IndexErrorKind::NotAscii, try_range};
+
+/// Trait adding ASCII-validated checked slicing to byte slices.
+pub trait GetAsciiChecked
+{
+    /// Returns the bytes within `range` as a `&str` if they are all ASCII.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] describing the invalid range as
+    /// [`get_checked`](crate::GetChecked::get_checked) would, or of kind `NotAscii` containing
+    /// the index of the first non-ASCII byte within the slice.
+    ///
+    /// # Examples
+    /// ```
+    /// # use get_checked::{GetAsciiChecked, IndexErrorKind};
+    /// let v = "GET /caf\u{e9}".as_bytes();
+    /// assert_eq!(v.get_ascii_checked(..3), Ok("GET"));
+    /// assert_eq!(*v.get_ascii_checked(4..).unwrap_err().kind(), IndexErrorKind::NotAscii(8));
+    /// ```
+    fn get_ascii_checked<R>(&self, range: R) -> Result<&str, IndexError>
+    where R: RangeBounds<usize>;
+}
+
+impl GetAsciiChecked for [u8]
+{
+    fn get_ascii_checked<R>(&self, range: R) -> Result<&str, IndexError>
+    where R: RangeBounds<usize>
+    {
+        let range = try_range(range, self.len())?;
+        let bytes = &self[range.clone()];
+
+        match bytes.iter().position(|b| !b.is_ascii())
+        {
+            | Some(i) => Err(Error::new(NotAscii(range.start + i))),
+            | None => Ok(unsafe { str::from_utf8_unchecked(bytes) }),
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use core::sync::atomic::Ordering;
+#[cfg(target_has_atomic = "8")]
+use core::sync::atomic::{AtomicI8, AtomicU8};
+#[cfg(target_has_atomic = "16")]
+use core::sync::atomic::{AtomicI16, AtomicU16};
+#[cfg(target_has_atomic = "32")]
+use core::sync::atomic::{AtomicI32, AtomicU32};
+#[cfg(target_has_atomic = "64")]
+use core::sync::atomic::{AtomicI64, AtomicU64};
+#[cfg(target_has_atomic = "ptr")]
+use core::sync::atomic::{AtomicIsize, AtomicUsize};
+
+use crate::IndexError;
+
+/// Trait adding checked atomic operations to slices of integer atomics.
+///
+/// Each method performs the same operation as the corresponding method on the atomic type, but
+/// returns an [`IndexError`] of kind `Bounds` instead of panicking if `index` is out of bounds.
+///
+/// Implemented for each atomic width the target supports compare-and-swap for, as reported by
+/// `cfg(target_has_atomic)`, since `fetch_add` needs it. Targets with only atomic loads and
+/// stores, such as `thumbv6m`, have no implementations.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{GetCheckedAtomic, IndexErrorKind};
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+///
+/// let counters = [AtomicUsize::new(0), AtomicUsize::new(0)];
+/// assert_eq!(counters.fetch_add_checked(1, 5, Ordering::Relaxed), Ok(0));
+/// assert_eq!(counters.load_checked(1, Ordering::Relaxed), Ok(5));
+///
+/// let err = counters.store_checked(2, 1, Ordering::Relaxed).unwrap_err();
+/// assert_eq!(*err.kind(), IndexErrorKind::Bounds(2, 2));
+/// ```
+pub trait GetCheckedAtomic
+{
+    /// The integer type stored in each atomic.
+    type Value;
+
+    /// Loads the value at `index`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Bounds` if `index` is out of bounds.
+    fn load_checked(&self, index: usize, order: Ordering) -> Result<Self::Value, IndexError>;
+
+    /// Stores `val` at `index`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Bounds` if `index` is out of bounds.
+    fn store_checked(
+        &self,
+        index: usize,
+        val: Self::Value,
+        order: Ordering,
+    ) -> Result<(), IndexError>;
+
+    /// Adds `val` to the value at `index`, wrapping on overflow, and returns the previous value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Bounds` if `index` is out of bounds.
+    fn fetch_add_checked(
+        &self,
+        index: usize,
+        val: Self::Value,
+        order: Ordering,
+    ) -> Result<Self::Value, IndexError>;
+}
+
+macro_rules! impl_get_checked_atomic {
+    ($($width:literal: $atomic:ty => $value:ty),* $(,)?) => {$(
+        #[cfg(target_has_atomic = $width)]
+        impl GetCheckedAtomic for [$atomic]
+        {
+            type Value = $value;
+
+            fn load_checked(&self, index: usize, order: Ordering) -> Result<$value, IndexError>
+            {
+                Ok(crate::GetChecked::get_checked(self, index)?.load(order))
+            }
+
+            fn store_checked(&self, index: usize, val: $value, order: Ordering)
+                -> Result<(), IndexError>
+            {
+                crate::GetChecked::get_checked(self, index)?.store(val, order);
+                Ok(())
+            }
+
+            fn fetch_add_checked(&self, index: usize, val: $value, order: Ordering)
+                -> Result<$value, IndexError>
+            {
+                Ok(crate::GetChecked::get_checked(self, index)?.fetch_add(val, order))
+            }
+        }
+    )*};
+}
+
+impl_get_checked_atomic! {
+    "8": AtomicU8 => u8,
+    "16": AtomicU16 => u16,
+    "32": AtomicU32 => u32,
+    "64": AtomicU64 => u64,
+    "ptr": AtomicUsize => usize,
+    "8": AtomicI8 => i8,
+    "16": AtomicI16 => i16,
+    "32": AtomicI32 => i32,
+    "64": AtomicI64 => i64,
+    "ptr": AtomicIsize => isize,
+}
@@ -0,0 +1,187 @@
+use core::ops::{self, Bound, RangeBounds};
+
+use crate::{
+    Error, IndexError,
+    IndexErrorKind::{EndOverflow, EndRange, Order, StartOverflow, StartRange},
+};
+
+/// Resolves a pair of range bounds into an exclusive `(start, end)` pair, without validating it
+/// against a slice.
+///
+/// An unbounded start resolves to `0` and an unbounded end resolves to `len`.
+///
+/// # Errors
+///
+/// Returns an [`IndexError`] of kind `StartOverflow` if an excluded start is [`usize::MAX`], or
+/// `EndOverflow` if an included end is [`usize::MAX`].
+///
+/// # Examples
+/// ```
+/// # use get_checked::resolve_bounds;
+/// use std::ops::{Bound, RangeBounds};
+///
+/// let r = 2..=4;
+/// assert_eq!(resolve_bounds(r.start_bound(), r.end_bound(), 10), Ok((2, 5)));
+/// assert!(resolve_bounds(Bound::Unbounded, Bound::Included(&usize::MAX), 10).is_err());
+/// ```
+#[inline]
+pub fn resolve_bounds(
+    start: Bound<&usize>,
+    end: Bound<&usize>,
+    len: usize,
+) -> Result<(usize, usize), IndexError>
+{
+    let start = match start
+    {
+        | Bound::Included(x) => *x,
+        | Bound::Excluded(x) => x.checked_add(1).ok_or(Error::new(StartOverflow()))?,
+        | Bound::Unbounded => 0,
+    };
+
+    let end = match end
+    {
+        | Bound::Included(x) => x.checked_add(1).ok_or(Error::new(EndOverflow()))?,
+        | Bound::Excluded(x) => *x,
+        | Bound::Unbounded => len,
+    };
+
+    Ok((start, end))
+}
+
+/// Converts any [`RangeBounds`] into a concrete [`Range`](ops::Range) that is valid for a slice
+/// of length `len`.
+///
+/// This performs the same validation as the builtin [`GetCheckedSliceIndex`] implementations,
+/// allowing custom index types to produce consistent errors.
+///
+/// # Errors
+///
+/// Returns an [`IndexError`] describing the first violated constraint, exactly as
+/// [`get_checked`] would for the same range.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{try_range, GetCheckedSliceIndex, IndexError};
+/// /// A range of records, each four bytes wide.
+/// struct Records(std::ops::Range<usize>);
+///
+/// impl GetCheckedSliceIndex<[u8]> for Records
+/// {
+///     type Output = [u8];
+///
+///     fn get_checked(self, slice: &[u8]) -> Result<&[u8], IndexError>
+///     {
+///         let r = try_range(self.0.start * 4..self.0.end * 4, slice.len())?;
+///         Ok(&slice[r])
+///     }
+///
+///     fn get_checked_mut(self, slice: &mut [u8]) -> Result<&mut [u8], IndexError>
+///     {
+///         let r = try_range(self.0.start * 4..self.0.end * 4, slice.len())?;
+///         Ok(&mut slice[r])
+///     }
+/// }
+///
+/// let v = [0u8; 8];
+/// assert_eq!(Records(1..2).get_checked(&v).unwrap().len(), 4);
+/// assert_eq!(
+///     Records(1..3).get_checked(&v).unwrap_err().to_string(),
+///     "range end index 12 out of range for slice of length 8"
+/// );
+/// ```
+///
+/// [`GetCheckedSliceIndex`]: crate::GetCheckedSliceIndex
+/// [`get_checked`]:          crate::GetChecked::get_checked
+#[inline]
+pub fn try_range<R>(range: R, len: usize) -> Result<ops::Range<usize>, IndexError>
+where R: RangeBounds<usize>
+{
+    let start = match range.start_bound()
+    {
+        | Bound::Included(x) => *x,
+        | Bound::Excluded(x) => x.checked_add(1).ok_or(Error::new(StartOverflow()))?,
+        | Bound::Unbounded => 0,
+    };
+
+    // An inclusive end is reported as given, and an inclusive end of `usize::MAX` is out of
+    // range for every slice, including zero-sized slices of length `usize::MAX`.
+    let (end, exclusive) = match range.end_bound()
+    {
+        | Bound::Included(x) => (*x, x.checked_add(1)),
+        | Bound::Excluded(x) => (*x, Some(*x)),
+        | Bound::Unbounded => (len, Some(len)),
+    };
+
+    match exclusive
+    {
+        | _ if start > len => Err(Error::new(StartRange(start, len))),
+        | Some(exclusive) if exclusive > len => Err(Error::new(EndRange(end, len))),
+        | None => Err(Error::new(EndRange(end, len))),
+        | Some(exclusive) if start > exclusive => Err(Error::new(Order(start, exclusive))),
+        | Some(exclusive) => Ok(start..exclusive),
+    }
+    .map_err(|e| e.with_range(start, exclusive.unwrap_or(end), len))
+}
+
+/// Returns the intersection of two ranges after validating each against a slice of length
+/// `len`, or `None` if they share no elements.
+///
+/// # Errors
+///
+/// Returns an [`IndexError`] describing the first invalid range as [`try_range`] would.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{overlap_checked, IndexErrorKind};
+/// assert_eq!(overlap_checked(2..6, 4.., 8), Ok(Some(4..6)));
+/// assert_eq!(overlap_checked(..2, 2..4, 8), Ok(None));
+///
+/// let err = overlap_checked(2..6, 4..9, 8).unwrap_err();
+/// assert_eq!(*err.kind(), IndexErrorKind::EndRange(9, 8));
+/// ```
+#[inline]
+pub fn overlap_checked<A, B>(
+    a: A,
+    b: B,
+    len: usize,
+) -> Result<Option<ops::Range<usize>>, IndexError>
+where
+    A: RangeBounds<usize>,
+    B: RangeBounds<usize>,
+{
+    let (a, b) = (try_range(a, len)?, try_range(b, len)?);
+    let overlap = a.start.max(b.start)..a.end.min(b.end);
+    Ok(Some(overlap).filter(|r| r.start < r.end))
+}
+
+/// Returns the union of two ranges after validating each against a slice of length `len`, or
+/// `None` if a gap separates them. An empty range does not contribute to the union.
+///
+/// # Errors
+///
+/// Returns an [`IndexError`] describing the first invalid range as [`try_range`] would.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{union_checked, IndexErrorKind};
+/// assert_eq!(union_checked(2..4, 4..6, 8), Ok(Some(2..6)));
+/// assert_eq!(union_checked(..2, 3..4, 8), Ok(None));
+///
+/// let err = union_checked(2..4, 6..3, 8).unwrap_err();
+/// assert_eq!(*err.kind(), IndexErrorKind::Order(6, 3));
+/// ```
+#[inline]
+pub fn union_checked<A, B>(a: A, b: B, len: usize) -> Result<Option<ops::Range<usize>>, IndexError>
+where
+    A: RangeBounds<usize>,
+    B: RangeBounds<usize>,
+{
+    let (a, b) = (try_range(a, len)?, try_range(b, len)?);
+    Ok(match (a.is_empty(), b.is_empty())
+    {
+        | (true, _) => Some(b),
+        | (_, true) => Some(a),
+        | _ if a.end < b.start || b.end < a.start => None,
+        | _ => Some(a.start.min(b.start)..a.end.max(b.end)),
+    })
+}
@@ -0,0 +1,81 @@
+use core::ops::RangeBounds;
+
+use bytes::{Bytes, BytesMut};
+
+use crate::{GetChecked, GetCheckedMut, IndexError, try_range};
+
+/// Checked indexing over the contents of a `Bytes` buffer.
+///
+/// Only available with the `bytes` feature.
+impl GetChecked<u8> for Bytes
+{
+    type Target = [u8];
+
+    #[inline]
+    fn checked_target(&self) -> &[u8]
+    {
+        self
+    }
+}
+
+/// Checked indexing over the contents of a `BytesMut` buffer.
+///
+/// Only available with the `bytes` feature.
+impl GetChecked<u8> for BytesMut
+{
+    type Target = [u8];
+
+    #[inline]
+    fn checked_target(&self) -> &[u8]
+    {
+        self
+    }
+}
+
+impl GetCheckedMut<u8> for BytesMut
+{
+    #[inline]
+    fn checked_target_mut(&mut self) -> &mut [u8]
+    {
+        self
+    }
+}
+
+/// Trait adding checked zero-copy slicing to `Bytes`.
+///
+/// Only available with the `bytes` feature.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{GetCheckedBytes, IndexErrorKind};
+/// use bytes::Bytes;
+///
+/// let frame = Bytes::from_static(b"\x00\x05hello");
+///
+/// let payload = frame.slice_checked(2..).unwrap();
+/// assert_eq!(payload, "hello");
+///
+/// let err = frame.slice_checked(2..9).unwrap_err();
+/// assert_eq!(*err.kind(), IndexErrorKind::EndRange(9, 7));
+/// ```
+pub trait GetCheckedBytes
+{
+    /// Returns a `Bytes` sharing the subslice corresponding to `range`, without copying.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] describing the invalid range as
+    /// [`get_checked`](crate::GetChecked::get_checked) would.
+    fn slice_checked<R>(&self, range: R) -> Result<Bytes, IndexError>
+    where R: RangeBounds<usize>;
+}
+
+impl GetCheckedBytes for Bytes
+{
+    #[inline]
+    fn slice_checked<R>(&self, range: R) -> Result<Bytes, IndexError>
+    where R: RangeBounds<usize>
+    {
+        Ok(self.slice(try_range(range, self.len())?))
+    }
+}
@@ -0,0 +1,99 @@
+use core::cell::Cell;
+
+use crate::{GetChecked, IndexError};
+
+/// Trait adding checked writes through shared references to slices of [`Cell`]s.
+///
+/// A `&[Cell<T>]` is indexed by the [`GetChecked`] implementation for slices, and a
+/// `&Cell<[T]>` by the implementation below, both returning the cell at an index. The methods of
+/// this trait read or write the value inside that cell, returning an [`IndexError`] of kind
+/// `Bounds` instead of panicking if `index` is out of bounds.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{GetChecked, GetCheckedCell, IndexErrorKind};
+/// use std::cell::Cell;
+///
+/// let mut parents = [0, 0, 1];
+/// let parents = Cell::from_mut(&mut parents[..]).as_slice_of_cells();
+///
+/// assert_eq!(parents.replace_checked(2, 0), Ok(1));
+/// assert_eq!(parents.set_checked(1, 2), Ok(()));
+/// assert_eq!(parents.get_checked(1).map(Cell::get), Ok(2));
+///
+/// let err = parents.set_checked(3, 0).unwrap_err();
+/// assert_eq!(*err.kind(), IndexErrorKind::Bounds(3, 3));
+/// ```
+pub trait GetCheckedCell<T>
+{
+    /// Returns a copy of the value at `index`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Bounds` if `index` is out of bounds.
+    fn get_value_checked(&self, index: usize) -> Result<T, IndexError>
+    where T: Copy;
+
+    /// Sets the value at `index` to `value`, dropping the previous value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Bounds` if `index` is out of bounds, in which case
+    /// `value` is dropped.
+    fn set_checked(&self, index: usize, value: T) -> Result<(), IndexError>;
+
+    /// Replaces the value at `index` with `value`, returning the previous value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Bounds` if `index` is out of bounds, in which case
+    /// `value` is dropped.
+    fn replace_checked(&self, index: usize, value: T) -> Result<T, IndexError>;
+}
+
+impl<T> GetCheckedCell<T> for [Cell<T>]
+{
+    #[inline]
+    fn get_value_checked(&self, index: usize) -> Result<T, IndexError>
+    where T: Copy
+    {
+        Ok(self.get_checked(index)?.get())
+    }
+
+    #[inline]
+    fn set_checked(&self, index: usize, value: T) -> Result<(), IndexError>
+    {
+        self.get_checked(index)?.set(value);
+        Ok(())
+    }
+
+    #[inline]
+    fn replace_checked(&self, index: usize, value: T) -> Result<T, IndexError>
+    {
+        Ok(self.get_checked(index)?.replace(value))
+    }
+}
+
+/// Indexes a `Cell<[T]>` as the slice of cells returned by [`Cell::as_slice_of_cells`].
+///
+/// # Examples
+/// ```
+/// # use get_checked::GetChecked;
+/// use std::cell::Cell;
+///
+/// let mut bytes = [1, 2, 3];
+/// let cells = Cell::from_mut(&mut bytes[..]);
+/// cells.get_checked(0).unwrap().set(4);
+/// assert!(cells.get_checked(1..4).is_err());
+/// assert_eq!(bytes, [4, 2, 3]);
+/// ```
+impl<T> GetChecked<Cell<T>> for Cell<[T]>
+{
+    type Target = [Cell<T>];
+
+    #[inline]
+    fn checked_target(&self) -> &[Cell<T>]
+    {
+        self.as_slice_of_cells()
+    }
+}
@@ -0,0 +1,85 @@
+use crc::Crc;
+
+use crate::{GetChecked, GetCheckedSliceIndex, IndexError};
+
+/// A CRC algorithm from the `crc` crate that can checksum a byte slice.
+///
+/// Implemented for [`crc::Crc`] of every width with its default implementation.
+///
+/// Only available with the `crc` feature.
+pub trait CrcAlgorithm
+{
+    /// The type of the computed checksum.
+    type Value;
+
+    /// Computes the checksum of `bytes`.
+    fn checksum(&self, bytes: &[u8]) -> Self::Value;
+}
+
+macro_rules! impl_crc_algorithm {
+    ($($width:ty),* $(,)?) => {$(
+        impl CrcAlgorithm for Crc<$width>
+        {
+            type Value = $width;
+
+            #[inline]
+            fn checksum(&self, bytes: &[u8]) -> $width
+            {
+                Crc::<$width>::checksum(self, bytes)
+            }
+        }
+    )*};
+}
+
+impl_crc_algorithm! {
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+}
+
+/// Trait adding checksums over validated ranges of byte buffers.
+///
+/// Only available with the `crc` feature.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{GetCrcChecked, IndexErrorKind};
+/// use crc::{CRC_16_IBM_3740, CRC_32_ISO_HDLC, Crc};
+///
+/// const CRC32: Crc<u32> = Crc::<u32>::new(&CRC_32_ISO_HDLC);
+/// const CRC16: Crc<u16> = Crc::<u16>::new(&CRC_16_IBM_3740);
+///
+/// let image = b"\x00\x00123456789";
+/// assert_eq!(image.crc_range_checked(2.., &CRC32), Ok(0xCBF43926));
+/// assert_eq!(image.crc_range_checked(2..11, &CRC16), Ok(0x29B1));
+///
+/// let err = image.crc_range_checked(2..12, &CRC32).unwrap_err();
+/// assert_eq!(*err.kind(), IndexErrorKind::EndRange(12, 11));
+/// ```
+pub trait GetCrcChecked
+{
+    /// Computes the checksum of the bytes in `range` with `crc`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] describing `range` if it is out of bounds, as
+    /// [`get_checked`](crate::GetChecked::get_checked) would.
+    fn crc_range_checked<R, C>(&self, range: R, crc: &C) -> Result<C::Value, IndexError>
+    where
+        R: GetCheckedSliceIndex<[u8], Output = [u8]>,
+        C: CrcAlgorithm;
+}
+
+impl GetCrcChecked for [u8]
+{
+    #[inline]
+    fn crc_range_checked<R, C>(&self, range: R, crc: &C) -> Result<C::Value, IndexError>
+    where
+        R: GetCheckedSliceIndex<[u8], Output = [u8]>,
+        C: CrcAlgorithm,
+    {
+        Ok(crc.checksum(self.get_checked(range)?))
+    }
+}
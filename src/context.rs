@@ -0,0 +1,48 @@
+use core::panic::Location;
+
+use crate::IndexError;
+
+/// Extension trait converting a `Result<T, IndexError>` into an [`anyhow::Result`] annotated with
+/// the caller location.
+///
+/// The location is attached as context, so the alternate `{:#}` format prints it followed by
+/// the `IndexError` message.
+///
+/// Only available with the `anyhow` feature when the `no_std` feature is disabled.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{GetChecked, IndexContext, IndexError};
+/// fn third(v: &[u8]) -> anyhow::Result<u8>
+/// {
+///     Ok(*v.get_checked(3).context_index()?)
+/// }
+///
+/// let err = third(&[1, 2, 3]).unwrap_err();
+/// assert!(err.to_string().starts_with(&format!("at {}:", file!())));
+/// assert!(
+///     format!("{:#}", err).ends_with(": index out of bounds: the len is 3 but the index is 3")
+/// );
+/// assert!(err.downcast_ref::<IndexError>().is_some());
+/// ```
+pub trait IndexContext<T>
+{
+    /// Converts the error into an [`anyhow::Error`] with the location of the caller as context.
+    /// The original `IndexError` remains retrievable via [`anyhow::Error::downcast_ref`].
+    #[track_caller]
+    fn context_index(self) -> anyhow::Result<T>;
+}
+
+impl<T> IndexContext<T> for Result<T, IndexError>
+{
+    #[inline]
+    #[track_caller]
+    fn context_index(self) -> anyhow::Result<T>
+    {
+        match self
+        {
+            | Ok(ok) => Ok(ok),
+            | Err(e) => Err(anyhow::Error::new(e).context(format!("at {}", Location::caller()))),
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use core::ops::{Range, RangeBounds};
+
+use crate::{
+    Error, IndexError,
+    IndexErrorKind::{Bounds, Overlap, Unread},
+    try_range,
+};
+
+/// A slice view that records the range returned by every successful checked access, so a parser
+/// can verify that it consumed its whole input exactly once.
+///
+/// Elements that were never returned by an access remain raw, and are reported by
+/// [`unread`](Coverage::unread). Elements returned more than once are reported by
+/// [`reread`](Coverage::reread).
+///
+/// Only available when the `no_std` feature is disabled.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{Coverage, IndexErrorKind};
+/// let input = b"\x02hi!";
+/// let mut view = Coverage::new(input);
+///
+/// let len = *view.get_checked(0).unwrap() as usize;
+/// assert_eq!(view.get_range_checked(1..1 + len), Ok(&b"hi"[..]));
+///
+/// assert_eq!(view.unread(), [3..4]);
+/// assert_eq!(*view.finish_checked().unwrap_err().kind(), IndexErrorKind::Unread(3, 4));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Coverage<'a, T>
+{
+    slice: &'a [T],
+    reads: Vec<Range<usize>>,
+}
+
+impl<'a, T> Coverage<'a, T>
+{
+    /// Wraps `slice` with no elements read.
+    #[inline]
+    pub fn new(slice: &'a [T]) -> Self
+    {
+        Coverage { slice, reads: Vec::new() }
+    }
+
+    /// Returns the number of elements in the slice.
+    #[inline]
+    pub fn len(&self) -> usize
+    {
+        self.slice.len()
+    }
+
+    /// Returns `true` if the slice contains no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool
+    {
+        self.slice.is_empty()
+    }
+
+    /// Returns a reference to the element at `index`, recording it as read.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Bounds` if `index` is out of bounds.
+    pub fn get_checked(&mut self, index: usize) -> Result<&'a T, IndexError>
+    {
+        let slice = self.slice;
+        let elem = slice.get(index).ok_or(Error::new(Bounds(index, slice.len())))?;
+        self.reads.push(index..index + 1);
+        Ok(elem)
+    }
+
+    /// Returns the subslice corresponding to `range`, recording it as read.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] describing the invalid range as
+    /// [`get_checked`](crate::GetChecked::get_checked) would.
+    pub fn get_range_checked<R>(&mut self, range: R) -> Result<&'a [T], IndexError>
+    where R: RangeBounds<usize>
+    {
+        let slice = self.slice;
+        let range = try_range(range, slice.len())?;
+        self.reads.push(range.clone());
+        Ok(&slice[range])
+    }
+
+    /// Returns the ranges returned by successful accesses, in the order they were made.
+    #[inline]
+    pub fn reads(&self) -> &[Range<usize>]
+    {
+        &self.reads
+    }
+
+    /// Returns the maximal ranges of elements that were never read, in ascending order.
+    pub fn unread(&self) -> Vec<Range<usize>>
+    {
+        self.sweep().0
+    }
+
+    /// Returns the maximal ranges of elements that were read more than once, in ascending order.
+    pub fn reread(&self) -> Vec<Range<usize>>
+    {
+        self.sweep().1
+    }
+
+    /// Checks that every element was read exactly once.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] for the lowest element that was not read exactly once, of kind
+    /// `Unread` containing the bounds of the unread range it starts, or `Overlap` containing the
+    /// start of the read that repeats it and the end of the elements read before it.
+    pub fn finish_checked(&self) -> Result<(), IndexError>
+    {
+        let mut reads: Vec<_> = self.reads.iter().filter(|r| !r.is_empty()).collect();
+        reads.sort_by_key(|r| r.start);
+
+        let mut end = 0;
+        for r in reads
+        {
+            match r.start
+            {
+                | start if start > end => return Err(Error::new(Unread(end, start))),
+                | start if start < end => return Err(Error::new(Overlap(start, end))),
+                | _ => end = r.end,
+            }
+        }
+
+        match end
+        {
+            | _ if end < self.slice.len() => Err(Error::new(Unread(end, self.slice.len()))),
+            | _ => Ok(()),
+        }
+    }
+
+    /// Returns the unread and reread ranges.
+    fn sweep(&self) -> (Vec<Range<usize>>, Vec<Range<usize>>)
+    {
+        let mut reads: Vec<_> = self.reads.iter().filter(|r| !r.is_empty()).collect();
+        reads.sort_by_key(|r| r.start);
+
+        let (mut unread, mut reread) = (Vec::new(), Vec::<Range<usize>>::new());
+        let mut end = 0;
+        for r in reads
+        {
+            if r.start > end
+            {
+                unread.push(end..r.start);
+            }
+            if r.start < end
+            {
+                let doubled = r.start..r.end.min(end);
+                match reread.last_mut()
+                {
+                    | Some(last) if last.end >= doubled.start =>
+                    {
+                        last.end = last.end.max(doubled.end)
+                    },
+                    | _ => reread.push(doubled),
+                }
+            }
+            end = end.max(r.end);
+        }
+
+        if end < self.slice.len()
+        {
+            unread.push(end..self.slice.len());
+        }
+        (unread, reread)
+    }
+}
@@ -0,0 +1,408 @@
+use core::{fmt, ops::Range};
+#[cfg(not(feature = "no_std"))]
+use std::error;
+
+use crate::{
+    Error, GetChecked, IndexError,
+    IndexErrorKind::{self, Bounds, EndOverflow, EndRange},
+};
+
+/// A saved position of an [`IndexCursor`], created by [`IndexCursor::checkpoint`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Checkpoint
+{
+    pos: usize,
+}
+
+impl Checkpoint
+{
+    /// Returns the position the checkpoint was taken at.
+    #[inline]
+    pub const fn position(&self) -> usize
+    {
+        self.pos
+    }
+}
+
+/// An error returned when a speculative branch started by [`IndexCursor::speculate`] fails.
+///
+/// Holds the error the branch failed with, along with the position of the cursor when it failed
+/// and the checkpoint the cursor was rolled back to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BacktrackError
+{
+    offset:     usize,
+    checkpoint: Checkpoint,
+    error:      IndexError,
+}
+
+impl BacktrackError
+{
+    /// Returns the position of the cursor when the branch failed.
+    #[inline]
+    pub fn offset(&self) -> usize
+    {
+        self.offset
+    }
+
+    /// Returns the checkpoint that was active when the branch failed.
+    #[inline]
+    pub fn checkpoint(&self) -> Checkpoint
+    {
+        self.checkpoint
+    }
+
+    /// Returns the error the branch failed with.
+    #[inline]
+    pub fn error(&self) -> &IndexError
+    {
+        &self.error
+    }
+
+    /// Converts into the error the branch failed with.
+    #[inline]
+    pub fn into_error(self) -> IndexError
+    {
+        self.error
+    }
+}
+
+impl fmt::Display for BacktrackError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(
+            f,
+            "{} (failed at offset {}, rolled back to checkpoint at {})",
+            self.error, self.offset, self.checkpoint.pos
+        )
+    }
+}
+
+#[cfg(feature = "no_std")]
+impl core_error::Error for BacktrackError {}
+
+/// Only available when the `no_std` feature is disabled.
+#[cfg(not(feature = "no_std"))]
+impl error::Error for BacktrackError
+{
+    fn source(&self) -> Option<&(dyn error::Error + 'static)>
+    {
+        Some(&self.error)
+    }
+}
+
+/// A cursor that walks forward over the indices `0..len` of a sequence.
+///
+/// The cursor holds only its position and the length, not the sequence itself, so it can
+/// drive a state machine over a slice that is borrowed elsewhere, or over several parallel
+/// slices of the same length.
+///
+/// A cursor can hand out a sub-cursor over a window of the next few indices with
+/// [`sub_cursor_checked`](IndexCursor::sub_cursor_checked), for parsing a nested structure
+/// against its own bounds. Positions are shared with the enclosing cursor, so both index the same
+/// sequence.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{IndexCursor, IndexErrorKind};
+/// let tokens = ["let", "x", "=", "1"];
+/// let mut cursor = IndexCursor::new(tokens.len());
+///
+/// assert_eq!(tokens[cursor.peek_checked().unwrap()], "let");
+/// cursor.advance_checked(3).unwrap();
+/// assert_eq!(tokens[cursor.peek_checked().unwrap()], "1");
+///
+/// let err = cursor.advance_checked(2).unwrap_err();
+/// assert_eq!(*err.kind(), IndexErrorKind::EndRange(5, 4));
+/// assert_eq!(cursor.position(), 3);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IndexCursor
+{
+    start: usize,
+    pos:   usize,
+    end:   usize,
+    outer: usize,
+}
+
+impl IndexCursor
+{
+    /// Creates a cursor at the start of a sequence of length `len`.
+    #[inline]
+    pub const fn new(len: usize) -> Self
+    {
+        IndexCursor { start: 0, pos: 0, end: len, outer: len }
+    }
+
+    /// Returns the index the cursor is at, which equals the length once the end is reached.
+    #[inline]
+    pub const fn position(&self) -> usize
+    {
+        self.pos
+    }
+
+    /// Returns the number of indices in the cursor's window, which is the length of the sequence
+    /// unless the cursor is a sub-cursor.
+    #[inline]
+    pub const fn len(&self) -> usize
+    {
+        self.end - self.start
+    }
+
+    /// Returns `true` if the cursor's window is empty.
+    #[inline]
+    pub const fn is_empty(&self) -> bool
+    {
+        self.start == self.end
+    }
+
+    /// Returns the indices the cursor may visit, which are `0..len` unless the cursor is a
+    /// sub-cursor.
+    #[inline]
+    pub const fn window(&self) -> Range<usize>
+    {
+        self.start..self.end
+    }
+
+    /// Returns the number of indices left before the end.
+    #[inline]
+    pub const fn remaining(&self) -> usize
+    {
+        self.end - self.pos
+    }
+
+    /// Returns `true` if the cursor is at the end of the sequence.
+    #[inline]
+    pub const fn is_at_end(&self) -> bool
+    {
+        self.pos == self.end
+    }
+
+    /// Returns the index the cursor is at, without moving it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Bounds` if the cursor is at the end.
+    #[inline]
+    pub fn peek_checked(&self) -> Result<usize, IndexError>
+    {
+        match self.pos
+        {
+            | pos if pos < self.end => Ok(pos),
+            | pos => Err(self.error(Bounds(pos, self.end))),
+        }
+    }
+
+    /// Moves the cursor `n` indices forward, returning the new position. Advancing onto the end
+    /// is allowed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `EndOverflow` if the new position overflows, or
+    /// `EndRange` containing the new position and the length if it is past the end, in which
+    /// case the cursor does not move.
+    #[inline]
+    pub fn advance_checked(&mut self, n: usize) -> Result<usize, IndexError>
+    {
+        let end = self.pos.checked_add(n).ok_or_else(|| self.error(EndOverflow()))?;
+        match end
+        {
+            | _ if end > self.end && self.is_sub() => Err(self.error(EndRange(end, self.end))),
+            | _ if end > self.end =>
+            {
+                Err(Error::new(EndRange(end, self.end)).with_range(self.pos, end, self.end))
+            },
+            | _ =>
+            {
+                self.pos = end;
+                Ok(end)
+            },
+        }
+    }
+
+    /// Returns the index `k` places ahead of the cursor, without moving it. A `k` of zero
+    /// returns the same index as [`peek_checked`](IndexCursor::peek_checked).
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `EndOverflow` if the index overflows, or `Bounds`
+    /// containing the index and the length if it is past the last index.
+    #[inline]
+    pub fn lookahead_checked(&self, k: usize) -> Result<usize, IndexError>
+    {
+        match self.pos.checked_add(k).ok_or_else(|| self.error(EndOverflow()))?
+        {
+            | index if index < self.end => Ok(index),
+            | index => Err(self.error(Bounds(index, self.end))),
+        }
+    }
+
+    /// Returns the element of `slice` at the cursor and advances past it if it satisfies
+    /// `predicate`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Bounds` if the cursor is at the end of its window or of
+    /// `slice`, or
+    /// `Rejected` containing the position if the element does not satisfy `predicate`, in
+    /// which case the cursor does not move.
+    ///
+    /// # Examples
+    /// ```
+    /// # use get_checked::{IndexCursor, IndexErrorKind};
+    /// let tokens = ["(", "x", ")"];
+    /// let mut cursor = IndexCursor::new(tokens.len());
+    ///
+    /// assert_eq!(cursor.expect_checked(&tokens, |t| *t == "("), Ok(&"("));
+    /// assert_eq!(cursor.eat(&tokens, |t| *t == ")"), None);
+    /// assert_eq!(cursor.eat(&tokens, |t| t.chars().all(char::is_alphabetic)), Some(&"x"));
+    ///
+    /// let err = cursor.expect_checked(&tokens, |t| *t == ";").unwrap_err();
+    /// assert_eq!(err.to_string(), "element at index 2 was rejected");
+    /// ```
+    pub fn expect_checked<'a, T, P>(
+        &mut self,
+        slice: &'a [T],
+        predicate: P,
+    ) -> Result<&'a T, IndexError>
+    where
+        P: FnOnce(&T) -> bool,
+    {
+        let index = self.peek_checked()?;
+        let elem = slice.get_if_checked(index, predicate).map_err(|e| self.annotate(e))?;
+        self.advance_checked(1)?;
+        Ok(elem)
+    }
+
+    /// Returns the element of `slice` at the cursor and advances past it if it satisfies
+    /// `predicate`, or returns `None` without moving if it does not or the cursor is at the end.
+    pub fn eat<'a, T, P>(&mut self, slice: &'a [T], predicate: P) -> Option<&'a T>
+    where P: FnOnce(&T) -> bool
+    {
+        self.expect_checked(slice, predicate).ok()
+    }
+
+    /// Returns a sub-cursor over the next `len` indices and advances past them.
+    ///
+    /// The sub-cursor starts at the current position and ends `len` indices later. Errors
+    /// returned by it, and by sub-cursors of it, carry its window as their range and the length
+    /// of the whole sequence as their length, which
+    /// [`MessageStyle::Descriptive`](crate::MessageStyle::Descriptive) displays.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] as [`advance_checked`](IndexCursor::advance_checked) would if
+    /// the window would extend past the end, in which case the cursor does not move.
+    ///
+    /// # Examples
+    /// ```
+    /// # use get_checked::{IndexCursor, IndexErrorKind, MessageStyle};
+    /// // A tag byte, a length byte and a value of that length, where the value is itself a
+    /// // sequence of tag-length-value fields.
+    /// let input = [0x30, 4, 0x02, 1, 7, 0x04, 0x00];
+    /// let mut cursor = IndexCursor::new(input.len());
+    ///
+    /// cursor.advance_checked(1).unwrap();
+    /// let len = input[cursor.peek_checked().unwrap()] as usize;
+    /// cursor.advance_checked(1).unwrap();
+    ///
+    /// let mut inner = cursor.sub_cursor_checked(len).unwrap();
+    /// assert_eq!((inner.window(), cursor.position()), (2..6, 6));
+    ///
+    /// inner.advance_checked(3).unwrap();
+    /// let err = inner.advance_checked(2).unwrap_err();
+    /// assert_eq!(*err.kind(), IndexErrorKind::EndRange(7, 6));
+    /// assert_eq!(
+    ///     err.with_style(MessageStyle::Descriptive).to_string(),
+    ///     "range end index 7 out of range for slice of length 6 (range: 2..6, len: 7)"
+    /// );
+    /// ```
+    pub fn sub_cursor_checked(&mut self, len: usize) -> Result<IndexCursor, IndexError>
+    {
+        let start = self.pos;
+        let end = self.advance_checked(len)?;
+        Ok(IndexCursor { start, pos: start, end, outer: self.outer })
+    }
+
+    /// Saves the current position so that it can be restored by
+    /// [`rollback`](IndexCursor::rollback).
+    #[inline]
+    pub const fn checkpoint(&self) -> Checkpoint
+    {
+        Checkpoint { pos: self.pos }
+    }
+
+    /// Moves the cursor back, or forward, to `checkpoint`.
+    ///
+    /// A checkpoint taken from another cursor is clamped to this cursor's window.
+    #[inline]
+    pub fn rollback(&mut self, checkpoint: Checkpoint)
+    {
+        self.pos = checkpoint.pos.max(self.start).min(self.end);
+    }
+
+    /// Runs `branch` on the cursor, rolling the cursor back to where it started if `branch`
+    /// fails, so that an alternative can be tried from the same position.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BacktrackError`] holding the error `branch` failed with, the position of the
+    /// cursor when it failed, and the checkpoint it was rolled back to.
+    ///
+    /// # Examples
+    /// ```
+    /// # use get_checked::IndexCursor;
+    /// let tokens = ["let", "x", "=", "1", ";"];
+    /// let mut cursor = IndexCursor::new(tokens.len());
+    ///
+    /// let err = cursor
+    ///     .speculate(|c| {
+    ///         c.expect_checked(&tokens, |t| *t == "let")?;
+    ///         c.expect_checked(&tokens, |t| *t == "x")?;
+    ///         c.expect_checked(&tokens, |t| *t == ":")
+    ///     })
+    ///     .unwrap_err();
+    /// assert_eq!((err.offset(), err.checkpoint().position()), (2, 0));
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "element at index 2 was rejected (failed at offset 2, rolled back to checkpoint at 0)"
+    /// );
+    ///
+    /// assert_eq!(cursor.position(), 0);
+    /// assert_eq!(cursor.speculate(|c| c.advance_checked(4)), Ok(4));
+    /// ```
+    pub fn speculate<R, F>(&mut self, branch: F) -> Result<R, BacktrackError>
+    where F: FnOnce(&mut Self) -> Result<R, IndexError>
+    {
+        let checkpoint = self.checkpoint();
+        branch(self).map_err(|error| {
+            let offset = self.pos;
+            self.rollback(checkpoint);
+            BacktrackError { offset, checkpoint, error }
+        })
+    }
+
+    /// Returns `true` if the cursor is a sub-cursor over part of the sequence.
+    #[inline]
+    const fn is_sub(&self) -> bool
+    {
+        self.start != 0 || self.end != self.outer
+    }
+
+    /// Creates an error of `kind`, naming the window if the cursor is a sub-cursor.
+    fn error(&self, kind: IndexErrorKind) -> IndexError
+    {
+        self.annotate(Error::new(kind))
+    }
+
+    /// Sets the range of `err` to the window if the cursor is a sub-cursor.
+    fn annotate(&self, err: IndexError) -> IndexError
+    {
+        match self.is_sub()
+        {
+            | true => err.with_range(self.start, self.end, self.outer),
+            | false => err,
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use core::slice::SliceIndex;
+
+use crate::GetCheckedSliceIndex;
+
+/// Trait for migrating code that relies on [`get_unchecked`] toward checked indexing.
+///
+/// In builds with `debug_assertions` enabled the index is validated using
+/// [`GetCheckedSliceIndex`] and an invalid index panics with the usual [`IndexError`] message.
+/// Otherwise the methods compile to [`get_unchecked`] and [`get_unchecked_mut`], so existing
+/// assumptions can be exercised by debug builds and tests without changing release codegen.
+///
+/// [`get_unchecked`]:     slice::get_unchecked
+/// [`get_unchecked_mut`]: slice::get_unchecked_mut
+/// [`IndexError`]:        crate::IndexError
+///
+/// # Examples
+/// ```
+/// # use get_checked::GetCheckedDebug;
+/// let v = [1, 2, 3];
+/// // SAFETY: `1..3` is within the bounds of `v`.
+/// assert_eq!(unsafe { v.get_unchecked_checked_in_debug(1..3) }, [2, 3]);
+/// ```
+pub trait GetCheckedDebug<T>
+{
+    /// Returns a reference to an element or subslice without bounds checking in release builds.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method with an out-of-bounds index is *[undefined behavior]* when
+    /// `debug_assertions` are disabled, exactly as with [`get_unchecked`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds and `debug_assertions` are enabled.
+    ///
+    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+    /// [`get_unchecked`]:    slice::get_unchecked
+    unsafe fn get_unchecked_checked_in_debug<I>(&self, index: I) -> &<I as SliceIndex<[T]>>::Output
+    where I: GetCheckedSliceIndex<[T]>
+            + SliceIndex<[T], Output = <I as GetCheckedSliceIndex<[T]>>::Output>;
+
+    /// Returns a mutable reference to an element or subslice without bounds checking in release
+    /// builds.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method with an out-of-bounds index is *[undefined behavior]* when
+    /// `debug_assertions` are disabled, exactly as with [`get_unchecked_mut`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds and `debug_assertions` are enabled.
+    ///
+    /// [undefined behavior]:  https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+    /// [`get_unchecked_mut`]: slice::get_unchecked_mut
+    unsafe fn get_unchecked_checked_in_debug_mut<I>(
+        &mut self,
+        index: I,
+    ) -> &mut <I as SliceIndex<[T]>>::Output
+    where
+        I: GetCheckedSliceIndex<[T]>
+            + SliceIndex<[T], Output = <I as GetCheckedSliceIndex<[T]>>::Output>;
+}
+
+impl<T> GetCheckedDebug<T> for [T]
+{
+    #[inline]
+    #[track_caller]
+    unsafe fn get_unchecked_checked_in_debug<I>(
+        &self,
+        index: I,
+    ) -> &<I as SliceIndex<[T]>>::Output
+    where
+        I: GetCheckedSliceIndex<[T]>
+            + SliceIndex<[T], Output = <I as GetCheckedSliceIndex<[T]>>::Output>,
+    {
+        if cfg!(debug_assertions)
+        {
+            match GetCheckedSliceIndex::get_checked(index, self)
+            {
+                | Ok(output) => output,
+                | Err(e) => panic!("{}", e),
+            }
+        }
+        else
+        {
+            self.get_unchecked(index)
+        }
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn get_unchecked_checked_in_debug_mut<I>(
+        &mut self,
+        index: I,
+    ) -> &mut <I as SliceIndex<[T]>>::Output
+    where
+        I: GetCheckedSliceIndex<[T]>
+            + SliceIndex<[T], Output = <I as GetCheckedSliceIndex<[T]>>::Output>,
+    {
+        if cfg!(debug_assertions)
+        {
+            match GetCheckedSliceIndex::get_checked_mut(index, self)
+            {
+                | Ok(output) => output,
+                | Err(e) => panic!("{}", e),
+            }
+        }
+        else
+        {
+            self.get_unchecked_mut(index)
+        }
+    }
+}
@@ -0,0 +1,183 @@
+use core::ops::{Range, RangeBounds};
+#[cfg(not(feature = "no_std"))]
+use std::collections::VecDeque;
+
+#[cfg(feature = "heapless")]
+use heapless::Deque;
+
+use crate::{
+    Error, IndexError,
+    IndexErrorKind::{Bounds, Discontiguous},
+    try_range,
+};
+
+/// Trait adding checked element and range access to `VecDeque`, and to `heapless::Deque` with
+/// the `heapless` feature.
+///
+/// The elements of a deque are stored in a ring buffer, so a range may wrap around the end of the
+/// buffer. Such a range is returned by [`slices_checked`](GetCheckedDeque::slices_checked) as two
+/// slices, while [`slice_checked`](GetCheckedDeque::slice_checked) rejects it.
+///
+/// Only available when the `no_std` feature is disabled or the `heapless` feature is enabled.
+///
+/// # Examples
+/// ```
+/// # #[cfg(not(feature = "no_std"))]
+/// # {
+/// # use get_checked::{GetCheckedDeque, IndexErrorKind};
+/// use std::collections::VecDeque;
+///
+/// let mut deque: VecDeque<_> = vec![2, 3].into();
+/// deque.push_front(1);
+///
+/// assert_eq!(deque.get_checked(2), Ok(&3));
+/// assert_eq!(deque.slices_checked(..), Ok((&[1][..], &[2, 3][..])));
+/// assert_eq!(*deque.slice_checked(..).unwrap_err().kind(), IndexErrorKind::Discontiguous(1));
+/// assert_eq!(*deque.get_checked(3).unwrap_err().kind(), IndexErrorKind::Bounds(3, 3));
+/// # }
+/// ```
+pub trait GetCheckedDeque<T>
+{
+    /// Returns a reference to the element at `index`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Bounds` if `index` is out of bounds.
+    fn get_checked(&self, index: usize) -> Result<&T, IndexError>;
+
+    /// Returns a mutable reference to the element at `index`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Bounds` if `index` is out of bounds.
+    fn get_checked_mut(&mut self, index: usize) -> Result<&mut T, IndexError>;
+
+    /// Returns the elements in `range` as a single slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] describing the invalid range as
+    /// [`get_checked`](crate::GetChecked::get_checked) would, or `Discontiguous` containing the
+    /// index at which the range wraps around the ring buffer.
+    fn slice_checked<R>(&self, range: R) -> Result<&[T], IndexError>
+    where R: RangeBounds<usize>;
+
+    /// Returns the elements in `range` as a single mutable slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] describing the invalid range as
+    /// [`get_checked`](crate::GetChecked::get_checked) would, or `Discontiguous` containing the
+    /// index at which the range wraps around the ring buffer.
+    fn slice_checked_mut<R>(&mut self, range: R) -> Result<&mut [T], IndexError>
+    where R: RangeBounds<usize>;
+
+    /// Returns the elements in `range` as a pair of slices, in order. The second slice is empty
+    /// unless the range wraps around the ring buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] describing the invalid range as
+    /// [`get_checked`](crate::GetChecked::get_checked) would.
+    fn slices_checked<R>(&self, range: R) -> Result<(&[T], &[T]), IndexError>
+    where R: RangeBounds<usize>;
+
+    /// Returns the elements in `range` as a pair of mutable slices, in order. The second slice is
+    /// empty unless the range wraps around the ring buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] describing the invalid range as
+    /// [`get_checked`](crate::GetChecked::get_checked) would.
+    fn slices_checked_mut<R>(&mut self, range: R) -> Result<(&mut [T], &mut [T]), IndexError>
+    where R: RangeBounds<usize>;
+}
+
+macro_rules! impl_get_checked_deque {
+    ($(#[$attr:meta])* [$($gen:tt)*] $deque:ty) => {
+        $(#[$attr])*
+        impl<$($gen)*> GetCheckedDeque<T> for $deque
+        {
+            fn get_checked(&self, index: usize) -> Result<&T, IndexError>
+            {
+                self.get(index).ok_or(Error::new(Bounds(index, self.len())))
+            }
+
+            fn get_checked_mut(&mut self, index: usize) -> Result<&mut T, IndexError>
+            {
+                let len = self.len();
+                self.get_mut(index).ok_or(Error::new(Bounds(index, len)))
+            }
+
+            fn slice_checked<R>(&self, range: R) -> Result<&[T], IndexError>
+            where R: RangeBounds<usize>
+            {
+                let range = contiguous_range(range, self.len(), self.as_slices().0.len())?;
+                Ok(self.slices_checked(range)?.0)
+            }
+
+            fn slice_checked_mut<R>(&mut self, range: R) -> Result<&mut [T], IndexError>
+            where R: RangeBounds<usize>
+            {
+                let range = contiguous_range(range, self.len(), self.as_slices().0.len())?;
+                Ok(self.slices_checked_mut(range)?.0)
+            }
+
+            fn slices_checked<R>(&self, range: R) -> Result<(&[T], &[T]), IndexError>
+            where R: RangeBounds<usize>
+            {
+                let range = try_range(range, self.len())?;
+                let (front, back) = self.as_slices();
+                let head = front.len();
+                Ok(match range
+                {
+                    | _ if range.end <= head => (&front[range], &[]),
+                    | _ if range.start >= head => (&back[range.start - head..range.end - head], &[]),
+                    | _ => (&front[range.start..], &back[..range.end - head]),
+                })
+            }
+
+            fn slices_checked_mut<R>(&mut self, range: R) -> Result<(&mut [T], &mut [T]), IndexError>
+            where R: RangeBounds<usize>
+            {
+                let range = try_range(range, self.len())?;
+                let (front, back) = self.as_mut_slices();
+                let head = front.len();
+                Ok(match range
+                {
+                    | _ if range.end <= head => (&mut front[range], &mut []),
+                    | _ if range.start >= head =>
+                    {
+                        (&mut back[range.start - head..range.end - head], &mut [])
+                    },
+                    | _ => (&mut front[range.start..], &mut back[..range.end - head]),
+                })
+            }
+        }
+    };
+}
+
+impl_get_checked_deque! {
+    #[cfg(not(feature = "no_std"))]
+    [T] VecDeque<T>
+}
+
+impl_get_checked_deque! {
+    #[cfg(feature = "heapless")]
+    [T, const N: usize] Deque<T, N>
+}
+
+/// Validates `range` against a deque of length `len` whose ring buffer wraps after `head`
+/// elements, rejecting ranges that span the wrap.
+fn contiguous_range<R>(range: R, len: usize, head: usize) -> Result<Range<usize>, IndexError>
+where R: RangeBounds<usize>
+{
+    match try_range(range, len)?
+    {
+        | range if range.start < head && head < range.end =>
+        {
+            Err(Error::new(Discontiguous(head)).with_range(range.start, range.end, len))
+        },
+        | range => Ok(range),
+    }
+}
@@ -0,0 +1,49 @@
+use core::ops;
+
+use crate::{GetChecked, IndexError};
+
+/// An object-safe companion of [`GetChecked`] with monomorphic methods.
+///
+/// The generic methods of [`GetChecked`] prevent its use as a trait object. This trait is
+/// implemented for every type whose [`Target`](GetChecked::Target) is `[T]`, allowing buffers to
+/// be passed around as `&dyn DynGetChecked<T>`.
+///
+/// # Examples
+/// ```
+/// # use get_checked::DynGetChecked;
+/// fn header(buf: &dyn DynGetChecked<u8>) -> Option<&[u8]>
+/// {
+///     buf.get_checked_range(0..2).ok()
+/// }
+///
+/// let v: &[u8] = &[0xCA, 0xFE, 0x01];
+/// assert_eq!(header(&v), Some(&[0xCA, 0xFE][..]));
+/// assert_eq!(
+///     v.get_checked_usize(3).unwrap_err().to_string(),
+///     "index out of bounds: the len is 3 but the index is 3"
+/// );
+/// ```
+pub trait DynGetChecked<T>
+{
+    /// Returns a reference to the element at `index`. See [`GetChecked::get_checked`].
+    fn get_checked_usize(&self, index: usize) -> Result<&T, IndexError>;
+
+    /// Returns a reference to the subslice within `range`. See [`GetChecked::get_checked`].
+    fn get_checked_range(&self, range: ops::Range<usize>) -> Result<&[T], IndexError>;
+}
+
+impl<T, S> DynGetChecked<T> for S
+where S: GetChecked<T, Target = [T]> + ?Sized
+{
+    #[inline]
+    fn get_checked_usize(&self, index: usize) -> Result<&T, IndexError>
+    {
+        self.get_checked(index)
+    }
+
+    #[inline]
+    fn get_checked_range(&self, range: ops::Range<usize>) -> Result<&[T], IndexError>
+    {
+        self.get_checked(range)
+    }
+}
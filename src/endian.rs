@@ -0,0 +1,262 @@
+use crate::{
+    Error, GetChecked, IndexError,
+    IndexErrorKind::{CapacityOverflow, EndOverflow, EndRange},
+};
+
+/// Byte order of a multi-byte integer, selected at runtime.
+///
+/// Useful for formats whose header declares the byte order of the rest of the file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Endian
+{
+    /// Least significant byte first.
+    Little,
+    /// Most significant byte first.
+    Big,
+}
+
+impl Endian
+{
+    /// The byte order of the target platform.
+    #[cfg(target_endian = "little")]
+    pub const NATIVE: Endian = Endian::Little;
+    /// The byte order of the target platform.
+    #[cfg(target_endian = "big")]
+    pub const NATIVE: Endian = Endian::Big;
+}
+
+/// Width of an integer header, such as the length header written by
+/// [`WriteEndianChecked::write_len_prefixed_checked`] or the tag and length of a
+/// [`TlvConfig`](crate::TlvConfig).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LenPrefix
+{
+    /// A one-byte header.
+    U8,
+    /// A two-byte header.
+    U16,
+    /// A four-byte header.
+    U32,
+    /// An eight-byte header.
+    U64,
+}
+
+impl LenPrefix
+{
+    /// Returns the width of the header in bytes.
+    pub const fn size(self) -> usize
+    {
+        match self
+        {
+            | LenPrefix::U8 => 1,
+            | LenPrefix::U16 => 2,
+            | LenPrefix::U32 => 4,
+            | LenPrefix::U64 => 8,
+        }
+    }
+}
+
+/// Trait adding checked reads of integers at byte offsets, in a byte order chosen at runtime.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{Endian, IndexErrorKind, ReadEndianChecked};
+/// let header = [b'I', b'I', 0x2A, 0x00, 0x08, 0x00, 0x00, 0x00];
+/// let endian = match &header[..2]
+/// {
+///     | b"II" => Endian::Little,
+///     | _ => Endian::Big,
+/// };
+///
+/// assert_eq!(header.read_u16_checked(2, endian), Ok(42));
+/// assert_eq!(header.read_u32_checked(4, endian), Ok(8));
+///
+/// let err = header.read_u32_checked(6, endian).unwrap_err();
+/// assert_eq!(*err.kind(), IndexErrorKind::EndRange(10, 8));
+/// ```
+pub trait ReadEndianChecked
+{
+    /// Reads the `u16` stored at `offset` in the byte order `endian`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `EndOverflow` if the end of the value overflows, or
+    /// describing the range `offset..offset + 2` as
+    /// [`get_checked`](crate::GetChecked::get_checked) would.
+    fn read_u16_checked(&self, offset: usize, endian: Endian) -> Result<u16, IndexError>;
+
+    /// Reads the `u32` stored at `offset` in the byte order `endian`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `EndOverflow` if the end of the value overflows, or
+    /// describing the range `offset..offset + 4` as
+    /// [`get_checked`](crate::GetChecked::get_checked) would.
+    fn read_u32_checked(&self, offset: usize, endian: Endian) -> Result<u32, IndexError>;
+
+    /// Reads the `u64` stored at `offset` in the byte order `endian`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `EndOverflow` if the end of the value overflows, or
+    /// describing the range `offset..offset + 8` as
+    /// [`get_checked`](crate::GetChecked::get_checked) would.
+    fn read_u64_checked(&self, offset: usize, endian: Endian) -> Result<u64, IndexError>;
+}
+
+/// Implements a `read_*_checked` method for the integer type `$t`.
+macro_rules! read_checked {
+    ($name:ident, $t:ty) => {
+        #[inline]
+        fn $name(&self, offset: usize, endian: Endian) -> Result<$t, IndexError>
+        {
+            let bytes = read_array(self, offset)?;
+            Ok(match endian
+            {
+                | Endian::Little => <$t>::from_le_bytes(bytes),
+                | Endian::Big => <$t>::from_be_bytes(bytes),
+            })
+        }
+    };
+}
+
+impl ReadEndianChecked for [u8]
+{
+    read_checked!(read_u16_checked, u16);
+
+    read_checked!(read_u32_checked, u32);
+
+    read_checked!(read_u64_checked, u64);
+}
+
+/// Trait for fixed-size values that can be read from a byte buffer at an offset, such as the
+/// headers of binary formats.
+///
+/// With the `derive` feature, `#[derive(ReadChecked)]` implements this trait for structs whose
+/// fields are all fixed-width integers, reading the fields in declaration order from consecutive
+/// bytes without padding. Multi-byte fields are little-endian unless the struct is annotated with
+/// `#[checked(endian = "big")]` or `#[checked(endian = "native")]`.
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "derive")]
+/// # {
+/// # use get_checked::{IndexErrorKind, ReadChecked};
+/// #[derive(Debug, PartialEq, ReadChecked)]
+/// #[checked(endian = "big")]
+/// struct Header
+/// {
+///     version: u8,
+///     flags:   u8,
+///     length:  u16,
+///     offset:  i32,
+/// }
+///
+/// let buf = [0xFF, 1, 0x80, 0x00, 2, 0x00, 0x00, 0x00, 0x10];
+/// let header = Header::read_checked(&buf, 1).unwrap();
+/// assert_eq!(header, Header { version: 1, flags: 0x80, length: 2, offset: 16 });
+///
+/// let err = Header::read_checked(&buf, 2).unwrap_err();
+/// assert_eq!(*err.kind(), IndexErrorKind::EndRange(10, 9));
+/// # }
+/// ```
+pub trait ReadChecked: Sized
+{
+    /// The number of bytes read.
+    const SIZE: usize;
+
+    /// Reads a value from the [`SIZE`](ReadChecked::SIZE) bytes of `buf` starting at `offset`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `EndOverflow` if `offset + SIZE` overflows, or
+    /// describing the range `offset..offset + SIZE` as
+    /// [`get_checked`](crate::GetChecked::get_checked) would.
+    fn read_checked(buf: &[u8], offset: usize) -> Result<Self, IndexError>;
+}
+
+/// Copies the `N` bytes starting at `offset` out of `bytes`.
+fn read_array<const N: usize>(bytes: &[u8], offset: usize) -> Result<[u8; N], IndexError>
+{
+    let end = offset.checked_add(N).ok_or(Error::new(EndOverflow()))?;
+    let mut array = [0; N];
+    array.copy_from_slice(bytes.get_checked(offset..end)?);
+    Ok(array)
+}
+
+/// Trait adding checked writes of length-prefixed payloads into caller-provided buffers, for
+/// encoders that cannot allocate.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{Endian, IndexErrorKind, LenPrefix, WriteEndianChecked};
+/// let mut buf = [0u8; 8];
+/// let end = buf.write_len_prefixed_checked(0, b"abc", LenPrefix::U16, Endian::Big).unwrap();
+/// assert_eq!(buf[..end], [0, 3, b'a', b'b', b'c']);
+///
+/// let err = buf.write_len_prefixed_checked(end, b"abc", LenPrefix::U8, Endian::Big).unwrap_err();
+/// match *err.kind()
+/// {
+///     | IndexErrorKind::EndRange(end, len) => assert_eq!(end - len, 1, "one byte missing"),
+///     | _ => unreachable!(),
+/// }
+/// ```
+pub trait WriteEndianChecked
+{
+    /// Writes the length of `payload` as a `prefix`-wide integer in the byte order `endian` at
+    /// `offset`, followed by `payload`, and returns the offset just past the payload.
+    ///
+    /// The buffer is left untouched if an error is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `CapacityOverflow` if the length of `payload` does not
+    /// fit in `prefix`, `EndOverflow` if the end of the payload overflows, or `EndRange`
+    /// containing the end of the payload and the buffer length if the buffer is too short, in
+    /// which case the difference is the number of missing bytes.
+    fn write_len_prefixed_checked(
+        &mut self,
+        offset: usize,
+        payload: &[u8],
+        prefix: LenPrefix,
+        endian: Endian,
+    ) -> Result<usize, IndexError>;
+}
+
+impl WriteEndianChecked for [u8]
+{
+    fn write_len_prefixed_checked(
+        &mut self,
+        offset: usize,
+        payload: &[u8],
+        prefix: LenPrefix,
+        endian: Endian,
+    ) -> Result<usize, IndexError>
+    {
+        let size = prefix.size();
+        let header = payload.len() as u64;
+        if size < 8 && header >> (size * 8) != 0
+        {
+            return Err(Error::new(CapacityOverflow()));
+        }
+
+        let end = offset
+            .checked_add(size)
+            .and_then(|end| end.checked_add(payload.len()))
+            .ok_or(Error::new(EndOverflow()))?;
+        let len = self.len();
+        if end > len
+        {
+            return Err(Error::new(EndRange(end, len)).with_range(offset, end, len));
+        }
+
+        let (dst_header, dst_payload) = self[offset..end].split_at_mut(size);
+        match endian
+        {
+            | Endian::Little => dst_header.copy_from_slice(&header.to_le_bytes()[..size]),
+            | Endian::Big => dst_header.copy_from_slice(&header.to_be_bytes()[8 - size..]),
+        }
+        dst_payload.copy_from_slice(payload);
+        Ok(end)
+    }
+}
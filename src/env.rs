@@ -0,0 +1,48 @@
+use std::env;
+
+use crate::{Error, IndexError, IndexErrorKind::Bounds};
+
+/// Returns the `n`th command line argument, where `0` is typically the program path.
+///
+/// Only available when the `no_std` feature is disabled.
+///
+/// # Errors
+///
+/// Returns an [`IndexError`] of kind `Bounds` containing `n` and the number of arguments if the
+/// argument does not exist.
+///
+/// # Panics
+///
+/// Panics under the same conditions as [`env::args`], i.e., if an argument is not valid
+/// Unicode.
+///
+/// # Examples
+/// ```
+/// # use get_checked::args_checked;
+/// match args_checked(1)
+/// {
+///     | Ok(path) => println!("input: {}", path),
+///     | Err(e) => eprintln!("missing input argument: {}", e),
+/// }
+/// ```
+pub fn args_checked(n: usize) -> Result<String, IndexError>
+{
+    env::args().nth(n).ok_or_else(|| Error::new(Bounds(n, env::args().count())))
+}
+
+/// Returns the `n`th `(key, value)` entry of the environment of the current process.
+///
+/// Only available when the `no_std` feature is disabled.
+///
+/// # Errors
+///
+/// Returns an [`IndexError`] of kind `Bounds` containing `n` and the number of environment
+/// variables if the entry does not exist.
+///
+/// # Panics
+///
+/// Panics under the same conditions as [`env::vars`], i.e., if an entry is not valid Unicode.
+pub fn vars_checked(n: usize) -> Result<(String, String), IndexError>
+{
+    env::vars().nth(n).ok_or_else(|| Error::new(Bounds(n, env::vars().count())))
+}
@@ -1,4 +1,6 @@
 use core::fmt;
+#[cfg(not(feature = "no_std"))]
+use std::{error, fmt::Debug, sync::Arc};
 
 use write as w;
 
@@ -14,6 +16,11 @@ use write as w;
 /// A [`print`] of a given error will match the error message that panic would have produced for
 /// the same index or range. See [`IndexErrorKind`] for details.
 ///
+/// # Thread safety
+/// `IndexError` is `Send + Sync + 'static` in every configuration, so it can be returned across
+/// threads and `.await` points and boxed as `Box<dyn Error + Send + Sync>`. Any source attached
+/// with `with_source` is required to be `Send + Sync` for the same reason.
+///
 /// # Examples
 /// ```
 /// # use get_checked::GetChecked;
@@ -25,7 +32,7 @@ use write as w;
 ///
 /// # use get_checked::GetCheckedSliceIndex;
 /// let v = vec![1, 2, 3];
-/// if let Err(e) = (2..5).get_checked(&v)
+/// if let Err(e) = (2..5).get_checked(&v[..])
 /// {
 ///     println!("Index error: {}", e);
 /// }
@@ -33,10 +40,33 @@ use write as w;
 ///
 /// [`GetChecked`]:           crate::GetChecked
 /// [`GetCheckedSliceIndex`]: crate::GetCheckedSliceIndex
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone)]
 pub struct IndexError
 {
-    pub(super) kind: IndexErrorKind,
+    pub(super) kind:   IndexErrorKind,
+    pub(super) range:  Option<(usize, usize)>,
+    pub(super) len:    Option<usize>,
+    pub(super) style:  MessageStyle,
+    #[cfg(not(feature = "no_std"))]
+    pub(super) source: Option<Arc<dyn error::Error + Send + Sync + 'static>>,
+}
+
+/// Styles in which an [`IndexError`] can be displayed.
+///
+/// See [`IndexError::with_style`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MessageStyle
+{
+    /// Matches the message that [`panic`] would have produced. This is the default.
+    #[default]
+    Std,
+
+    /// Appends the requested index or range and the slice length, when known, to the std
+    /// message, e.g.:
+    /// ```text
+    /// "range end index 4 out of range for slice of length 3 (range: 1..4, len: 3)"
+    /// ```
+    Descriptive,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -54,7 +84,7 @@ pub enum IndexErrorKind
     ///
     /// Builtin error message:
     /// ```text
-    /// "index out of bounds: the len is {0} but the index is {1}"
+    /// "index out of bounds: the len is {1} but the index is {0}"
     Bounds(usize, usize),
 
     /// Slice index start is after the end of the slice.
@@ -100,39 +130,544 @@ pub enum IndexErrorKind
     /// ```text
     /// "attempted to index slice up to maximum usize"
     EndOverflow(),
+
+    /// Element at the index is vacant (i.e., `None`).
+    /// * `0` - index of element.
+    ///
+    /// Builtin error message:
+    /// ```text
+    /// "slot at index {0} is vacant"
+    /// ```
+    Vacant(usize),
+
+    /// Index splits a UTF-16 surrogate pair.
+    /// * `0` - index of the split.
+    ///
+    /// Builtin error message:
+    /// ```text
+    /// "index {0} splits a UTF-16 surrogate pair"
+    /// ```
+    SurrogateBoundary(usize),
+
+    /// Byte at the index is not ASCII.
+    /// * `0` - index of the byte.
+    ///
+    /// Builtin error message:
+    /// ```text
+    /// "byte at index {0} is not ASCII"
+    /// ```
+    NotAscii(usize),
+
+    /// Byte index is not on a UTF-8 `char` boundary.
+    /// * `0` - byte index.
+    ///
+    /// Builtin error message:
+    /// ```text
+    /// "byte index {0} is not a char boundary"
+    /// ```
+    CharBoundary(usize),
+
+    /// Key refers to a slot that has since been removed or reused.
+    /// * `0` - index of the slot.
+    ///
+    /// Builtin error message:
+    /// ```text
+    /// "key for slot {0} is stale"
+    /// ```
+    StaleKey(usize),
+
+    /// Chunk, page or stride size is zero.
+    ///
+    /// Builtin error message:
+    /// ```text
+    /// "chunk size must be non-zero"
+    /// ```
+    ZeroChunk(),
+
+    /// Coordinate is out of bounds along an axis of a two-dimensional view.
+    /// * `0` - axis of the coordinate.
+    /// * `1` - coordinate.
+    /// * `2` - extent of the axis.
+    ///
+    /// Builtin error message:
+    /// ```text
+    /// "x coordinate {1} out of range for width {2}"
+    /// "y coordinate {1} out of range for height {2}"
+    /// ```
+    AxisBounds(Axis, usize, usize),
+
+    /// Byte offset is not suitably aligned for the requested type.
+    /// * `0` - byte offset.
+    /// * `1` - required alignment.
+    ///
+    /// Builtin error message:
+    /// ```text
+    /// "byte offset {0} is not aligned to {1}"
+    /// ```
+    Misaligned(usize, usize),
+
+    /// Slice length does not match the required length.
+    /// * `0` - required length.
+    /// * `1` - length of slice.
+    ///
+    /// Builtin error message:
+    /// ```text
+    /// "expected slice of length {0} but found length {1}"
+    /// ```
+    LengthMismatch(usize, usize),
+
+    /// Growing a collection would exceed the maximum length for its element type, which is
+    /// [`usize::MAX`] for zero-sized types and `isize::MAX` bytes otherwise, or a length does
+    /// not fit in the header that encodes it.
+    ///
+    /// Builtin error message:
+    /// ```text
+    /// "capacity overflow"
+    /// ```
+    CapacityOverflow(),
+
+    /// Range starts before the end of the range preceding it in a set that must be sorted and
+    /// non-overlapping.
+    /// * `0` - start of range.
+    /// * `1` - end of the preceding range.
+    ///
+    /// Builtin error message:
+    /// ```text
+    /// "range starting at {0} overlaps preceding range ending at {1}"
+    /// ```
+    Overlap(usize, usize),
+
+    /// Element at the index exists but was rejected by a predicate.
+    /// * `0` - index of element.
+    ///
+    /// Builtin error message:
+    /// ```text
+    /// "element at index {0} was rejected"
+    /// ```
+    Rejected(usize),
+
+    /// Window around an index would start before the first element.
+    /// * `0` - center index of the window.
+    /// * `1` - radius of the window.
+    ///
+    /// Builtin error message:
+    /// ```text
+    /// "window of radius {1} around index {0} starts before the slice"
+    /// ```
+    StartUnderflow(usize, usize),
+
+    /// Searched-for subslice does not occur.
+    /// * `0` - length of the subslice.
+    ///
+    /// Builtin error message:
+    /// ```text
+    /// "subslice of length {0} not found"
+    /// ```
+    NotFound(usize),
+
+    /// Opening delimiter is not followed by a closing delimiter.
+    /// * `0` - index of the opening delimiter.
+    ///
+    /// Builtin error message:
+    /// ```text
+    /// "delimiter at index {0} is not closed"
+    /// ```
+    Unclosed(usize),
+
+    /// Element differs from the expected element of a prefix or suffix.
+    /// * `0` - index of the element.
+    ///
+    /// Builtin error message:
+    /// ```text
+    /// "element at index {0} does not match the expected element"
+    /// ```
+    Mismatch(usize),
+
+    /// Byte is not a valid digit in the expected encoding.
+    /// * `0` - index of the byte.
+    ///
+    /// Builtin error message:
+    /// ```text
+    /// "byte at index {0} is not a valid digit"
+    /// ```
+    InvalidDigit(usize),
+
+    /// Index appears more than once where each element may only be borrowed once.
+    /// * `0` - repeated index.
+    ///
+    /// Builtin error message:
+    /// ```text
+    /// "index {0} appears more than once"
+    /// ```
+    Duplicate(usize),
+
+    /// Index or range bound lies beyond an application-defined limit on the accessible length.
+    /// * `0` - index or range bound.
+    /// * `1` - limit.
+    ///
+    /// Builtin error message:
+    /// ```text
+    /// "index {0} exceeds access limit of {1}"
+    /// ```
+    LimitExceeded(usize, usize),
+
+    /// Range spans the point at which a ring buffer wraps around, so its elements are not
+    /// contiguous in memory.
+    /// * `0` - index at which the buffer wraps.
+    ///
+    /// Builtin error message:
+    /// ```text
+    /// "range is discontiguous at index {0}"
+    /// ```
+    Discontiguous(usize),
+
+    /// Access would exceed the remaining budget of a metered view.
+    /// * `0` - number of elements requested.
+    /// * `1` - remaining budget.
+    ///
+    /// Builtin error message:
+    /// ```text
+    /// "access of {0} elements exceeds remaining quota of {1}"
+    /// ```
+    QuotaExceeded(usize, usize),
+
+    /// Range of elements was never read by a view that requires its whole input to be read.
+    /// * `0` - start of the unread range.
+    /// * `1` - end of the unread range.
+    ///
+    /// Builtin error message:
+    /// ```text
+    /// "range {0}..{1} was not read"
+    /// ```
+    Unread(usize, usize),
+
+    /// Key refers to a value that has since been removed, where the key does not expose the
+    /// index of its slot.
+    ///
+    /// Builtin error message:
+    /// ```text
+    /// "key is stale"
+    /// ```
+    StaleOpaqueKey(),
+}
+
+/// An axis of a two-dimensional view, as reported by [`IndexErrorKind::AxisBounds`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis
+{
+    /// The horizontal axis, i.e. the column or `x` coordinate.
+    X,
+    /// The vertical axis, i.e. the row or `y` coordinate.
+    Y,
 }
 
-use IndexErrorKind::{Bounds, EndOverflow, EndRange, Order, StartOverflow, StartRange};
+use IndexErrorKind::{
+    AxisBounds, Bounds, CapacityOverflow, CharBoundary, Discontiguous, Duplicate, EndOverflow,
+    EndRange, InvalidDigit, LengthMismatch, LimitExceeded, Misaligned, Mismatch, NotAscii,
+    NotFound, Order, Overlap, QuotaExceeded, Rejected, StaleKey, StaleOpaqueKey, StartOverflow,
+    StartRange, StartUnderflow, SurrogateBoundary, Unclosed, Unread, Vacant, ZeroChunk,
+};
+
+/// Implementation of IndexErrorKind.
+impl IndexErrorKind
+{
+    /// The number of kinds, i.e. one more than the largest [`discriminant`](Self::discriminant).
+    ///
+    /// A table sized by this constant stops compiling when a kind is added, prompting the new
+    /// kind to be mapped.
+    pub const COUNT: usize = KIND_COUNT;
+
+    /// Returns an iterator over one instance of every kind, in order of
+    /// [`discriminant`](Self::discriminant).
+    ///
+    /// The fields of each instance are placeholders: every `usize` is zero and every [`Axis`] is
+    /// [`Axis::X`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use get_checked::IndexErrorKind;
+    /// #[derive(Debug, Clone, Copy, PartialEq)]
+    /// enum MyError
+    /// {
+    ///     OutOfRange,
+    ///     Malformed,
+    /// }
+    ///
+    /// // Fails to compile if a kind is added or removed.
+    /// let table: [MyError; IndexErrorKind::COUNT] = {
+    ///     use MyError::*;
+    ///     [
+    ///         OutOfRange, OutOfRange, OutOfRange, OutOfRange, OutOfRange, OutOfRange, // 0-5
+    ///         OutOfRange, Malformed, Malformed, Malformed, OutOfRange, Malformed, // 6-11
+    ///         OutOfRange, Malformed, Malformed, OutOfRange, Malformed, Malformed, // 12-17
+    ///         OutOfRange, Malformed, Malformed, Malformed, Malformed, Malformed, // 18-23
+    ///         OutOfRange, Malformed, OutOfRange, Malformed, OutOfRange, // 24-28
+    ///     ]
+    /// };
+    ///
+    /// for (i, kind) in IndexErrorKind::all().enumerate()
+    /// {
+    ///     assert_eq!(kind.discriminant() as usize, i);
+    /// }
+    /// assert_eq!(table[IndexErrorKind::Bounds(4, 3).discriminant() as usize], MyError::OutOfRange);
+    /// ```
+    pub fn all() -> core::array::IntoIter<IndexErrorKind, { IndexErrorKind::COUNT }>
+    {
+        IntoIterator::into_iter(kind_table())
+    }
+
+    /// Returns a number identifying the kind, ignoring its fields.
+    ///
+    /// Discriminants are stable: existing kinds keep their discriminant across releases, and new
+    /// kinds are assigned the next unused one. They are always less than [`COUNT`](Self::COUNT).
+    ///
+    /// # Examples
+    /// ```
+    /// # use get_checked::IndexErrorKind;
+    /// assert_eq!(IndexErrorKind::Bounds(4, 3).discriminant(), 0);
+    /// assert_eq!(IndexErrorKind::EndRange(9, 7).discriminant(), 3);
+    /// ```
+    pub const fn discriminant(&self) -> u8
+    {
+        kind_discriminant(self)
+    }
+}
+
+/// Generates the count, representative instances and discriminants of [`IndexErrorKind`] from a
+/// single table, so that they cannot drift apart. A kind missing from the table fails to compile
+/// in the exhaustive match of `kind_discriminant`, and the discriminants must count up from zero
+/// in table order.
+macro_rules! impl_kind_table {
+    ($($discriminant:literal => $kind:ident($($field:expr),*),)*) => {
+        const KIND_COUNT: usize = [$($discriminant),*].len();
+
+        fn kind_table() -> [IndexErrorKind; KIND_COUNT]
+        {
+            [$($kind($($field),*)),*]
+        }
+
+        const fn kind_discriminant(kind: &IndexErrorKind) -> u8
+        {
+            match kind
+            {
+                $(| $kind { .. } => $discriminant,)*
+            }
+        }
+
+        const _: () = {
+            let discriminants = [$($discriminant),*];
+            let mut i = 0;
+            while i < discriminants.len()
+            {
+                assert!(discriminants[i] == i);
+                i += 1;
+            }
+        };
+    };
+}
+
+impl_kind_table! {
+    0 => Bounds(0, 0),
+    1 => Order(0, 0),
+    2 => StartRange(0, 0),
+    3 => EndRange(0, 0),
+    4 => StartOverflow(),
+    5 => EndOverflow(),
+    6 => Vacant(0),
+    7 => SurrogateBoundary(0),
+    8 => NotAscii(0),
+    9 => CharBoundary(0),
+    10 => StaleKey(0),
+    11 => ZeroChunk(),
+    12 => AxisBounds(Axis::X, 0, 0),
+    13 => Misaligned(0, 0),
+    14 => LengthMismatch(0, 0),
+    15 => CapacityOverflow(),
+    16 => Overlap(0, 0),
+    17 => Rejected(0),
+    18 => StartUnderflow(0, 0),
+    19 => NotFound(0),
+    20 => Unclosed(0),
+    21 => Mismatch(0),
+    22 => InvalidDigit(0),
+    23 => Duplicate(0),
+    24 => LimitExceeded(0, 0),
+    25 => Discontiguous(0),
+    26 => QuotaExceeded(0, 0),
+    27 => Unread(0, 0),
+    28 => StaleOpaqueKey(),
+}
 
 /// Implementation of IndexError.
 impl IndexError
 {
+    /// Creates a new error of the given kind.
+    ///
+    /// This is intended for external implementors of [`GetCheckedSliceIndex`], allowing custom
+    /// index types to report errors consistent with the builtin ones. See also
+    /// [`try_range`](crate::try_range) and [`resolve_bounds`](crate::resolve_bounds).
+    ///
+    /// # Examples
+    /// ```
+    /// # use get_checked::{IndexError, IndexErrorKind};
+    /// let err = IndexError::new(IndexErrorKind::Bounds(4, 3));
+    /// assert_eq!(*err.kind(), IndexErrorKind::Bounds(4, 3));
+    /// ```
+    ///
+    /// [`GetCheckedSliceIndex`]: crate::GetCheckedSliceIndex
+    pub const fn new(kind: IndexErrorKind) -> Self
+    {
+        IndexError {
+            kind,
+            range: None,
+            len: None,
+            style: MessageStyle::Std,
+            #[cfg(not(feature = "no_std"))]
+            source: None,
+        }
+    }
+
+    /// Records the requested `start..end` range that caused the error and the slice length.
+    pub(crate) const fn with_range(mut self, start: usize, end: usize, len: usize) -> Self
+    {
+        self.range = Some((start, end));
+        self.len = Some(len);
+        self
+    }
+
+    /// Sets the style used when displaying the error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use get_checked::{GetChecked, MessageStyle};
+    /// let v = [1, 2, 3];
+    /// let err = v.get_checked(1..4).unwrap_err().with_style(MessageStyle::Descriptive);
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "range end index 4 out of range for slice of length 3 (range: 1..4, len: 3)"
+    /// );
+    /// ```
+    pub const fn with_style(mut self, style: MessageStyle) -> Self
+    {
+        self.style = style;
+        self
+    }
+
+    /// Outputs the style used when displaying the error.
+    pub fn style(&self) -> MessageStyle
+    {
+        self.style
+    }
+
     /// Outputs the detailed cause of an index error.
     pub fn kind(&self) -> &IndexErrorKind
     {
         &self.kind
     }
 
+    /// Attaches an underlying cause to the error, which is then exposed via
+    /// [`Error::source`](std::error::Error::source).
+    ///
+    /// This allows composite operations, such as validating a subslice after retrieving it, to
+    /// preserve the inner cause while still reporting an `IndexError`. The [`Display`] output
+    /// is unaffected.
+    ///
+    /// Only available when the `no_std` feature is disabled.
+    ///
+    /// [`Display`]: fmt::Display
+    ///
+    /// # Examples
+    /// ```
+    /// # use get_checked::GetChecked;
+    /// use std::error::Error;
+    ///
+    /// let v = ["1", "2", "x"];
+    /// let err = v.get_checked(3).unwrap_err();
+    /// let err = err.with_source(v[2].parse::<u8>().unwrap_err());
+    ///
+    /// assert!(err.source().is_some());
+    /// ```
+    #[cfg(not(feature = "no_std"))]
+    pub fn with_source<E>(mut self, source: E) -> Self
+    where E: Into<Box<dyn error::Error + Send + Sync + 'static>>
+    {
+        self.source = Some(Arc::from(source.into()));
+        self
+    }
+
     #[rustfmt::skip]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
     {
         match self.kind
         {
-            | Bounds(a, b)     => { w!(f, "index out of bounds: the len is {0} but the index is {1}", a, b) },
-            | Order(a, b)      => { w!(f, "slice index starts at {0} but ends at {1}", a, b) },
-            | StartRange(a, b) => { w!(f, "range start index {0} out of range for slice of length {1}", a, b) },
-            | StartOverflow()  => { w!(f, "attempted to index slice from after maximum usize") },
-            | EndRange(a, b)   => { w!(f, "range end index {0} out of range for slice of length {1}", a, b) },
-            | EndOverflow()    => { w!(f, "attempted to index slice up to maximum usize") },
+            | Bounds(a, b)              => { w!(f, "index out of bounds: the len is {1} but the index is {0}", a, b) },
+            | Order(a, b)               => { w!(f, "slice index starts at {0} but ends at {1}", a, b) },
+            | StartRange(a, b)          => { w!(f, "range start index {0} out of range for slice of length {1}", a, b) },
+            | StartOverflow()           => { w!(f, "attempted to index slice from after maximum usize") },
+            | EndRange(a, b)            => { w!(f, "range end index {0} out of range for slice of length {1}", a, b) },
+            | EndOverflow()             => { w!(f, "attempted to index slice up to maximum usize") },
+            | Vacant(a)                 => { w!(f, "slot at index {0} is vacant", a) },
+            | SurrogateBoundary(a)      => { w!(f, "index {0} splits a UTF-16 surrogate pair", a) },
+            | NotAscii(a)               => { w!(f, "byte at index {0} is not ASCII", a) },
+            | CharBoundary(a)           => { w!(f, "byte index {0} is not a char boundary", a) },
+            | StaleKey(a)               => { w!(f, "key for slot {0} is stale", a) },
+            | ZeroChunk()               => { w!(f, "chunk size must be non-zero") },
+            | AxisBounds(Axis::X, a, b) => { w!(f, "x coordinate {0} out of range for width {1}", a, b) },
+            | AxisBounds(Axis::Y, a, b) => { w!(f, "y coordinate {0} out of range for height {1}", a, b) },
+            | Misaligned(a, b)          => { w!(f, "byte offset {0} is not aligned to {1}", a, b) },
+            | LengthMismatch(a, b)      => { w!(f, "expected slice of length {0} but found length {1}", a, b) },
+            | CapacityOverflow()        => { w!(f, "capacity overflow") },
+            | Overlap(a, b)             => { w!(f, "range starting at {0} overlaps preceding range ending at {1}", a, b) },
+            | Rejected(a)               => { w!(f, "element at index {0} was rejected", a) },
+            | StartUnderflow(a, b)      => { w!(f, "window of radius {1} around index {0} starts before the slice", a, b) },
+            | NotFound(a)               => { w!(f, "subslice of length {0} not found", a) },
+            | Unclosed(a)               => { w!(f, "delimiter at index {0} is not closed", a) },
+            | Mismatch(a)               => { w!(f, "element at index {0} does not match the expected element", a) },
+            | InvalidDigit(a)           => { w!(f, "byte at index {0} is not a valid digit", a) },
+            | Duplicate(a)              => { w!(f, "index {0} appears more than once", a) },
+            | LimitExceeded(a, b)       => { w!(f, "index {0} exceeds access limit of {1}", a, b) },
+            | Discontiguous(a)          => { w!(f, "range is discontiguous at index {0}", a) },
+            | QuotaExceeded(a, b)       => { w!(f, "access of {0} elements exceeds remaining quota of {1}", a, b) },
+            | Unread(a, b)              => { w!(f, "range {0}..{1} was not read", a, b) },
+            | StaleOpaqueKey()          => { w!(f, "key is stale") },
         }
     }
 }
 
+/// Errors compare equal if their kinds are equal; any other attached context is not compared.
+impl PartialEq for IndexError
+{
+    fn eq(&self, other: &Self) -> bool
+    {
+        self.kind == other.kind
+    }
+}
+
+impl Eq for IndexError {}
+
+impl From<IndexErrorKind> for IndexError
+{
+    fn from(kind: IndexErrorKind) -> Self
+    {
+        IndexError::new(kind)
+    }
+}
+
 impl fmt::Display for IndexError
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
     {
-        self.fmt(f)
+        self.fmt(f)?;
+        match (self.style, &self.kind, self.range, self.len)
+        {
+            | (MessageStyle::Std, ..) => Ok(()),
+            | (_, _, Some((start, end)), Some(len)) =>
+            {
+                w!(f, " (range: {}..{}, len: {})", start, end, len)
+            },
+            | (_, Bounds(index, len), ..) => w!(f, " (index: {}, len: {})", index, len),
+            | _ => Ok(()),
+        }
     }
 }
 
@@ -140,4 +675,76 @@ impl fmt::Display for IndexError
 impl core_error::Error for IndexError {}
 
 #[cfg(not(feature = "no_std"))]
-impl std::error::Error for IndexError {}
+impl error::Error for IndexError
+{
+    fn source(&self) -> Option<&(dyn error::Error + 'static)>
+    {
+        self.source.as_deref().map(|e| e as &(dyn error::Error + 'static))
+    }
+}
+
+// Fails to compile if a field ever makes the errors lose `Send`, `Sync` or `'static`.
+const _: () = {
+    const fn assert_send_sync<T: Send + Sync + 'static>() {}
+    assert_send_sync::<IndexError>();
+    assert_send_sync::<IndexErrorKind>();
+    #[cfg(not(feature = "no_std"))]
+    assert_send_sync::<KeyError>();
+};
+
+/// An error that can be returned when a keyed lookup finds no entry for the requested key.
+///
+/// This is the keyed counterpart of [`IndexError`]. The key is captured using its [`Debug`]
+/// representation so the error does not borrow from or depend on the key type.
+///
+/// Only available when the `no_std` feature is disabled.
+///
+/// # Examples
+/// ```
+/// # use get_checked::KeyError;
+/// let err = KeyError::new("b", 2);
+/// assert_eq!(err.key(), "\"b\"");
+/// assert_eq!(err.to_string(), "key \"b\" not found in map of length 2");
+/// ```
+#[cfg(not(feature = "no_std"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyError
+{
+    key: String,
+    len: usize,
+}
+
+#[cfg(not(feature = "no_std"))]
+impl KeyError
+{
+    /// Creates a new error for `key` missing from a map holding `len` entries.
+    pub fn new<K>(key: &K, len: usize) -> Self
+    where K: Debug + ?Sized
+    {
+        KeyError { key: format!("{:?}", key), len }
+    }
+
+    /// Outputs the [`Debug`] representation of the missing key.
+    pub fn key(&self) -> &str
+    {
+        &self.key
+    }
+
+    /// Outputs the number of entries in the map at the time of the lookup.
+    pub fn map_len(&self) -> usize
+    {
+        self.len
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl fmt::Display for KeyError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        w!(f, "key {0} not found in map of length {1}", self.key, self.len)
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl error::Error for KeyError {}
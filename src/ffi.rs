@@ -0,0 +1,95 @@
+use core::ffi::CStr;
+#[cfg(not(feature = "no_std"))]
+use std::path::{Component, Path};
+#[cfg(all(unix, not(feature = "no_std")))]
+use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
+
+use crate::GetChecked;
+#[cfg(not(feature = "no_std"))]
+use crate::{Error, IndexError, IndexErrorKind::Bounds};
+
+/// Indexes the bytes of a `CStr`, excluding the trailing nul.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{GetChecked, IndexErrorKind};
+/// use std::ffi::CStr;
+///
+/// let s = CStr::from_bytes_with_nul(b"eth0\0").unwrap();
+/// assert_eq!(s.get_checked(..3), Ok(&b"eth"[..]));
+/// assert_eq!(*s.get_checked(4).unwrap_err().kind(), IndexErrorKind::Bounds(4, 4));
+/// ```
+impl GetChecked<u8> for CStr
+{
+    type Target = [u8];
+
+    #[inline]
+    fn checked_target(&self) -> &[u8]
+    {
+        self.to_bytes()
+    }
+}
+
+/// Indexes the raw bytes of an `OsStr`.
+///
+/// Only available on Unix when the `no_std` feature is disabled.
+///
+/// # Examples
+/// ```
+/// # #[cfg(unix)]
+/// # {
+/// # use get_checked::GetChecked;
+/// use std::ffi::OsStr;
+///
+/// let name = OsStr::new("core.1234");
+/// assert_eq!(name.get_checked(5..), Ok(&b"1234"[..]));
+/// assert!(name.get_checked(5..10).is_err());
+/// # }
+/// ```
+#[cfg(all(unix, not(feature = "no_std")))]
+impl GetChecked<u8> for OsStr
+{
+    type Target = [u8];
+
+    #[inline]
+    fn checked_target(&self) -> &[u8]
+    {
+        self.as_bytes()
+    }
+}
+
+/// Trait adding checked access to the components of a [`Path`].
+///
+/// Only available when the `no_std` feature is disabled.
+#[cfg(not(feature = "no_std"))]
+pub trait GetCheckedPath
+{
+    /// Returns the component at position `n` of the path, as yielded by [`Path::components`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Bounds` containing `n` and the number of components if
+    /// `n` is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// # use get_checked::{GetCheckedPath, IndexErrorKind};
+    /// use std::path::{Component, Path};
+    ///
+    /// let path = Path::new("/usr/lib/libc.so");
+    /// assert_eq!(path.nth_component_checked(0), Ok(Component::RootDir));
+    /// assert_eq!(path.nth_component_checked(2), Ok(Component::Normal("lib".as_ref())));
+    /// assert_eq!(*path.nth_component_checked(4).unwrap_err().kind(), IndexErrorKind::Bounds(4, 4));
+    /// ```
+    fn nth_component_checked(&self, n: usize) -> Result<Component<'_>, IndexError>;
+}
+
+#[cfg(not(feature = "no_std"))]
+impl GetCheckedPath for Path
+{
+    fn nth_component_checked(&self, n: usize) -> Result<Component<'_>, IndexError>
+    {
+        let components = || self.components();
+        components().nth(n).ok_or_else(|| Error::new(Bounds(n, components().count())))
+    }
+}
@@ -0,0 +1,54 @@
+use crate::{Error, GetChecked, GetCheckedSliceIndex, IndexError, IndexErrorKind::ZeroChunk};
+
+/// Trait adding a bounds-checked, `memset`-style fill with a repeating pattern to slices.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{FillPatternChecked, IndexErrorKind};
+/// // RGBA framebuffer of four pixels.
+/// let mut framebuffer = [0u8; 16];
+/// framebuffer.fill_pattern_checked(4..12, &[255, 0, 0, 255]).unwrap();
+/// assert_eq!(framebuffer[4..8], [255, 0, 0, 255]);
+/// assert_eq!(framebuffer[12..], [0; 4]);
+///
+/// let err = framebuffer.fill_pattern_checked(.., &[]).unwrap_err();
+/// assert_eq!(*err.kind(), IndexErrorKind::ZeroChunk());
+/// ```
+pub trait FillPatternChecked<T>
+{
+    /// Fills the elements in `range` with clones of `pattern`, repeated from the start of the
+    /// range. The last repetition is cut short if the range length is not a multiple of the
+    /// pattern length.
+    ///
+    /// The slice is left untouched if an error is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] describing `range` if it is out of bounds, or of kind
+    /// `ZeroChunk` if `pattern` is empty.
+    fn fill_pattern_checked<R>(&mut self, range: R, pattern: &[T]) -> Result<(), IndexError>
+    where
+        R: GetCheckedSliceIndex<[T], Output = [T]>,
+        T: Clone;
+}
+
+impl<T> FillPatternChecked<T> for [T]
+{
+    fn fill_pattern_checked<R>(&mut self, range: R, pattern: &[T]) -> Result<(), IndexError>
+    where
+        R: GetCheckedSliceIndex<[T], Output = [T]>,
+        T: Clone,
+    {
+        let dst = self.get_checked_mut(range)?;
+        if pattern.is_empty()
+        {
+            return Err(Error::new(ZeroChunk()));
+        }
+
+        for (value, fill) in dst.iter_mut().zip(pattern.iter().cycle())
+        {
+            value.clone_from(fill);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,218 @@
+use core::{convert::TryInto, ops};
+
+use crate::{
+    Error, GetChecked, IndexError,
+    IndexErrorKind::{EndRange, LengthMismatch},
+};
+
+/// A compile-time index into a [`FixedLen`] or [`MinLen`].
+///
+/// Indexing with `ConstIndex::<I>` fails to compile, rather than panicking, if `I` is not below
+/// the proven length.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ConstIndex<const I: usize>;
+
+/// Evaluates to `()` if `I < N` and fails to compile otherwise.
+pub(crate) struct AssertBelow<const I: usize, const N: usize>;
+
+impl<const I: usize, const N: usize> AssertBelow<I, N>
+{
+    pub(crate) const OK: () = assert!(I < N, "const index out of bounds");
+}
+
+/// A slice proven to hold exactly `N` elements.
+///
+/// The length is checked once, on construction, after which accesses through [`ConstIndex`] or
+/// [`get`](FixedLen::get) need no further checks.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{ConstIndex, FixedLen, IndexErrorKind};
+/// let v = vec![1, 2, 3];
+/// let fixed = FixedLen::<_, 3>::new(&v).unwrap();
+/// assert_eq!(fixed[ConstIndex::<2>], 3);
+/// assert_eq!(fixed.get::<0>(), &1);
+///
+/// let err = FixedLen::<_, 4>::new(&v).unwrap_err();
+/// assert_eq!(*err.kind(), IndexErrorKind::LengthMismatch(4, 3));
+/// ```
+///
+/// Out-of-bounds constant indices are rejected at compile time:
+/// ```compile_fail
+/// # use get_checked::{ConstIndex, FixedLen};
+/// let fixed = FixedLen::<_, 3>::new(&[1, 2, 3]).unwrap();
+/// let _ = fixed[ConstIndex::<3>];
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub struct FixedLen<'a, T, const N: usize>
+{
+    array: &'a [T; N],
+}
+
+impl<'a, T, const N: usize> FixedLen<'a, T, N>
+{
+    /// Wraps `slice` if it holds exactly `N` elements.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `LengthMismatch` containing `N` and the length of
+    /// `slice` otherwise.
+    pub fn new(slice: &'a [T]) -> Result<Self, IndexError>
+    {
+        match slice.try_into()
+        {
+            | Ok(array) => Ok(FixedLen { array }),
+            | Err(_) => Err(Error::new(LengthMismatch(N, slice.len()))),
+        }
+    }
+
+    /// Returns the element at the constant index `I`.
+    pub fn get<const I: usize>(&self) -> &'a T
+    {
+        let () = AssertBelow::<I, N>::OK;
+        &self.array[I]
+    }
+
+    /// Returns the underlying elements as an array reference.
+    pub fn as_array(&self) -> &'a [T; N]
+    {
+        self.array
+    }
+
+    /// Returns the underlying elements as a slice.
+    pub fn as_slice(&self) -> &'a [T]
+    {
+        self.array
+    }
+}
+
+impl<T, const N: usize> Clone for FixedLen<'_, T, N>
+{
+    fn clone(&self) -> Self
+    {
+        *self
+    }
+}
+
+impl<T, const N: usize> Copy for FixedLen<'_, T, N> {}
+
+impl<T, const I: usize, const N: usize> ops::Index<ConstIndex<I>> for FixedLen<'_, T, N>
+{
+    type Output = T;
+
+    fn index(&self, _: ConstIndex<I>) -> &T
+    {
+        self.get::<I>()
+    }
+}
+
+/// A slice proven to hold at least `N` elements.
+///
+/// The first `N` elements, such as a fixed-size protocol header, can be accessed without further
+/// checks, while the remainder is accessed through [`GetChecked`].
+///
+/// # Examples
+/// ```
+/// # use get_checked::{ConstIndex, GetChecked, IndexErrorKind, MinLen};
+/// let packet = [0x01, 0x03, b'a', b'b', b'c'];
+/// let packet = MinLen::<_, 2>::new(&packet).unwrap();
+///
+/// assert_eq!(packet[ConstIndex::<1>], 3);
+/// assert_eq!(packet.tail(), b"abc");
+/// assert_eq!(packet.get_checked(4), Ok(&b'c'));
+///
+/// let err = MinLen::<u8, 2>::new(&[0x01]).unwrap_err();
+/// assert_eq!(*err.kind(), IndexErrorKind::EndRange(2, 1));
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub struct MinLen<'a, T, const N: usize>
+{
+    head:  &'a [T; N],
+    slice: &'a [T],
+}
+
+impl<'a, T, const N: usize> MinLen<'a, T, N>
+{
+    /// Wraps `slice` if it holds at least `N` elements.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `EndRange` containing `N` and the length of `slice`
+    /// otherwise.
+    pub fn new(slice: &'a [T]) -> Result<Self, IndexError>
+    {
+        match slice.get(..N).map(TryInto::try_into)
+        {
+            | Some(Ok(head)) => Ok(MinLen { head, slice }),
+            | _ => Err(Error::new(EndRange(N, slice.len())).with_range(0, N, slice.len())),
+        }
+    }
+
+    /// Returns the number of elements in the slice, which is at least `N`.
+    pub fn len(&self) -> usize
+    {
+        self.slice.len()
+    }
+
+    /// Returns `true` if the slice holds no elements, which is only possible if `N` is zero.
+    pub fn is_empty(&self) -> bool
+    {
+        self.slice.is_empty()
+    }
+
+    /// Returns the element at the constant index `I`.
+    pub fn get<const I: usize>(&self) -> &'a T
+    {
+        let () = AssertBelow::<I, N>::OK;
+        &self.head[I]
+    }
+
+    /// Returns the first `N` elements.
+    pub fn head(&self) -> &'a [T; N]
+    {
+        self.head
+    }
+
+    /// Returns the elements after the first `N`.
+    pub fn tail(&self) -> &'a [T]
+    {
+        &self.slice[N..]
+    }
+
+    /// Returns all elements as a slice.
+    pub fn as_slice(&self) -> &'a [T]
+    {
+        self.slice
+    }
+}
+
+impl<T, const N: usize> Clone for MinLen<'_, T, N>
+{
+    fn clone(&self) -> Self
+    {
+        *self
+    }
+}
+
+impl<T, const N: usize> Copy for MinLen<'_, T, N> {}
+
+impl<T, const I: usize, const N: usize> ops::Index<ConstIndex<I>> for MinLen<'_, T, N>
+{
+    type Output = T;
+
+    fn index(&self, _: ConstIndex<I>) -> &T
+    {
+        self.get::<I>()
+    }
+}
+
+impl<T, const N: usize> GetChecked<T> for MinLen<'_, T, N>
+{
+    type Target = [T];
+
+    #[inline]
+    fn checked_target(&self) -> &[T]
+    {
+        self.slice
+    }
+}
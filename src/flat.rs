@@ -0,0 +1,59 @@
+use crate::{Error, GetChecked, IndexError, IndexErrorKind::Vacant};
+
+/// Trait adding [`get_flat_checked`] and [`get_flat_checked_mut`] to slices of [`Option`].
+///
+/// These merge the bounds check and the occupancy check that sparse storage performs on every
+/// access into a single `Result`.
+///
+/// [`get_flat_checked`]:     GetFlatChecked::get_flat_checked
+/// [`get_flat_checked_mut`]: GetFlatChecked::get_flat_checked_mut
+pub trait GetFlatChecked<T>
+{
+    /// Returns a reference to the value in the slot at `index`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Bounds` if `index` is out of bounds, or `Vacant` if the
+    /// slot is `None`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use get_checked::{GetFlatChecked, IndexErrorKind};
+    /// let v = [Some(10), None];
+    /// assert_eq!(v.get_flat_checked(0), Ok(&10));
+    /// assert_eq!(*v.get_flat_checked(1).unwrap_err().kind(), IndexErrorKind::Vacant(1));
+    /// assert_eq!(*v.get_flat_checked(2).unwrap_err().kind(), IndexErrorKind::Bounds(2, 2));
+    /// ```
+    fn get_flat_checked(&self, index: usize) -> Result<&T, IndexError>;
+
+    /// Returns a mutable reference to the value in the slot at `index`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Bounds` if `index` is out of bounds, or `Vacant` if the
+    /// slot is `None`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use get_checked::GetFlatChecked;
+    /// let mut v = [Some(10), None];
+    /// *v.get_flat_checked_mut(0).unwrap() = 42;
+    /// assert_eq!(v, [Some(42), None]);
+    /// ```
+    fn get_flat_checked_mut(&mut self, index: usize) -> Result<&mut T, IndexError>;
+}
+
+impl<T> GetFlatChecked<T> for [Option<T>]
+{
+    #[inline]
+    fn get_flat_checked(&self, index: usize) -> Result<&T, IndexError>
+    {
+        self.get_checked(index)?.as_ref().ok_or(Error::new(Vacant(index)))
+    }
+
+    #[inline]
+    fn get_flat_checked_mut(&mut self, index: usize) -> Result<&mut T, IndexError>
+    {
+        self.get_checked_mut(index)?.as_mut().ok_or(Error::new(Vacant(index)))
+    }
+}
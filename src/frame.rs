@@ -0,0 +1,194 @@
+use core::{
+    iter::{FusedIterator, StepBy},
+    ops::Range,
+    slice,
+};
+
+use crate::{
+    Error, IndexError,
+    IndexErrorKind::{Bounds, CapacityOverflow, LengthMismatch, ZeroChunk},
+};
+
+/// Trait adding checked frame access to interleaved sample buffers.
+///
+/// A frame holds one sample per channel, so frame `n` spans `channels` consecutive samples
+/// starting at `n * channels`. Trailing samples that do not form a complete frame are ignored.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{GetFrameChecked, IndexErrorKind};
+/// let mut stereo = [0.1, 0.2, 0.3, 0.4];
+/// assert_eq!(stereo.frame_checked(1, 2), Ok(&[0.3, 0.4][..]));
+///
+/// stereo.frame_checked_mut(0, 2).unwrap().fill(0.0);
+/// assert_eq!(stereo, [0.0, 0.0, 0.3, 0.4]);
+///
+/// assert_eq!(*stereo.frame_checked(2, 2).unwrap_err().kind(), IndexErrorKind::Bounds(2, 2));
+/// assert_eq!(*stereo.frame_checked(0, 0).unwrap_err().kind(), IndexErrorKind::ZeroChunk());
+/// ```
+pub trait GetFrameChecked<T>
+{
+    /// Returns the samples of frame `frame_idx` in a buffer with `channels` channels.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `ZeroChunk` if `channels` is zero, or `Bounds`
+    /// containing `frame_idx` and the number of complete frames if the frame does not exist.
+    fn frame_checked(&self, frame_idx: usize, channels: usize) -> Result<&[T], IndexError>;
+
+    /// Returns the mutable samples of frame `frame_idx` in a buffer with `channels` channels.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `ZeroChunk` if `channels` is zero, or `Bounds`
+    /// containing `frame_idx` and the number of complete frames if the frame does not exist.
+    fn frame_checked_mut(
+        &mut self,
+        frame_idx: usize,
+        channels: usize,
+    ) -> Result<&mut [T], IndexError>;
+
+    /// Returns an iterator over the channels of a buffer with `channels` channels, each of
+    /// which is an iterator over that channel's samples in frame order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `ZeroChunk` if `channels` is zero, or `LengthMismatch`
+    /// containing the length of the complete frames and the buffer length if the buffer ends
+    /// with a partial frame.
+    ///
+    /// # Examples
+    /// ```
+    /// # use get_checked::{GetFrameChecked, IndexErrorKind};
+    /// let stereo = [1, -1, 2, -2, 3, -3];
+    /// let mut channels = stereo.deinterleave_checked(2).unwrap();
+    /// assert!(channels.next().unwrap().eq(&[1, 2, 3]));
+    /// assert!(channels.next().unwrap().eq(&[-1, -2, -3]));
+    ///
+    /// let err = stereo[..5].deinterleave_checked(2).unwrap_err();
+    /// assert_eq!(*err.kind(), IndexErrorKind::LengthMismatch(4, 5));
+    /// ```
+    fn deinterleave_checked(&self, channels: usize) -> Result<Channels<'_, T>, IndexError>;
+
+    /// Fills the buffer by interleaving `sources`, one per channel, so that frame `n` holds the
+    /// `n`th sample of every source in order.
+    ///
+    /// The buffer is left untouched if an error is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `ZeroChunk` if `sources` is empty, `LengthMismatch`
+    /// containing the length of the first source and the length of a source that differs from
+    /// it, `CapacityOverflow` if the total number of samples overflows, or `LengthMismatch`
+    /// containing the total number of samples and the buffer length if they differ.
+    ///
+    /// # Examples
+    /// ```
+    /// # use get_checked::{GetFrameChecked, IndexErrorKind};
+    /// let mut stereo = [0; 6];
+    /// stereo.interleave_checked(&[&[1, 2, 3], &[-1, -2, -3]]).unwrap();
+    /// assert_eq!(stereo, [1, -1, 2, -2, 3, -3]);
+    ///
+    /// let err = stereo.interleave_checked(&[&[1, 2, 3], &[-1, -2]]).unwrap_err();
+    /// assert_eq!(*err.kind(), IndexErrorKind::LengthMismatch(3, 2));
+    /// ```
+    fn interleave_checked(&mut self, sources: &[&[T]]) -> Result<(), IndexError>
+    where T: Clone;
+}
+
+impl<T> GetFrameChecked<T> for [T]
+{
+    fn frame_checked(&self, frame_idx: usize, channels: usize) -> Result<&[T], IndexError>
+    {
+        let start = frame_start(self.len(), frame_idx, channels)?;
+        Ok(&self[start..start + channels])
+    }
+
+    fn frame_checked_mut(
+        &mut self,
+        frame_idx: usize,
+        channels: usize,
+    ) -> Result<&mut [T], IndexError>
+    {
+        let start = frame_start(self.len(), frame_idx, channels)?;
+        Ok(&mut self[start..start + channels])
+    }
+
+    fn deinterleave_checked(&self, channels: usize) -> Result<Channels<'_, T>, IndexError>
+    {
+        match self.len() % channels.max(1)
+        {
+            | _ if channels == 0 => Err(Error::new(ZeroChunk())),
+            | 0 => Ok(Channels { samples: self, channels: 0..channels }),
+            | partial => Err(Error::new(LengthMismatch(self.len() - partial, self.len()))),
+        }
+    }
+
+    fn interleave_checked(&mut self, sources: &[&[T]]) -> Result<(), IndexError>
+    where T: Clone
+    {
+        let frames = sources.first().ok_or_else(|| Error::new(ZeroChunk()))?.len();
+        if let Some(source) = sources.iter().find(|source| source.len() != frames)
+        {
+            return Err(Error::new(LengthMismatch(frames, source.len())));
+        }
+
+        let len =
+            frames.checked_mul(sources.len()).ok_or_else(|| Error::new(CapacityOverflow()))?;
+        if len != self.len()
+        {
+            return Err(Error::new(LengthMismatch(len, self.len())));
+        }
+
+        for (frame, samples) in self.chunks_exact_mut(sources.len()).enumerate()
+        {
+            for (sample, source) in samples.iter_mut().zip(sources)
+            {
+                *sample = source[frame].clone();
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Validates a frame and returns the index of its first sample.
+fn frame_start(len: usize, frame_idx: usize, channels: usize) -> Result<usize, IndexError>
+{
+    let frames = len / channels.max(1);
+    match frame_idx
+    {
+        | _ if channels == 0 => Err(Error::new(ZeroChunk())),
+        | _ if frame_idx >= frames => Err(Error::new(Bounds(frame_idx, frames))),
+        | _ => Ok(frame_idx * channels),
+    }
+}
+
+/// An iterator over the channels of an interleaved sample buffer.
+///
+/// This struct is created by [`GetFrameChecked::deinterleave_checked`].
+#[derive(Debug, Clone)]
+pub struct Channels<'a, T>
+{
+    samples:  &'a [T],
+    channels: Range<usize>,
+}
+
+impl<'a, T> Iterator for Channels<'a, T>
+{
+    type Item = StepBy<slice::Iter<'a, T>>;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        let channel = self.channels.next()?;
+        Some(self.samples.get(channel..).unwrap_or(&[]).iter().step_by(self.channels.end))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        self.channels.size_hint()
+    }
+}
+
+impl<T> ExactSizeIterator for Channels<'_, T> {}
+
+impl<T> FusedIterator for Channels<'_, T> {}
@@ -0,0 +1,102 @@
+use quickcheck::{Arbitrary, Gen};
+
+use crate::{
+    Axis,
+    IndexErrorKind::{self, *},
+};
+
+/// An index for property tests, generated to land near the edges that checked access must
+/// handle.
+///
+/// Half of the generated values are small, reaching just past the lengths of the collections
+/// quickcheck generates alongside them; a quarter are within a few steps of [`usize::MAX`]; the
+/// rest are arbitrary. Values shrink toward zero.
+///
+/// Only available with the `quickcheck` feature.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{ArbitraryIndex, GetChecked};
+/// fn agrees_with_get(v: Vec<u8>, a: ArbitraryIndex, b: ArbitraryIndex) -> bool
+/// {
+///     v.get_checked(a.0..b.0).ok() == v.get(a.0..b.0)
+/// }
+///
+/// quickcheck::quickcheck(agrees_with_get as fn(_, _, _) -> _);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ArbitraryIndex(pub usize);
+
+impl Arbitrary for ArbitraryIndex
+{
+    fn arbitrary(g: &mut Gen) -> Self
+    {
+        let n = usize::arbitrary(g);
+        ArbitraryIndex(match u8::arbitrary(g) % 4
+        {
+            | 0 | 1 => n % (g.size() + 2),
+            | 2 => usize::MAX - n % 4,
+            | _ => n,
+        })
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>>
+    {
+        Box::new(self.0.shrink().map(ArbitraryIndex))
+    }
+}
+
+/// Only available with the `quickcheck` feature.
+impl Arbitrary for Axis
+{
+    fn arbitrary(g: &mut Gen) -> Self
+    {
+        *g.choose(&[Axis::X, Axis::Y]).unwrap()
+    }
+}
+
+/// Generates every kind with arbitrary fields. Only available with the `quickcheck` feature.
+///
+/// The kind is picked from [`IndexErrorKind::all`], so a new kind is generated as soon as it is
+/// added, and the match filling in its fields has no catch-all, so it fails to compile until the
+/// new kind is handled.
+impl Arbitrary for IndexErrorKind
+{
+    fn arbitrary(g: &mut Gen) -> Self
+    {
+        let (a, b) = (usize::arbitrary(g), usize::arbitrary(g));
+        let kind = IndexErrorKind::all().nth(usize::arbitrary(g) % IndexErrorKind::COUNT);
+        match kind.unwrap()
+        {
+            | Bounds(..) => Bounds(a, b),
+            | Order(..) => Order(a, b),
+            | StartRange(..) => StartRange(a, b),
+            | EndRange(..) => EndRange(a, b),
+            | StartOverflow() => StartOverflow(),
+            | EndOverflow() => EndOverflow(),
+            | Vacant(..) => Vacant(a),
+            | SurrogateBoundary(..) => SurrogateBoundary(a),
+            | NotAscii(..) => NotAscii(a),
+            | CharBoundary(..) => CharBoundary(a),
+            | StaleKey(..) => StaleKey(a),
+            | ZeroChunk() => ZeroChunk(),
+            | AxisBounds(..) => AxisBounds(Axis::arbitrary(g), a, b),
+            | Misaligned(..) => Misaligned(a, b),
+            | LengthMismatch(..) => LengthMismatch(a, b),
+            | CapacityOverflow() => CapacityOverflow(),
+            | Overlap(..) => Overlap(a, b),
+            | Rejected(..) => Rejected(a),
+            | StartUnderflow(..) => StartUnderflow(a, b),
+            | NotFound(..) => NotFound(a),
+            | Unclosed(..) => Unclosed(a),
+            | Mismatch(..) => Mismatch(a),
+            | InvalidDigit(..) => InvalidDigit(a),
+            | Duplicate(..) => Duplicate(a),
+            | LimitExceeded(..) => LimitExceeded(a, b),
+            | Discontiguous(..) => Discontiguous(a),
+            | QuotaExceeded(..) => QuotaExceeded(a, b),
+            | Unread(..) => Unread(a, b),
+            | StaleOpaqueKey() => StaleOpaqueKey(),
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use std::{
+    error, fmt,
+    sync::{Mutex, RwLock},
+};
+
+use crate::{GetCheckedSliceIndex, IndexError};
+
+/// An error that can be returned when performing a checked access through a lock.
+///
+/// Only available when the `no_std` feature is disabled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LockError
+{
+    /// The lock was poisoned by a thread that panicked while holding it.
+    Poisoned,
+
+    /// The lock was acquired but the index or range was invalid.
+    Index(IndexError),
+}
+
+impl From<IndexError> for LockError
+{
+    fn from(err: IndexError) -> Self
+    {
+        LockError::Index(err)
+    }
+}
+
+impl fmt::Display for LockError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            | LockError::Poisoned => write!(f, "lock poisoned by a panicked thread"),
+            | LockError::Index(err) => fmt::Display::fmt(err, f),
+        }
+    }
+}
+
+impl error::Error for LockError
+{
+    fn source(&self) -> Option<&(dyn error::Error + 'static)>
+    {
+        match self
+        {
+            | LockError::Poisoned => None,
+            | LockError::Index(err) => Some(err),
+        }
+    }
+}
+
+/// Trait that acquires a lock and performs a checked access in one call.
+///
+/// The element or subslice is passed to a closure since it cannot outlive the guard. Code that
+/// already holds a guard can call [`get_checked`](crate::GetChecked::get_checked) through it
+/// directly.
+///
+/// Only available when the `no_std` feature is disabled.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{GetCheckedLocked, LockError};
+/// use std::sync::{Arc, Mutex};
+///
+/// let shared = Arc::new(Mutex::new(vec![1, 2, 3]));
+/// assert_eq!(shared.with_checked(1.., |s| s.iter().sum::<i32>()), Ok(5));
+/// shared.with_checked_mut(0, |x| *x = 7).unwrap();
+/// assert!(matches!(shared.with_checked(3, |x| *x), Err(LockError::Index(_))));
+/// ```
+pub trait GetCheckedLocked<T>
+{
+    /// Locks for reading and calls `f` with the element or subslice at `index`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LockError::Poisoned`] if the lock is poisoned, or [`LockError::Index`] if
+    /// `index` is out of bounds.
+    fn with_checked<I, F, R>(&self, index: I, f: F) -> Result<R, LockError>
+    where
+        I: GetCheckedSliceIndex<[T]>,
+        F: FnOnce(&I::Output) -> R;
+
+    /// Locks for writing and calls `f` with the mutable element or subslice at `index`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LockError::Poisoned`] if the lock is poisoned, or [`LockError::Index`] if
+    /// `index` is out of bounds.
+    fn with_checked_mut<I, F, R>(&self, index: I, f: F) -> Result<R, LockError>
+    where
+        I: GetCheckedSliceIndex<[T]>,
+        F: FnOnce(&mut I::Output) -> R;
+}
+
+impl<T, S> GetCheckedLocked<T> for Mutex<S>
+where S: AsRef<[T]> + AsMut<[T]> + ?Sized
+{
+    fn with_checked<I, F, R>(&self, index: I, f: F) -> Result<R, LockError>
+    where
+        I: GetCheckedSliceIndex<[T]>,
+        F: FnOnce(&I::Output) -> R,
+    {
+        let guard = self.lock().map_err(|_| LockError::Poisoned)?;
+        Ok(f(index.get_checked((*guard).as_ref())?))
+    }
+
+    fn with_checked_mut<I, F, R>(&self, index: I, f: F) -> Result<R, LockError>
+    where
+        I: GetCheckedSliceIndex<[T]>,
+        F: FnOnce(&mut I::Output) -> R,
+    {
+        let mut guard = self.lock().map_err(|_| LockError::Poisoned)?;
+        Ok(f(index.get_checked_mut((*guard).as_mut())?))
+    }
+}
+
+impl<T, S> GetCheckedLocked<T> for RwLock<S>
+where S: AsRef<[T]> + AsMut<[T]> + ?Sized
+{
+    fn with_checked<I, F, R>(&self, index: I, f: F) -> Result<R, LockError>
+    where
+        I: GetCheckedSliceIndex<[T]>,
+        F: FnOnce(&I::Output) -> R,
+    {
+        let guard = self.read().map_err(|_| LockError::Poisoned)?;
+        Ok(f(index.get_checked((*guard).as_ref())?))
+    }
+
+    fn with_checked_mut<I, F, R>(&self, index: I, f: F) -> Result<R, LockError>
+    where
+        I: GetCheckedSliceIndex<[T]>,
+        F: FnOnce(&mut I::Output) -> R,
+    {
+        let mut guard = self.write().map_err(|_| LockError::Poisoned)?;
+        Ok(f(index.get_checked_mut((*guard).as_mut())?))
+    }
+}
@@ -0,0 +1,44 @@
+use std::collections::BinaryHeap;
+
+use crate::{Error, IndexError, IndexErrorKind::Bounds};
+
+/// Trait adding positional peeking to [`BinaryHeap`].
+///
+/// Only available when the `no_std` feature is disabled.
+pub trait GetCheckedHeap<T>
+{
+    /// Returns the `n`th greatest element of the heap, where `0` is the element returned by
+    /// [`BinaryHeap::peek`].
+    ///
+    /// The heap is iterated and the element is selected in `O(n)` time, allocating a temporary
+    /// buffer of references.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Bounds` if `n` is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// # use get_checked::GetCheckedHeap;
+    /// use std::collections::BinaryHeap;
+    ///
+    /// let heap: BinaryHeap<_> = vec![3, 9, 1, 5].into();
+    /// assert_eq!(heap.peek_nth_checked(1), Ok(&5));
+    /// assert!(heap.peek_nth_checked(4).is_err());
+    /// ```
+    fn peek_nth_checked(&self, n: usize) -> Result<&T, IndexError>;
+}
+
+impl<T: Ord> GetCheckedHeap<T> for BinaryHeap<T>
+{
+    fn peek_nth_checked(&self, n: usize) -> Result<&T, IndexError>
+    {
+        if n >= self.len()
+        {
+            return Err(Error::new(Bounds(n, self.len())));
+        }
+
+        let mut elems: Vec<&T> = self.iter().collect();
+        Ok(*elems.select_nth_unstable_by(n, |a, b| b.cmp(a)).1)
+    }
+}
@@ -0,0 +1,124 @@
+use core::fmt;
+
+use crate::{
+    Error, GetChecked, GetCheckedSliceIndex, IndexError,
+    IndexErrorKind::{InvalidDigit, LengthMismatch},
+};
+
+/// Trait adding hex encoding and decoding of validated ranges of byte buffers.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{HexChecked, IndexErrorKind};
+/// let mut buf = [0u8; 4];
+/// buf.decode_hex_into_checked(1.., b"c0ffee").unwrap();
+/// assert_eq!(buf.to_hex_checked(..).unwrap().to_string(), "00c0ffee");
+///
+/// let err = buf.decode_hex_into_checked(..2, b"c0ffee").unwrap_err();
+/// assert_eq!(*err.kind(), IndexErrorKind::LengthMismatch(4, 6));
+/// ```
+pub trait HexChecked
+{
+    /// Returns the bytes in `range` wrapped for formatting as hex, lowercase with
+    /// [`Display`](fmt::Display) and [`LowerHex`](fmt::LowerHex) or uppercase with
+    /// [`UpperHex`](fmt::UpperHex).
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] describing `range` if it is out of bounds, as
+    /// [`get_checked`](crate::GetChecked::get_checked) would.
+    ///
+    /// # Examples
+    /// ```
+    /// # use get_checked::HexChecked;
+    /// let bytes = [0xDE, 0xAD, 0xBE, 0xEF];
+    /// assert_eq!(format!("{}", bytes.to_hex_checked(..2).unwrap()), "dead");
+    /// assert_eq!(format!("{:X}", bytes.to_hex_checked(2..).unwrap()), "BEEF");
+    /// ```
+    fn to_hex_checked<R>(&self, range: R) -> Result<Hex<'_>, IndexError>
+    where R: GetCheckedSliceIndex<[u8], Output = [u8]>;
+
+    /// Decodes the hex digits in `src`, in either case, into the bytes in `dst_range`.
+    ///
+    /// The buffer is left untouched if an error is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] describing `dst_range` if it is out of bounds,
+    /// `LengthMismatch` containing twice the length of `dst_range` and the length of `src` if
+    /// they differ, or `InvalidDigit` containing the index in `src` of the first byte that is
+    /// not a hex digit.
+    fn decode_hex_into_checked<R>(&mut self, dst_range: R, src: &[u8]) -> Result<(), IndexError>
+    where R: GetCheckedSliceIndex<[u8], Output = [u8]>;
+}
+
+impl HexChecked for [u8]
+{
+    fn to_hex_checked<R>(&self, range: R) -> Result<Hex<'_>, IndexError>
+    where R: GetCheckedSliceIndex<[u8], Output = [u8]>
+    {
+        Ok(Hex(self.get_checked(range)?))
+    }
+
+    fn decode_hex_into_checked<R>(&mut self, dst_range: R, src: &[u8]) -> Result<(), IndexError>
+    where R: GetCheckedSliceIndex<[u8], Output = [u8]>
+    {
+        let dst = self.get_checked_mut(dst_range)?;
+        if src.len() != dst.len() * 2
+        {
+            return Err(Error::new(LengthMismatch(dst.len() * 2, src.len())));
+        }
+        if let Some(i) = src.iter().position(|&c| digit(c).is_none())
+        {
+            return Err(Error::new(InvalidDigit(i)));
+        }
+
+        for (byte, pair) in dst.iter_mut().zip(src.chunks_exact(2))
+        {
+            *byte = digit(pair[0]).unwrap_or(0) << 4 | digit(pair[1]).unwrap_or(0);
+        }
+        Ok(())
+    }
+}
+
+/// Returns the value of the hex digit `c`.
+fn digit(c: u8) -> Option<u8>
+{
+    match c
+    {
+        | b'0'..=b'9' => Some(c - b'0'),
+        | b'a'..=b'f' => Some(c - b'a' + 10),
+        | b'A'..=b'F' => Some(c - b'A' + 10),
+        | _ => None,
+    }
+}
+
+/// Bytes formatted as hex, two digits per byte.
+///
+/// This struct is created by [`HexChecked::to_hex_checked`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hex<'a>(&'a [u8]);
+
+impl fmt::Display for Hex<'_>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        fmt::LowerHex::fmt(self, f)
+    }
+}
+
+impl fmt::LowerHex for Hex<'_>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        self.0.iter().try_for_each(|byte| write!(f, "{:02x}", byte))
+    }
+}
+
+impl fmt::UpperHex for Hex<'_>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        self.0.iter().try_for_each(|byte| write!(f, "{:02X}", byte))
+    }
+}
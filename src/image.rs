@@ -0,0 +1,228 @@
+use crate::{
+    Axis, Error, IndexError,
+    IndexErrorKind::{AxisBounds, EndOverflow, EndRange, LengthMismatch, ZeroChunk},
+};
+
+/// Trait adding checked pixel access to tightly packed image buffers.
+///
+/// Rows are `width * bytes_per_pixel` bytes long with no padding; the height is the number of
+/// complete rows in the buffer.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{Axis, GetPixelChecked, IndexErrorKind};
+/// // 2x2 RGB image.
+/// let image = [255, 0, 0, 0, 255, 0, 0, 0, 255, 255, 255, 255];
+/// assert_eq!(image.pixel_checked(1, 1, 2, 3), Ok(&[255, 255, 255][..]));
+///
+/// let err = image.pixel_checked(2, 0, 2, 3).unwrap_err();
+/// assert_eq!(*err.kind(), IndexErrorKind::AxisBounds(Axis::X, 2, 2));
+/// ```
+pub trait GetPixelChecked
+{
+    /// Returns the bytes of the pixel at (`x`, `y`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `ZeroChunk` if `width` or `bytes_per_pixel` is zero,
+    /// `EndOverflow` if the row length overflows, or `AxisBounds` naming the coordinate that is
+    /// out of bounds.
+    fn pixel_checked(
+        &self,
+        x: usize,
+        y: usize,
+        width: usize,
+        bytes_per_pixel: usize,
+    ) -> Result<&[u8], IndexError>;
+
+    /// Returns the mutable bytes of the pixel at (`x`, `y`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `ZeroChunk` if `width` or `bytes_per_pixel` is zero,
+    /// `EndOverflow` if the row length overflows, or `AxisBounds` naming the coordinate that is
+    /// out of bounds.
+    fn pixel_checked_mut(
+        &mut self,
+        x: usize,
+        y: usize,
+        width: usize,
+        bytes_per_pixel: usize,
+    ) -> Result<&mut [u8], IndexError>;
+}
+
+impl GetPixelChecked for [u8]
+{
+    fn pixel_checked(
+        &self,
+        x: usize,
+        y: usize,
+        width: usize,
+        bytes_per_pixel: usize,
+    ) -> Result<&[u8], IndexError>
+    {
+        let row = width.checked_mul(bytes_per_pixel).ok_or(Error::new(EndOverflow()))?;
+        let start = pixel_offset(x, y, width, row, self.len())?;
+        Ok(&self[start..start + bytes_per_pixel])
+    }
+
+    fn pixel_checked_mut(
+        &mut self,
+        x: usize,
+        y: usize,
+        width: usize,
+        bytes_per_pixel: usize,
+    ) -> Result<&mut [u8], IndexError>
+    {
+        let row = width.checked_mul(bytes_per_pixel).ok_or(Error::new(EndOverflow()))?;
+        let start = pixel_offset(x, y, width, row, self.len())?;
+        Ok(&mut self[start..start + bytes_per_pixel])
+    }
+}
+
+/// Validates a pixel coordinate of a tightly packed image and returns the offset of its first
+/// byte, where `row` is the number of bytes in a row.
+fn pixel_offset(
+    x: usize,
+    y: usize,
+    width: usize,
+    row: usize,
+    len: usize,
+) -> Result<usize, IndexError>
+{
+    if row == 0
+    {
+        return Err(Error::new(ZeroChunk()));
+    }
+
+    let height = len / row;
+    match x
+    {
+        | _ if x >= width => Err(Error::new(AxisBounds(Axis::X, x, width))),
+        | _ if y >= height => Err(Error::new(AxisBounds(Axis::Y, y, height))),
+        | _ => Ok(y * row + x * (row / width)),
+    }
+}
+
+/// A read-only view of an image whose rows may be padded, as is common for GPU uploads and
+/// framebuffers.
+///
+/// Each row holds `width * bytes_per_pixel` bytes of pixel data followed by padding up to
+/// `pitch` bytes. The final row does not need to be padded.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{Axis, ImageView, IndexErrorKind};
+/// // 2x2 image with 1 byte per pixel and rows padded to 4 bytes.
+/// let bytes = [1, 2, 0, 0, 3, 4];
+/// let image = ImageView::new(&bytes, 2, 2, 4, 1).unwrap();
+///
+/// assert_eq!(image.row_checked(1), Ok(&[3, 4][..]));
+/// assert_eq!(image.pixel_checked(1, 0), Ok(&[2][..]));
+/// assert_eq!(
+///     *image.pixel_checked(0, 2).unwrap_err().kind(),
+///     IndexErrorKind::AxisBounds(Axis::Y, 2, 2)
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImageView<'a>
+{
+    bytes:           &'a [u8],
+    width:           usize,
+    height:          usize,
+    pitch:           usize,
+    bytes_per_pixel: usize,
+}
+
+impl<'a> ImageView<'a>
+{
+    /// Creates a view of a `width` by `height` image with rows `pitch` bytes apart.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `ZeroChunk` if `bytes_per_pixel` is zero,
+    /// `EndOverflow` if the image size overflows, `LengthMismatch` containing the row length and
+    /// `pitch` if a row does not fit in `pitch`, or `EndRange` containing the required length
+    /// and the buffer length if `bytes` is too short.
+    pub fn new(
+        bytes: &'a [u8],
+        width: usize,
+        height: usize,
+        pitch: usize,
+        bytes_per_pixel: usize,
+    ) -> Result<Self, IndexError>
+    {
+        if bytes_per_pixel == 0
+        {
+            return Err(Error::new(ZeroChunk()));
+        }
+
+        let row = width.checked_mul(bytes_per_pixel).ok_or(Error::new(EndOverflow()))?;
+        if row > pitch
+        {
+            return Err(Error::new(LengthMismatch(row, pitch)));
+        }
+
+        let required = match height
+        {
+            | 0 => 0,
+            | _ => (height - 1)
+                .checked_mul(pitch)
+                .and_then(|n| n.checked_add(row))
+                .ok_or(Error::new(EndOverflow()))?,
+        };
+
+        match bytes.len()
+        {
+            | len if required > len => Err(Error::new(EndRange(required, len))),
+            | _ => Ok(ImageView { bytes, width, height, pitch, bytes_per_pixel }),
+        }
+    }
+
+    /// Returns the width of the image in pixels.
+    pub fn width(&self) -> usize
+    {
+        self.width
+    }
+
+    /// Returns the height of the image in pixels.
+    pub fn height(&self) -> usize
+    {
+        self.height
+    }
+
+    /// Returns the distance between the starts of consecutive rows in bytes.
+    pub fn pitch(&self) -> usize
+    {
+        self.pitch
+    }
+
+    /// Returns the pixel data of row `y`, excluding any padding.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `AxisBounds` if `y` is out of bounds.
+    pub fn row_checked(&self, y: usize) -> Result<&'a [u8], IndexError>
+    {
+        match y
+        {
+            | _ if y >= self.height => Err(Error::new(AxisBounds(Axis::Y, y, self.height))),
+            | _ => Ok(&self.bytes[y * self.pitch..][..self.width * self.bytes_per_pixel]),
+        }
+    }
+
+    /// Returns the bytes of the pixel at (`x`, `y`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `AxisBounds` naming the coordinate that is out of
+    /// bounds.
+    pub fn pixel_checked(&self, x: usize, y: usize) -> Result<&'a [u8], IndexError>
+    {
+        match x
+        {
+            | _ if x >= self.width => Err(Error::new(AxisBounds(Axis::X, x, self.width))),
+            | _ => Ok(&self.row_checked(y)?[x * self.bytes_per_pixel..][..self.bytes_per_pixel]),
+        }
+    }
+}
@@ -0,0 +1,218 @@
+#[cfg(feature = "arrayvec")]
+use arrayvec::ArrayVec;
+#[cfg(feature = "heapless")]
+use heapless::{
+    LenType,
+    string::{StringInner, StringStorage},
+    vec::{VecInner, VecStorage},
+};
+#[cfg(feature = "smallvec")]
+use smallvec::SmallVec;
+#[cfg(all(feature = "tinyvec", feature = "alloc"))]
+use tinyvec::TinyVec;
+#[cfg(feature = "tinyvec")]
+use tinyvec::{ArrayVec as TinyArrayVec, SliceVec};
+
+use crate::{GetChecked, GetCheckedMut};
+
+/// Indexes the elements of a `SmallVec` as a slice.
+///
+/// Only available with the `smallvec` feature.
+///
+/// # Examples
+/// ```
+/// # use get_checked::GetChecked;
+/// use smallvec::{SmallVec, smallvec};
+///
+/// fn first<S>(s: &S) -> Option<&u8>
+/// where S: GetChecked<u8, Target = [u8]>
+/// {
+///     s.get_checked(0).ok()
+/// }
+///
+/// let v: SmallVec<[u8; 4]> = smallvec![1, 2, 3];
+/// assert_eq!(first(&v), Some(&1));
+/// ```
+#[cfg(feature = "smallvec")]
+impl<A: smallvec::Array> GetChecked<A::Item> for SmallVec<A>
+{
+    type Target = [A::Item];
+
+    #[inline]
+    fn checked_target(&self) -> &[A::Item]
+    {
+        self
+    }
+}
+
+#[cfg(feature = "smallvec")]
+impl<A: smallvec::Array> GetCheckedMut<A::Item> for SmallVec<A>
+{
+    #[inline]
+    fn checked_target_mut(&mut self) -> &mut [A::Item]
+    {
+        self
+    }
+}
+
+/// Indexes the elements of an `arrayvec::ArrayVec` as a slice.
+///
+/// Only available with the `arrayvec` feature.
+#[cfg(feature = "arrayvec")]
+impl<T, const CAP: usize> GetChecked<T> for ArrayVec<T, CAP>
+{
+    type Target = [T];
+
+    #[inline]
+    fn checked_target(&self) -> &[T]
+    {
+        self
+    }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<T, const CAP: usize> GetCheckedMut<T> for ArrayVec<T, CAP>
+{
+    #[inline]
+    fn checked_target_mut(&mut self) -> &mut [T]
+    {
+        self
+    }
+}
+
+/// Indexes the elements of a `tinyvec::ArrayVec` as a slice.
+///
+/// Only available with the `tinyvec` feature.
+#[cfg(feature = "tinyvec")]
+impl<A: tinyvec::Array> GetChecked<A::Item> for TinyArrayVec<A>
+{
+    type Target = [A::Item];
+
+    #[inline]
+    fn checked_target(&self) -> &[A::Item]
+    {
+        self
+    }
+}
+
+#[cfg(feature = "tinyvec")]
+impl<A: tinyvec::Array> GetCheckedMut<A::Item> for TinyArrayVec<A>
+{
+    #[inline]
+    fn checked_target_mut(&mut self) -> &mut [A::Item]
+    {
+        self
+    }
+}
+
+/// Indexes the elements of a `tinyvec::SliceVec` as a slice.
+///
+/// Only available with the `tinyvec` feature.
+#[cfg(feature = "tinyvec")]
+impl<T> GetChecked<T> for SliceVec<'_, T>
+{
+    type Target = [T];
+
+    #[inline]
+    fn checked_target(&self) -> &[T]
+    {
+        self
+    }
+}
+
+#[cfg(feature = "tinyvec")]
+impl<T> GetCheckedMut<T> for SliceVec<'_, T>
+{
+    #[inline]
+    fn checked_target_mut(&mut self) -> &mut [T]
+    {
+        self
+    }
+}
+
+/// Indexes the elements of a `TinyVec` as a slice, whether they are stored inline or on the
+/// heap.
+///
+/// Only available with both the `tinyvec` and `alloc` features.
+#[cfg(all(feature = "tinyvec", feature = "alloc"))]
+impl<A: tinyvec::Array> GetChecked<A::Item> for TinyVec<A>
+{
+    type Target = [A::Item];
+
+    #[inline]
+    fn checked_target(&self) -> &[A::Item]
+    {
+        self
+    }
+}
+
+#[cfg(all(feature = "tinyvec", feature = "alloc"))]
+impl<A: tinyvec::Array> GetCheckedMut<A::Item> for TinyVec<A>
+{
+    #[inline]
+    fn checked_target_mut(&mut self) -> &mut [A::Item]
+    {
+        self
+    }
+}
+
+/// Indexes the elements of a `heapless::Vec`, or a `heapless::VecView`, as a slice.
+///
+/// Only available with the `heapless` feature.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{GetChecked, IndexErrorKind};
+/// let mut v = heapless::Vec::<u8, 4>::new();
+/// v.extend_from_slice(&[1, 2, 3]).unwrap();
+///
+/// assert_eq!(v.get_checked(1..), Ok(&[2, 3][..]));
+/// assert_eq!(*v.get_checked(3).unwrap_err().kind(), IndexErrorKind::Bounds(3, 3));
+/// ```
+#[cfg(feature = "heapless")]
+impl<T, L: LenType, S: VecStorage<T> + ?Sized> GetChecked<T> for VecInner<T, L, S>
+{
+    type Target = [T];
+
+    #[inline]
+    fn checked_target(&self) -> &[T]
+    {
+        self
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<T, L: LenType, S: VecStorage<T> + ?Sized> GetCheckedMut<T> for VecInner<T, L, S>
+{
+    #[inline]
+    fn checked_target_mut(&mut self) -> &mut [T]
+    {
+        self
+    }
+}
+
+/// Indexes the contents of a `heapless::String`, or a `heapless::StringView`, by byte ranges, as
+/// for `str`.
+///
+/// Only available with the `heapless` feature.
+#[cfg(feature = "heapless")]
+impl<L: LenType, S: StringStorage + ?Sized> GetChecked<u8> for StringInner<L, S>
+{
+    type Target = str;
+
+    #[inline]
+    fn checked_target(&self) -> &str
+    {
+        self
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<L: LenType, S: StringStorage + ?Sized> GetCheckedMut<u8> for StringInner<L, S>
+{
+    #[inline]
+    fn checked_target_mut(&mut self) -> &mut str
+    {
+        self
+    }
+}
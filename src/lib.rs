@@ -1,5 +1,6 @@
 #![cfg_attr(feature = "no_std", no_std)]
 #![warn(missing_docs)]
+#![allow(clippy::borrow_deref_ref)]
 //! This crate provides [`GetChecked`] and [`GetCheckedSliceIndex`] traits which provide
 //! `get_checked` and `get_checked_mut` methods for [`array`] and [`slice`] types.
 //!
@@ -80,25 +81,246 @@
 //! {
 //!     assert_eq!(
 //!         foo().unwrap_err().to_string(),
-//!         "My Error: index out of bounds: the len is 4 but the index is 3"
+//!         "My Error: index out of bounds: the len is 3 but the index is 4"
 //!     );
 //! }
 //! ```
+//!
+//! # Zero-sized types
+//! Slices of zero-sized types such as `()` never allocate, so their length can reach
+//! `usize::MAX`, well past the `isize::MAX` byte limit of other slices. Checked access handles
+//! every length exactly as std indexing does: for example, a range with an inclusive end of
+//! `usize::MAX` is out of range even for a slice of length `usize::MAX`. Operations that grow a
+//! `Vec` report `CapacityOverflow` instead of panicking when the new length would exceed the
+//! maximum for the element type.
+//!
+//! # Features
+//! - `no_std`: Builds the crate without the standard library.
+//! - `alloc`: Implements `GetChecked` for `Vec<T>`, `Box<[T]>`, `Rc<[T]>`, `Arc<[T]>` and
+//!   `Cow<[T]>` in `no_std` builds. These are always implemented when `no_std` is disabled.
+//! - `anyhow`: Provides the `IndexContext` extension trait for converting errors into
+//!   `anyhow::Error` values annotated with the caller location.
+//! - `mmap`: Implements [`GetChecked`] for `memmap2::Mmap` and `memmap2::MmapMut`.
+//! - `derive`: Provides `#[derive(ReadChecked)]` for reading structs of integer fields from byte
+//!   buffers.
+//! - `edge_cases`: Widens the `unsafe_paths` test suite, meant to run under Miri, with
+//!   zero-sized slices of length `usize::MAX` and more indices. Has no effect on the library.
+//! - `ropey`: Provides the `GetCheckedRope` extension trait for checked char and line access on
+//!   `ropey::Rope`.
+//! - `im`: Provides the `GetCheckedImVector` extension trait for checked access on the
+//!   persistent `im::Vector`.
+//! - `slab`: Provides the `GetCheckedSlab` extension trait for checked keyed access on
+//!   `slab::Slab`.
+//! - `slotmap`: Provides the `GetCheckedSlotMap` extension trait for checked keyed access on
+//!   `slotmap::SlotMap`, reporting removed keys as stale.
+//! - `indexmap`: Provides the `GetCheckedIndexMap` and `GetCheckedIndexSet` extension traits for
+//!   checked positional, range and keyed access on `indexmap` collections. Requires `std`.
+//! - `http`: Provides `IndexError::http_status` and `IndexError::problem_details` for mapping
+//!   errors caused by client-provided offsets to HTTP responses, and `validate_http_range` for
+//!   resolving `Range` header values. Requires `std`.
+//! - `bytemuck`: Provides the `GetPodChecked` extension trait for checked, alignment-aware access
+//!   to plain-old-data structs stored in byte buffers.
+//! - `crc`: Provides the `GetCrcChecked` extension trait for computing `crc` crate checksums over
+//!   validated ranges of byte buffers.
+//! - `quickcheck`: Implements `quickcheck::Arbitrary` for `IndexErrorKind` and `Axis`, and
+//!   provides the `ArbitraryIndex` newtype for generating edge-biased indices. Requires `std`.
+//! - `smallvec`: Implements `GetChecked` for `smallvec::SmallVec`.
+//! - `arrayvec`: Implements `GetChecked` for `arrayvec::ArrayVec`.
+//! - `tinyvec`: Implements `GetChecked` for `tinyvec::ArrayVec` and `tinyvec::SliceVec`, and
+//!   for `tinyvec::TinyVec` if the `alloc` feature is also enabled.
+//! - `heapless`: Implements `GetChecked` for `heapless::Vec` and `heapless::String`, and
+//!   `GetCheckedDeque` for `heapless::Deque`. Compatible with `no_std`.
+//! - `bytes`: Implements `GetChecked` for `bytes::Bytes` and `bytes::BytesMut`, and provides the
+//!   `GetCheckedBytes` extension trait for checked zero-copy slicing of `Bytes`.
+//!
 //! [`get`]:              slice::get
 //! [`get_mut`]:          slice::get_mut
 
 use core::ops::{self, Bound, RangeBounds};
 
+#[cfg(any(feature = "alloc", not(feature = "no_std")))]
+extern crate alloc;
+#[cfg(all(feature = "derive", test))]
+extern crate self as get_checked;
+
+#[cfg(not(feature = "no_std"))]
+mod arena;
+mod ascii;
+mod atomic;
+mod bounds;
+#[cfg(feature = "bytes")]
+mod buf;
+mod cell;
+#[cfg(feature = "crc")]
+mod checksum;
+#[cfg(all(feature = "anyhow", not(feature = "no_std")))]
+mod context;
+#[cfg(not(feature = "no_std"))]
+mod coverage;
+mod cursor;
+mod debug;
+#[cfg(any(feature = "heapless", not(feature = "no_std")))]
+mod deque;
+mod dynamic;
+mod endian;
+#[cfg(not(feature = "no_std"))]
+mod env;
 mod error;
-
-pub use error::{IndexError, IndexErrorKind};
+mod ffi;
+mod fill;
+mod fixed;
+mod flat;
+mod frame;
+#[cfg(all(feature = "quickcheck", not(feature = "no_std")))]
+mod generate;
+#[cfg(not(feature = "no_std"))]
+mod guarded;
+#[cfg(not(feature = "no_std"))]
+mod heap;
+mod hex;
+mod image;
+#[cfg(any(feature = "smallvec", feature = "arrayvec", feature = "tinyvec", feature = "heapless"))]
+mod inline;
+mod limited;
+#[cfg(not(feature = "no_std"))]
+mod list;
+#[cfg(not(feature = "no_std"))]
+mod map;
+mod metered;
+#[cfg(feature = "mmap")]
+mod mmap;
+mod nibble;
+#[cfg(all(feature = "indexmap", not(feature = "no_std")))]
+mod ordered;
+#[cfg(any(feature = "alloc", not(feature = "no_std")))]
+mod owned;
+mod page;
+mod parse;
+#[cfg(feature = "im")]
+mod persistent;
+#[cfg(feature = "bytemuck")]
+mod pod;
+pub mod prelude;
+#[cfg(kani)]
+mod proofs;
+mod records;
+mod recover;
+mod reverse;
+#[cfg(feature = "ropey")]
+mod rope;
+#[cfg(not(feature = "no_std"))]
+mod scatter;
+mod search;
+mod segmented;
+mod seq;
+#[cfg(any(feature = "slab", feature = "slotmap"))]
+mod slots;
+mod sorted;
+#[cfg(all(feature = "http", not(feature = "no_std")))]
+mod status;
+#[cfg(not(feature = "no_std"))]
+mod table;
+mod text;
+mod tlv;
+mod traced;
+mod uninit;
+mod utf16;
+#[cfg(not(feature = "no_std"))]
+mod vec;
+mod window;
+
+#[cfg(not(feature = "no_std"))]
+pub use arena::{ArenaSlice, Handle};
+pub use ascii::GetAsciiChecked;
+pub use atomic::GetCheckedAtomic;
+pub use bounds::{overlap_checked, resolve_bounds, try_range, union_checked};
+#[cfg(feature = "bytes")]
+pub use buf::GetCheckedBytes;
+pub use cell::GetCheckedCell;
+#[cfg(feature = "crc")]
+pub use checksum::{CrcAlgorithm, GetCrcChecked};
+#[cfg(all(feature = "anyhow", not(feature = "no_std")))]
+pub use context::IndexContext;
+#[cfg(not(feature = "no_std"))]
+pub use coverage::Coverage;
+pub use cursor::{BacktrackError, Checkpoint, IndexCursor};
+pub use debug::GetCheckedDebug;
+#[cfg(any(feature = "heapless", not(feature = "no_std")))]
+pub use deque::GetCheckedDeque;
+pub use dynamic::DynGetChecked;
+pub use endian::{Endian, LenPrefix, ReadChecked, ReadEndianChecked, WriteEndianChecked};
+#[cfg(not(feature = "no_std"))]
+pub use env::{args_checked, vars_checked};
+#[cfg(not(feature = "no_std"))]
+pub use error::KeyError;
+pub use error::{Axis, IndexError, IndexErrorKind, MessageStyle};
+#[cfg(not(feature = "no_std"))]
+pub use ffi::GetCheckedPath;
+pub use fill::FillPatternChecked;
+pub use fixed::{ConstIndex, FixedLen, MinLen};
+pub use flat::GetFlatChecked;
+pub use frame::{Channels, GetFrameChecked};
+#[cfg(all(feature = "quickcheck", not(feature = "no_std")))]
+pub use generate::ArbitraryIndex;
+#[cfg(feature = "derive")]
+pub use get_checked_derive::ReadChecked;
+#[cfg(not(feature = "no_std"))]
+pub use guarded::{GetCheckedLocked, LockError};
+#[cfg(not(feature = "no_std"))]
+pub use heap::GetCheckedHeap;
+pub use hex::{Hex, HexChecked};
+pub use image::{GetPixelChecked, ImageView};
+pub use limited::LimitedSlice;
+#[cfg(not(feature = "no_std"))]
+pub use list::GetCheckedList;
+#[cfg(not(feature = "no_std"))]
+pub use map::{GetCheckedBTreeMap, GetCheckedBTreeSet, GetCheckedMap};
+pub use metered::Metered;
+pub use nibble::GetNibbleChecked;
+#[cfg(all(feature = "indexmap", not(feature = "no_std")))]
+pub use ordered::{GetCheckedIndexMap, GetCheckedIndexSet};
+pub use page::GetPageChecked;
+pub use parse::{ParseChecked, ParseError};
+#[cfg(feature = "im")]
+pub use persistent::GetCheckedImVector;
+#[cfg(feature = "bytemuck")]
+pub use pod::GetPodChecked;
+pub use records::{FixedFields, GetRecordChecked};
+pub use recover::IndexRecover;
+pub use reverse::GetFromEndChecked;
+#[cfg(feature = "ropey")]
+pub use rope::GetCheckedRope;
+#[cfg(not(feature = "no_std"))]
+pub use scatter::{GetUniqueChecked, SetAllChecked};
+pub use search::{FindSubsliceChecked, StripChecked};
+pub use segmented::Segmented;
+pub use seq::GetCheckedSeq;
+#[cfg(feature = "slab")]
+pub use slots::GetCheckedSlab;
+#[cfg(feature = "slotmap")]
+pub use slots::GetCheckedSlotMap;
+pub use sorted::SortedSlice;
+#[cfg(all(feature = "http", not(feature = "no_std")))]
+pub use status::{HttpRange, ProblemDetails, validate_http_range};
+#[cfg(not(feature = "no_std"))]
+pub use table::{MultiIndexError, resolve_offset_table_checked, validate_ranges_checked};
+pub use text::{GetCheckedStr, byte_to_char_index_checked, char_to_byte_index_checked};
+pub use tlv::{GetTlvChecked, TlvConfig, TlvRecords};
+pub use traced::{Access, AccessRecord, Debounced, Traced};
+pub use uninit::{GetCheckedUninit, PartialInit};
+pub use utf16::GetUtf16Checked;
+#[cfg(not(feature = "no_std"))]
+pub use vec::{GetCheckedVec, Growth, VecWriter, WriteError};
+pub use window::{EdgePolicy, GetWindowChecked, StridedWindows};
 
 /// Type definition of [`IndexError`].
 pub type Error = error::IndexError;
 /// Type definition of [`IndexErrorKind`].
 pub type ErrorKind = error::IndexErrorKind;
 
-use error::IndexErrorKind::{Bounds, EndOverflow, EndRange, Order, StartOverflow, StartRange};
+use error::IndexErrorKind::{
+    Bounds, EndOverflow, EndRange, Order, Rejected, StartOverflow, StartRange,
+};
 
 #[cfg(test)]
 mod tests;
@@ -138,14 +360,14 @@ pub trait GetCheckedSliceIndex<T: ?Sized>
     /// # use get_checked::GetCheckedSliceIndex;
     /// let v = [10, 40, 30];
     /// assert_eq!(Ok(&40), 1.get_checked(&v));
-    /// assert_eq!(Ok(&[10, 40][..]), (0..2).get_checked(&v));
+    /// assert_eq!(Ok(&[10, 40][..]), (0..2).get_checked(&v[..]));
     ///
     /// if let Err(e) = 3.get_checked(&v)
     /// {
     ///     println!("Index error: {}", e);
     /// }
     ///
-    /// if let Err(e) = (2..4).get_checked(&v)
+    /// if let Err(e) = (2..4).get_checked(&v[..])
     /// {
     ///     println!("Index error: {}", e);
     /// }
@@ -185,7 +407,7 @@ pub trait GetCheckedSliceIndex<T: ?Sized>
     ///     println!("Index error: {}", e);
     /// }
     ///
-    /// if let Err(e) = (2..4).get_checked_mut(&mut v)
+    /// if let Err(e) = (2..4).get_checked_mut(&mut v[..])
     /// {
     ///     println!("Index error: {}", e);
     /// }
@@ -203,7 +425,7 @@ impl<T> GetCheckedSliceIndex<[T]> for usize
         match self
         {
             | _ if self < slice.len() => unsafe { Ok(&*slice.get_unchecked(self)) },
-            | _ => Err(Error { kind: Bounds(self, slice.len()) }),
+            | _ => Err(Error::new(Bounds(self, slice.len()))),
         }
     }
 
@@ -213,7 +435,7 @@ impl<T> GetCheckedSliceIndex<[T]> for usize
         match self
         {
             | _ if self < slice.len() => unsafe { Ok(&mut *slice.get_unchecked_mut(self)) },
-            | _ => Err(Error { kind: Bounds(self, slice.len()) }),
+            | _ => Err(Error::new(Bounds(self, slice.len()))),
         }
     }
 }
@@ -225,11 +447,12 @@ impl<T> GetCheckedSliceIndex<[T]> for ops::Range<usize>
     #[inline] #[rustfmt::skip]
     fn get_checked(self, slice: &[T]) -> Result<&[T], IndexError>
     {
-        let len = slice.len();
+        let (start, end, len) = (self.start, self.end, slice.len());
         match self
         {
-            | _ if self.start > self.end => Err(Error { kind: Order(self.start, self.end) }),
-            | _ if self.end > len => Err(Error { kind: EndRange(self.end, len) }),
+            | _ if start > len => Err(Error::new(StartRange(start, len)).with_range(start, end, len)),
+            | _ if start > end => Err(Error::new(Order(start, end)).with_range(start, end, len)),
+            | _ if end > len => Err(Error::new(EndRange(end, len)).with_range(start, end, len)),
             | _ => unsafe { Ok(&*slice.get_unchecked(self)) },
         }
     }
@@ -237,11 +460,12 @@ impl<T> GetCheckedSliceIndex<[T]> for ops::Range<usize>
     #[inline] #[rustfmt::skip]
     fn get_checked_mut(self, slice: &mut [T]) -> Result<&mut [T], IndexError>
     {
-        let len = slice.len();
+        let (start, end, len) = (self.start, self.end, slice.len());
         match self
         {
-            | _ if self.start > self.end => Err(Error { kind: Order(self.start, self.end) }),
-            | _ if self.end > len => Err(Error { kind: EndRange(self.end, len) }),
+            | _ if start > len => Err(Error::new(StartRange(start, len)).with_range(start, end, len)),
+            | _ if start > end => Err(Error::new(Order(start, end)).with_range(start, end, len)),
+            | _ if end > len => Err(Error::new(EndRange(end, len)).with_range(start, end, len)),
             | _ => unsafe { Ok(&mut *slice.get_unchecked_mut(self)) },
         }
     }
@@ -256,7 +480,7 @@ impl<T> GetCheckedSliceIndex<[T]> for ops::RangeTo<usize>
     {
         let end = match self.end_bound()
         {
-            | Bound::Included(x) => x.checked_add(1).ok_or(Error { kind: EndOverflow() })?,
+            | Bound::Included(x) => x.checked_add(1).ok_or(Error::new(EndOverflow()))?,
             | Bound::Excluded(x) => *x,
             | Bound::Unbounded => slice.len(),
         };
@@ -265,7 +489,7 @@ impl<T> GetCheckedSliceIndex<[T]> for ops::RangeTo<usize>
 
         match slice
         {
-            | _ if end > len => Err(Error { kind: EndRange(end, len) })?,
+            | _ if end > len => Err(Error::new(EndRange(end, len)).with_range(0, end, len))?,
             | _ => Ok(unsafe { &*slice.get_unchecked(self) }),
         }
     }
@@ -275,7 +499,7 @@ impl<T> GetCheckedSliceIndex<[T]> for ops::RangeTo<usize>
     {
         let end = match self.end_bound()
         {
-            | Bound::Included(x) => x.checked_add(1).ok_or(Error { kind: EndOverflow() })?,
+            | Bound::Included(x) => x.checked_add(1).ok_or(Error::new(EndOverflow()))?,
             | Bound::Excluded(x) => *x,
             | Bound::Unbounded => slice.len(),
         };
@@ -284,7 +508,7 @@ impl<T> GetCheckedSliceIndex<[T]> for ops::RangeTo<usize>
 
         match slice
         {
-            | _ if end > len => Err(Error { kind: EndRange(end, len) })?,
+            | _ if end > len => Err(Error::new(EndRange(end, len)).with_range(0, end, len))?,
             | _ => Ok(unsafe { &mut *slice.get_unchecked_mut(self) }),
         }
     }
@@ -300,7 +524,7 @@ impl<T> GetCheckedSliceIndex<[T]> for ops::RangeFrom<usize>
         let start = match self.start_bound()
         {
             | Bound::Included(x) => *x,
-            | Bound::Excluded(x) => x.checked_add(1).ok_or(Error { kind: StartOverflow() })?,
+            | Bound::Excluded(x) => x.checked_add(1).ok_or(Error::new(StartOverflow()))?,
             | Bound::Unbounded => 0,
         };
 
@@ -308,7 +532,10 @@ impl<T> GetCheckedSliceIndex<[T]> for ops::RangeFrom<usize>
 
         match slice
         {
-            | _ if start > len => Err(Error { kind: StartRange(start, len) })?,
+            | _ if start > len =>
+            {
+                Err(Error::new(StartRange(start, len)).with_range(start, len, len))?
+            },
             | _ => Ok(unsafe { &*slice.get_unchecked(self) }),
         }
     }
@@ -319,7 +546,7 @@ impl<T> GetCheckedSliceIndex<[T]> for ops::RangeFrom<usize>
         let start = match self.start_bound()
         {
             | Bound::Included(x) => *x,
-            | Bound::Excluded(x) => x.checked_add(1).ok_or(Error { kind: StartOverflow() })?,
+            | Bound::Excluded(x) => x.checked_add(1).ok_or(Error::new(StartOverflow()))?,
             | Bound::Unbounded => 0,
         };
 
@@ -327,7 +554,10 @@ impl<T> GetCheckedSliceIndex<[T]> for ops::RangeFrom<usize>
 
         match slice
         {
-            | _ if start > len => Err(Error { kind: StartRange(start, len) })?,
+            | _ if start > len =>
+            {
+                Err(Error::new(StartRange(start, len)).with_range(start, len, len))?
+            },
             | _ => Ok(unsafe { &mut *slice.get_unchecked_mut(self) }),
         }
     }
@@ -357,55 +587,15 @@ impl<T> GetCheckedSliceIndex<[T]> for ops::RangeInclusive<usize>
     #[inline]
     fn get_checked(self, slice: &[T]) -> Result<&[T], IndexError>
     {
-        let start = match self.start_bound()
-        {
-            | Bound::Included(x) => *x,
-            | Bound::Excluded(x) => x.checked_add(1).ok_or(Error { kind: StartOverflow() })?,
-            | Bound::Unbounded => 0,
-        };
-
-        let end = match self.end_bound()
-        {
-            | Bound::Included(x) => x.checked_add(1).ok_or(Error { kind: EndOverflow() })?,
-            | Bound::Excluded(x) => *x,
-            | Bound::Unbounded => slice.len(),
-        };
-
-        let len = slice.len();
-
-        match slice
-        {
-            | _ if start > end => Err(Error { kind: Order(start, end) })?,
-            | _ if end > len => Err(Error { kind: EndRange(end, len) })?,
-            | _ => Ok(unsafe { &*slice.get_unchecked(self) }),
-        }
+        let range = try_range(self, slice.len())?;
+        Ok(unsafe { slice.get_unchecked(range) })
     }
 
     #[inline]
     fn get_checked_mut(self, slice: &mut [T]) -> Result<&mut [T], IndexError>
     {
-        let start = match self.start_bound()
-        {
-            | Bound::Included(x) => *x,
-            | Bound::Excluded(x) => x.checked_add(1).ok_or(Error { kind: StartOverflow() })?,
-            | Bound::Unbounded => 0,
-        };
-
-        let end = match self.end_bound()
-        {
-            | Bound::Included(x) => x.checked_add(1).ok_or(Error { kind: EndOverflow() })?,
-            | Bound::Excluded(x) => *x,
-            | Bound::Unbounded => slice.len(),
-        };
-
-        let len = slice.len();
-
-        match slice
-        {
-            | _ if start > end => Err(Error { kind: Order(start, end) })?,
-            | _ if end > len => Err(Error { kind: EndRange(end, len) })?,
-            | _ => Ok(unsafe { &mut *slice.get_unchecked_mut(self) }),
-        }
+        let range = try_range(self, slice.len())?;
+        Ok(unsafe { &mut *slice.get_unchecked_mut(range) })
     }
 }
 
@@ -428,10 +618,36 @@ impl<T> GetCheckedSliceIndex<[T]> for ops::RangeToInclusive<usize>
 
 /// Trait adding [`get_checked`] and [`get_checked_mut`] Indexing implementations to `[T]`.
 ///
+/// The trait is also implemented for shared and mutable references to implementing types, so
+/// generic code bounded on `GetChecked` accepts `&[T]` and `&mut [T]` alike:
+/// ```
+/// # use get_checked::{GetChecked, GetCheckedSliceIndex};
+/// fn first<S>(s: S) -> Option<u8>
+/// where
+///     S: GetChecked<u8>,
+///     usize: GetCheckedSliceIndex<S::Target, Output = u8>,
+/// {
+///     s.get_checked(0).ok().copied()
+/// }
+///
+/// let mut v = [1, 2, 3];
+/// assert_eq!(first(&v[..]), Some(1));
+/// assert_eq!(first(&mut v[..]), Some(1));
+/// ```
+///
 /// [`get_checked`]: GetChecked::get_checked
 /// [`get_checked_mut`]: GetChecked::get_checked_mut
 pub trait GetChecked<T>
 {
+    /// The type indexed by [`get_checked`] and [`get_checked_mut`].
+    ///
+    /// [`get_checked`]: GetChecked::get_checked
+    /// [`get_checked_mut`]: GetChecked::get_checked_mut
+    type Target: ?Sized;
+
+    /// Returns a reference to the indexed [`Target`](GetChecked::Target).
+    fn checked_target(&self) -> &Self::Target;
+
     /// Accepts a [`usize`] or [`range`] and returns a `Result` containing a reference to an
     /// element or subslice corresponding to the index.
     ///
@@ -470,10 +686,12 @@ pub trait GetChecked<T>
     /// }
     /// ```
     #[inline]
-    fn get_checked<I>(&self, index: I) -> Result<&I::Output, IndexError>
-    where I: GetCheckedSliceIndex<Self>
+    fn get_checked<'a, I>(&'a self, index: I) -> Result<&'a I::Output, IndexError>
+    where
+        I: GetCheckedSliceIndex<Self::Target>,
+        Self::Target: 'a,
     {
-        index.get_checked(self)
+        index.get_checked(self.checked_target())
     }
 
     /// Accepts a `usize` or `range` and returns a `Result` containing a mutable reference
@@ -516,11 +734,139 @@ pub trait GetChecked<T>
     /// }
     /// ```
     #[inline]
-    fn get_checked_mut<I>(&mut self, index: I) -> Result<&mut I::Output, IndexError>
-    where I: GetCheckedSliceIndex<Self>
+    fn get_checked_mut<'a, I>(&'a mut self, index: I) -> Result<&'a mut I::Output, IndexError>
+    where
+        I: GetCheckedSliceIndex<Self::Target>,
+        Self: GetCheckedMut<T>,
+        Self::Target: 'a,
+    {
+        index.get_checked_mut(self.checked_target_mut())
+    }
+
+    /// Returns a reference to the element at `index` if it satisfies `predicate`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Bounds` if `index` is out of bounds, or `Rejected`
+    /// containing `index` if the element does not satisfy `predicate`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use get_checked::{GetChecked, IndexErrorKind};
+    /// let v = [10, 0, 30];
+    /// assert_eq!(v.get_if_checked(0, |x| *x != 0), Ok(&10));
+    /// assert_eq!(*v.get_if_checked(1, |x| *x != 0).unwrap_err().kind(), IndexErrorKind::Rejected(1));
+    /// assert_eq!(*v.get_if_checked(3, |x| *x != 0).unwrap_err().kind(), IndexErrorKind::Bounds(3, 3));
+    /// ```
+    #[inline]
+    fn get_if_checked<'a, P>(&'a self, index: usize, predicate: P) -> Result<&'a T, IndexError>
+    where
+        usize: GetCheckedSliceIndex<Self::Target, Output = T>,
+        P: FnOnce(&T) -> bool,
+        Self::Target: 'a,
+    {
+        match self.get_checked(index)?
+        {
+            | elem if predicate(elem) => Ok(elem),
+            | _ => Err(Error::new(Rejected(index))),
+        }
+    }
+}
+
+/// Companion of [`GetChecked`] for types whose [`Target`](GetChecked::Target) can be borrowed
+/// mutably, enabling [`get_checked_mut`](GetChecked::get_checked_mut).
+pub trait GetCheckedMut<T>: GetChecked<T>
+{
+    /// Returns a mutable reference to the indexed [`Target`](GetChecked::Target).
+    fn checked_target_mut(&mut self) -> &mut Self::Target;
+}
+
+impl<T> GetChecked<T> for [T]
+{
+    type Target = [T];
+
+    #[inline]
+    fn checked_target(&self) -> &[T]
+    {
+        self
+    }
+}
+
+impl<T> GetCheckedMut<T> for [T]
+{
+    #[inline]
+    fn checked_target_mut(&mut self) -> &mut [T]
     {
-        index.get_checked_mut(self)
+        self
     }
 }
 
-impl<T> GetChecked<T> for [T] {}
+/// Indexes arrays as slices, so arrays satisfy [`GetChecked`] bounds in generic code without
+/// unsized coercion.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{GetChecked, GetCheckedSliceIndex};
+/// fn last<S>(s: &S) -> Option<&u8>
+/// where S: GetChecked<u8, Target = [u8]>
+/// {
+///     let len = s.checked_target().len();
+///     s.get_checked(len.checked_sub(1)?).ok()
+/// }
+///
+/// assert_eq!(last(&[1, 2, 3]), Some(&3));
+/// assert_eq!(last(&[]), None);
+/// ```
+impl<T, const N: usize> GetChecked<T> for [T; N]
+{
+    type Target = [T];
+
+    #[inline]
+    fn checked_target(&self) -> &[T]
+    {
+        self
+    }
+}
+
+impl<T, const N: usize> GetCheckedMut<T> for [T; N]
+{
+    #[inline]
+    fn checked_target_mut(&mut self) -> &mut [T]
+    {
+        self
+    }
+}
+
+impl<T, S> GetChecked<T> for &S
+where S: GetChecked<T> + ?Sized
+{
+    type Target = S::Target;
+
+    #[inline]
+    fn checked_target(&self) -> &S::Target
+    {
+        (**self).checked_target()
+    }
+}
+
+impl<T, S> GetChecked<T> for &mut S
+where S: GetChecked<T> + ?Sized
+{
+    type Target = S::Target;
+
+    #[inline]
+    fn checked_target(&self) -> &S::Target
+    {
+        (**self).checked_target()
+    }
+}
+
+impl<T, S> GetCheckedMut<T> for &mut S
+where S: GetCheckedMut<T> + ?Sized
+{
+    #[inline]
+    fn checked_target_mut(&mut self) -> &mut S::Target
+    {
+        (**self).checked_target_mut()
+    }
+}
@@ -0,0 +1,131 @@
+use core::ops::RangeBounds;
+
+use crate::{
+    Error, IndexError,
+    IndexErrorKind::{Bounds, EndRange, LimitExceeded, StartRange},
+    try_range,
+};
+
+/// A slice whose accessible length is capped at an application-defined limit, such as the
+/// maximum size of a protocol message.
+///
+/// Accesses beyond the limit fail with an [`IndexError`] of kind `LimitExceeded`, even if the
+/// underlying slice is longer. If the slice is no longer than the limit, accesses behave exactly
+/// as they would on the slice itself.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{IndexErrorKind, LimitedSlice};
+/// let buf = [1, 2, 3, 4, 5, 6];
+/// let msg = LimitedSlice::new(&buf, 4);
+///
+/// assert_eq!(msg.len(), 4);
+/// assert_eq!(msg.get_checked(3), Ok(&4));
+/// assert_eq!(*msg.get_checked(4).unwrap_err().kind(), IndexErrorKind::LimitExceeded(4, 4));
+/// assert_eq!(
+///     *msg.get_range_checked(2..6).unwrap_err().kind(),
+///     IndexErrorKind::LimitExceeded(6, 4)
+/// );
+/// ```
+#[derive(Debug)]
+pub struct LimitedSlice<'a, T>
+{
+    slice: &'a [T],
+    limit: usize,
+}
+
+impl<T> Clone for LimitedSlice<'_, T>
+{
+    fn clone(&self) -> Self
+    {
+        *self
+    }
+}
+
+impl<T> Copy for LimitedSlice<'_, T> {}
+
+impl<'a, T> LimitedSlice<'a, T>
+{
+    /// Wraps `slice`, allowing access to at most its first `limit` elements.
+    #[inline]
+    pub fn new(slice: &'a [T], limit: usize) -> Self
+    {
+        LimitedSlice { slice, limit }
+    }
+
+    /// Returns the limit on the accessible length.
+    #[inline]
+    pub fn limit(&self) -> usize
+    {
+        self.limit
+    }
+
+    /// Returns the number of accessible elements, which is the smaller of the limit and the
+    /// length of the underlying slice.
+    #[inline]
+    pub fn len(&self) -> usize
+    {
+        self.slice.len().min(self.limit)
+    }
+
+    /// Returns `true` if no elements are accessible.
+    #[inline]
+    pub fn is_empty(&self) -> bool
+    {
+        self.len() == 0
+    }
+
+    /// Returns a reference to the element at `index`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `LimitExceeded` containing `index` and the limit if
+    /// `index` is not below the limit and the slice is longer than the limit, or `Bounds` if
+    /// `index` is otherwise out of bounds.
+    pub fn get_checked(&self, index: usize) -> Result<&'a T, IndexError>
+    {
+        match self.as_slice().get(index)
+        {
+            | Some(elem) => Ok(elem),
+            | None if self.is_capped() => Err(Error::new(LimitExceeded(index, self.limit))),
+            | None => Err(Error::new(Bounds(index, self.slice.len()))),
+        }
+    }
+
+    /// Returns the subslice corresponding to `range`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `LimitExceeded` containing the offending bound and the
+    /// limit if `range` extends past the limit and the slice is longer than the limit. Otherwise
+    /// returns an `IndexError` describing the invalid range as
+    /// [`get_checked`](crate::GetChecked::get_checked) would.
+    pub fn get_range_checked<R>(&self, range: R) -> Result<&'a [T], IndexError>
+    where R: RangeBounds<usize>
+    {
+        try_range(range, self.len()).map(|range| &self.slice[range]).map_err(|mut err| {
+            match err.kind
+            {
+                | StartRange(index, _) | EndRange(index, _) if self.is_capped() =>
+                {
+                    err.kind = LimitExceeded(index, self.limit);
+                    err
+                },
+                | _ => err,
+            }
+        })
+    }
+
+    /// Returns the accessible elements as a slice.
+    #[inline]
+    pub fn as_slice(&self) -> &'a [T]
+    {
+        &self.slice[..self.len()]
+    }
+
+    /// Returns `true` if the limit hides part of the underlying slice.
+    fn is_capped(&self) -> bool
+    {
+        self.limit < self.slice.len()
+    }
+}
@@ -0,0 +1,70 @@
+use std::collections::LinkedList;
+
+use crate::{Error, IndexError, IndexErrorKind::Bounds};
+
+/// Trait adding positional checked access to [`LinkedList`].
+///
+/// Access walks the list from whichever end is closer, so it runs in `O(n)` time.
+///
+/// Only available when the `no_std` feature is disabled.
+pub trait GetCheckedList<T>
+{
+    /// Returns a reference to the `n`th element of the list.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Bounds` if `n` is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// # use get_checked::{GetCheckedList, IndexErrorKind};
+    /// use std::collections::LinkedList;
+    ///
+    /// let list: LinkedList<_> = vec![1, 2, 3].into_iter().collect();
+    /// assert_eq!(list.nth_checked(2), Ok(&3));
+    /// assert_eq!(*list.nth_checked(3).unwrap_err().kind(), IndexErrorKind::Bounds(3, 3));
+    /// ```
+    fn nth_checked(&self, n: usize) -> Result<&T, IndexError>;
+
+    /// Returns a mutable reference to the `n`th element of the list.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Bounds` if `n` is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// # use get_checked::GetCheckedList;
+    /// use std::collections::LinkedList;
+    ///
+    /// let mut list: LinkedList<_> = vec![1, 2, 3].into_iter().collect();
+    /// *list.nth_checked_mut(0).unwrap() = 7;
+    /// assert_eq!(list.front(), Some(&7));
+    /// ```
+    fn nth_checked_mut(&mut self, n: usize) -> Result<&mut T, IndexError>;
+}
+
+impl<T> GetCheckedList<T> for LinkedList<T>
+{
+    fn nth_checked(&self, n: usize) -> Result<&T, IndexError>
+    {
+        let len = self.len();
+        match n
+        {
+            | _ if n >= len => Err(Error::new(Bounds(n, len))),
+            | _ if n < len / 2 => self.iter().nth(n).ok_or(Error::new(Bounds(n, len))),
+            | _ => self.iter().rev().nth(len - 1 - n).ok_or(Error::new(Bounds(n, len))),
+        }
+    }
+
+    fn nth_checked_mut(&mut self, n: usize) -> Result<&mut T, IndexError>
+    {
+        let len = self.len();
+        match n
+        {
+            | _ if n >= len => Err(Error::new(Bounds(n, len))),
+            | _ if n < len / 2 => self.iter_mut().nth(n).ok_or(Error::new(Bounds(n, len))),
+            | _ => self.iter_mut().rev().nth(len - 1 - n).ok_or(Error::new(Bounds(n, len))),
+        }
+    }
+}
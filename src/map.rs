@@ -0,0 +1,243 @@
+use core::{
+    borrow::Borrow,
+    fmt::Debug,
+    hash::{BuildHasher, Hash},
+    ops::{
+        Bound::{self, Excluded, Included, Unbounded},
+        RangeBounds,
+    },
+};
+use std::collections::{BTreeMap, BTreeSet, HashMap, btree_map, btree_set};
+
+use crate::{Error, IndexError, IndexErrorKind::Order, KeyError};
+
+/// Trait adding checked keyed access to [`HashMap`] and [`BTreeMap`].
+///
+/// Lookups of a missing key fail with a [`KeyError`] holding the key's [`Debug`] representation
+/// and the length of the map.
+///
+/// Only available when the `no_std` feature is disabled.
+///
+/// # Examples
+/// ```
+/// # use get_checked::GetCheckedMap;
+/// use std::collections::HashMap;
+///
+/// let mut map: HashMap<_, _> = [("a", 1), ("b", 2)].iter().cloned().collect();
+/// assert_eq!(map.get_checked("a"), Ok(&1));
+/// *map.get_checked_mut("b").unwrap() = 3;
+/// assert_eq!(map["b"], 3);
+/// assert_eq!(
+///     map.get_checked("c").unwrap_err().to_string(),
+///     "key \"c\" not found in map of length 2"
+/// );
+/// ```
+pub trait GetCheckedMap<Q: ?Sized, V>
+{
+    /// Returns a reference to the value stored under `key`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`KeyError`] if `key` is not in the map.
+    fn get_checked(&self, key: &Q) -> Result<&V, KeyError>;
+
+    /// Returns a mutable reference to the value stored under `key`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`KeyError`] if `key` is not in the map.
+    fn get_checked_mut(&mut self, key: &Q) -> Result<&mut V, KeyError>;
+}
+
+impl<K, V, Q, S> GetCheckedMap<Q, V> for HashMap<K, V, S>
+where
+    K: Borrow<Q> + Hash + Eq,
+    Q: Hash + Eq + Debug + ?Sized,
+    S: BuildHasher,
+{
+    fn get_checked(&self, key: &Q) -> Result<&V, KeyError>
+    {
+        self.get(key).ok_or_else(|| KeyError::new(key, self.len()))
+    }
+
+    fn get_checked_mut(&mut self, key: &Q) -> Result<&mut V, KeyError>
+    {
+        let len = self.len();
+        self.get_mut(key).ok_or_else(|| KeyError::new(key, len))
+    }
+}
+
+impl<K, V, Q> GetCheckedMap<Q, V> for BTreeMap<K, V>
+where
+    K: Borrow<Q> + Ord,
+    Q: Ord + Debug + ?Sized,
+{
+    fn get_checked(&self, key: &Q) -> Result<&V, KeyError>
+    {
+        self.get(key).ok_or_else(|| KeyError::new(key, self.len()))
+    }
+
+    fn get_checked_mut(&mut self, key: &Q) -> Result<&mut V, KeyError>
+    {
+        let len = self.len();
+        self.get_mut(key).ok_or_else(|| KeyError::new(key, len))
+    }
+}
+
+/// Trait adding checked range queries to [`BTreeMap`].
+///
+/// Only available when the `no_std` feature is disabled.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{GetCheckedBTreeMap, IndexErrorKind};
+/// use std::collections::BTreeMap;
+///
+/// let mut map: BTreeMap<_, _> = [(10, 'a'), (20, 'b'), (30, 'c')].iter().cloned().collect();
+/// let keys: Vec<_> = map.range_checked(15..).unwrap().map(|(k, _)| *k).collect();
+/// assert_eq!(keys, [20, 30]);
+///
+/// for (_, v) in map.range_checked_mut(..=20).unwrap()
+/// {
+///     *v = v.to_ascii_uppercase();
+/// }
+/// assert_eq!(map[&20], 'B');
+///
+/// let err = map.range_checked(25..15).unwrap_err();
+/// assert_eq!(*err.kind(), IndexErrorKind::Order(2, 1));
+/// ```
+pub trait GetCheckedBTreeMap<K, V>
+{
+    /// Returns an iterator over the entries whose keys lie within `range`, as
+    /// [`BTreeMap::range`] would.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Order` if the start of `range` is greater than its end,
+    /// or if both bounds are excluded and equal. The error contains the positions the bounds
+    /// would occupy in the sorted keys, i.e. the number of keys before each bound.
+    fn range_checked<T, R>(&self, range: R) -> Result<btree_map::Range<'_, K, V>, IndexError>
+    where
+        K: Borrow<T> + Ord,
+        T: Ord + ?Sized,
+        R: RangeBounds<T>;
+
+    /// Returns a mutable iterator over the entries whose keys lie within `range`, as
+    /// [`BTreeMap::range_mut`] would.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] as [`range_checked`](Self::range_checked) would.
+    fn range_checked_mut<T, R>(
+        &mut self,
+        range: R,
+    ) -> Result<btree_map::RangeMut<'_, K, V>, IndexError>
+    where
+        K: Borrow<T> + Ord,
+        T: Ord + ?Sized,
+        R: RangeBounds<T>;
+}
+
+impl<K, V> GetCheckedBTreeMap<K, V> for BTreeMap<K, V>
+{
+    fn range_checked<T, R>(&self, range: R) -> Result<btree_map::Range<'_, K, V>, IndexError>
+    where
+        K: Borrow<T> + Ord,
+        T: Ord + ?Sized,
+        R: RangeBounds<T>,
+    {
+        check_order(&range, self.len(), |bound| self.range::<T, _>((Unbounded, bound)).count())?;
+        Ok(self.range(range))
+    }
+
+    fn range_checked_mut<T, R>(
+        &mut self,
+        range: R,
+    ) -> Result<btree_map::RangeMut<'_, K, V>, IndexError>
+    where
+        K: Borrow<T> + Ord,
+        T: Ord + ?Sized,
+        R: RangeBounds<T>,
+    {
+        check_order(&range, self.len(), |bound| self.range::<T, _>((Unbounded, bound)).count())?;
+        Ok(self.range_mut(range))
+    }
+}
+
+/// Trait adding checked range queries to [`BTreeSet`].
+///
+/// Only available when the `no_std` feature is disabled.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{GetCheckedBTreeSet, IndexErrorKind};
+/// use std::{collections::BTreeSet, ops::Bound::Excluded};
+///
+/// let set: BTreeSet<_> = ["ant", "bee", "cat"].iter().cloned().collect();
+/// assert_eq!(set.range_checked("b"..).unwrap().count(), 2);
+///
+/// let err = set.range_checked::<&str, _>((Excluded("bee"), Excluded("bee"))).unwrap_err();
+/// assert_eq!(*err.kind(), IndexErrorKind::Order(2, 1));
+/// ```
+pub trait GetCheckedBTreeSet<T>
+{
+    /// Returns an iterator over the elements that lie within `range`, as [`BTreeSet::range`]
+    /// would.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Order` if the start of `range` is greater than its end,
+    /// or if both bounds are excluded and equal. The error contains the positions the bounds
+    /// would occupy in the sorted elements, i.e. the number of elements before each bound.
+    fn range_checked<U, R>(&self, range: R) -> Result<btree_set::Range<'_, T>, IndexError>
+    where
+        T: Borrow<U> + Ord,
+        U: Ord + ?Sized,
+        R: RangeBounds<U>;
+}
+
+impl<T> GetCheckedBTreeSet<T> for BTreeSet<T>
+{
+    fn range_checked<U, R>(&self, range: R) -> Result<btree_set::Range<'_, T>, IndexError>
+    where
+        T: Borrow<U> + Ord,
+        U: Ord + ?Sized,
+        R: RangeBounds<U>,
+    {
+        check_order(&range, self.len(), |bound| self.range::<U, _>((Unbounded, bound)).count())?;
+        Ok(self.range(range))
+    }
+}
+
+/// Checks that `range` would not make a B-tree range query panic, where `before(bound)` counts
+/// the keys up to the end bound `bound`.
+fn check_order<T, R>(
+    range: &R,
+    len: usize,
+    before: impl Fn(Bound<&T>) -> usize,
+) -> Result<(), IndexError>
+where
+    T: Ord + ?Sized,
+    R: RangeBounds<T>,
+{
+    let (start, end) = (range.start_bound(), range.end_bound());
+    let valid = match (start, end)
+    {
+        | (Excluded(s), Excluded(e)) => s < e,
+        | (Included(s) | Excluded(s), Included(e) | Excluded(e)) => s <= e,
+        | _ => true,
+    };
+    if valid
+    {
+        return Ok(());
+    }
+
+    let start = match start
+    {
+        | Included(s) => before(Excluded(s)),
+        | Excluded(s) => before(Included(s)),
+        | Unbounded => 0,
+    };
+    let end = before(end);
+    Err(Error::new(Order(start, end)).with_range(start, end, len))
+}
@@ -0,0 +1,126 @@
+use core::ops::RangeBounds;
+
+use crate::{
+    Error, IndexError,
+    IndexErrorKind::{Bounds, QuotaExceeded},
+    try_range,
+};
+
+/// A slice view that counts the elements returned by successful checked accesses against a
+/// budget, for bounding the work done while parsing untrusted input.
+///
+/// Each access is charged the number of elements it returns, which is the number of bytes for
+/// a `Metered<u8>`. An access that would exceed the remaining budget fails without being
+/// charged, as does an access that is out of bounds. Repeated accesses to the same elements are
+/// charged each time.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{IndexErrorKind, Metered};
+/// let input = [0u8; 64];
+/// let mut view = Metered::new(&input, 10);
+///
+/// assert_eq!(view.get_range_checked(..8).map(<[u8]>::len), Ok(8));
+/// assert_eq!(view.get_checked(8), Ok(&0));
+/// assert_eq!(view.remaining(), 1);
+///
+/// let err = view.get_range_checked(9..11).unwrap_err();
+/// assert_eq!(*err.kind(), IndexErrorKind::QuotaExceeded(2, 1));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Metered<'a, T>
+{
+    slice:  &'a [T],
+    budget: usize,
+    used:   usize,
+}
+
+impl<'a, T> Metered<'a, T>
+{
+    /// Wraps `slice`, allowing accesses returning up to `budget` elements in total.
+    #[inline]
+    pub fn new(slice: &'a [T], budget: usize) -> Self
+    {
+        Metered { slice, budget, used: 0 }
+    }
+
+    /// Returns the number of elements in the slice.
+    #[inline]
+    pub fn len(&self) -> usize
+    {
+        self.slice.len()
+    }
+
+    /// Returns `true` if the slice contains no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool
+    {
+        self.slice.is_empty()
+    }
+
+    /// Returns the total budget.
+    #[inline]
+    pub fn budget(&self) -> usize
+    {
+        self.budget
+    }
+
+    /// Returns the number of elements charged so far.
+    #[inline]
+    pub fn used(&self) -> usize
+    {
+        self.used
+    }
+
+    /// Returns the number of elements that may still be accessed.
+    #[inline]
+    pub fn remaining(&self) -> usize
+    {
+        self.budget - self.used
+    }
+
+    /// Returns a reference to the element at `index`, charging one element.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Bounds` if `index` is out of bounds, or
+    /// `QuotaExceeded` if the budget is exhausted.
+    pub fn get_checked(&mut self, index: usize) -> Result<&'a T, IndexError>
+    {
+        let slice = self.slice;
+        let elem = slice.get(index).ok_or(Error::new(Bounds(index, slice.len())))?;
+        self.charge(1)?;
+        Ok(elem)
+    }
+
+    /// Returns the subslice corresponding to `range`, charging its length.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] describing the invalid range as
+    /// [`get_checked`](crate::GetChecked::get_checked) would, or `QuotaExceeded` containing the
+    /// length of the range and the remaining budget if the range is longer than the remaining
+    /// budget.
+    pub fn get_range_checked<R>(&mut self, range: R) -> Result<&'a [T], IndexError>
+    where R: RangeBounds<usize>
+    {
+        let slice = self.slice;
+        let range = try_range(range, slice.len())?;
+        self.charge(range.len())?;
+        Ok(&slice[range])
+    }
+
+    /// Charges `n` elements against the budget if they fit.
+    fn charge(&mut self, n: usize) -> Result<(), IndexError>
+    {
+        match self.remaining()
+        {
+            | remaining if n > remaining => Err(Error::new(QuotaExceeded(n, remaining))),
+            | _ =>
+            {
+                self.used += n;
+                Ok(())
+            },
+        }
+    }
+}
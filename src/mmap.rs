@@ -0,0 +1,50 @@
+use memmap2::{Mmap, MmapMut};
+
+use crate::{GetChecked, GetCheckedMut};
+
+/// Checked indexing over the bytes of a read-only memory map.
+///
+/// # Examples
+/// ```no_run
+/// # use get_checked::GetChecked;
+/// use memmap2::Mmap;
+///
+/// let file = std::fs::File::open("data.bin").unwrap();
+/// let map = unsafe { Mmap::map(&file).unwrap() };
+///
+/// if let Err(e) = map.get_checked(0..16)
+/// {
+///     println!("truncated file: {}", e);
+/// }
+/// ```
+impl GetChecked<u8> for Mmap
+{
+    type Target = [u8];
+
+    #[inline]
+    fn checked_target(&self) -> &[u8]
+    {
+        self
+    }
+}
+
+/// Checked indexing over the bytes of a writable memory map.
+impl GetChecked<u8> for MmapMut
+{
+    type Target = [u8];
+
+    #[inline]
+    fn checked_target(&self) -> &[u8]
+    {
+        self
+    }
+}
+
+impl GetCheckedMut<u8> for MmapMut
+{
+    #[inline]
+    fn checked_target_mut(&mut self) -> &mut [u8]
+    {
+        self
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{Error, IndexError, IndexErrorKind::Bounds};
+
+/// Trait adding checked nibble (half-byte) addressing to byte slices.
+///
+/// Nibble `2 * n` is the high four bits of byte `n` and nibble `2 * n + 1` is its low four bits,
+/// so nibbles are numbered in the order their hex digits are written.
+pub trait GetNibbleChecked
+{
+    /// Returns the 4-bit value of the nibble at `index`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Bounds` containing `index` and the number of nibbles
+    /// if `index` is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// # use get_checked::{GetNibbleChecked, IndexErrorKind};
+    /// let v = [0x12, 0xAB];
+    /// assert_eq!(v.nibble_checked(0), Ok(0x1));
+    /// assert_eq!(v.nibble_checked(3), Ok(0xB));
+    /// assert_eq!(*v.nibble_checked(4).unwrap_err().kind(), IndexErrorKind::Bounds(4, 4));
+    /// ```
+    fn nibble_checked(&self, index: usize) -> Result<u8, IndexError>;
+
+    /// Sets the nibble at `index` to the low four bits of `value`, leaving the other nibble of
+    /// the byte unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Bounds` containing `index` and the number of nibbles
+    /// if `index` is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// # use get_checked::GetNibbleChecked;
+    /// let mut v = [0x12, 0xAB];
+    /// v.set_nibble_checked(1, 0xF).unwrap();
+    /// assert_eq!(v, [0x1F, 0xAB]);
+    /// assert!(v.set_nibble_checked(4, 0).is_err());
+    /// ```
+    fn set_nibble_checked(&mut self, index: usize, value: u8) -> Result<(), IndexError>;
+}
+
+impl GetNibbleChecked for [u8]
+{
+    #[inline]
+    fn nibble_checked(&self, index: usize) -> Result<u8, IndexError>
+    {
+        let byte = self.get(index / 2).ok_or_else(|| nibble_bounds(index, self.len()))?;
+        Ok((byte >> shift(index)) & 0xF)
+    }
+
+    #[inline]
+    fn set_nibble_checked(&mut self, index: usize, value: u8) -> Result<(), IndexError>
+    {
+        let len = self.len();
+        let byte = self.get_mut(index / 2).ok_or_else(|| nibble_bounds(index, len))?;
+        *byte = (*byte & !(0xF << shift(index))) | ((value & 0xF) << shift(index));
+        Ok(())
+    }
+}
+
+/// Returns the bit offset of the nibble at `index` within its byte.
+fn shift(index: usize) -> u8
+{
+    match index % 2
+    {
+        | 0 => 4,
+        | _ => 0,
+    }
+}
+
+/// Returns the error for a nibble `index` past the end of `len` bytes. Byte slices are at most
+/// `isize::MAX` long, so the nibble count cannot overflow.
+fn nibble_bounds(index: usize, len: usize) -> IndexError
+{
+    Error::new(Bounds(index, len * 2))
+}
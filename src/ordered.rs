@@ -0,0 +1,188 @@
+use core::{fmt::Debug, hash::Hash, ops::RangeBounds};
+
+use indexmap::{Equivalent, IndexMap, IndexSet, map, set};
+
+use crate::{Error, IndexError, IndexErrorKind::Bounds, KeyError, try_range};
+
+/// Trait adding checked positional and keyed access to [`IndexMap`].
+///
+/// Positional lookups fail with an [`IndexError`] and keyed lookups with a [`KeyError`], so both
+/// can be propagated with `?` into a common error type.
+///
+/// Only available with the `indexmap` feature.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{GetCheckedIndexMap, IndexErrorKind};
+/// use indexmap::IndexMap;
+///
+/// let map: IndexMap<_, _> = [("a", 1), ("b", 2)].iter().cloned().collect();
+/// assert_eq!(map.get_index_checked(1), Ok((&"b", &2)));
+/// assert_eq!(map.get_checked("a"), Ok(&1));
+/// assert_eq!(map.get_range_checked(1..).unwrap().values().collect::<Vec<_>>(), [&2]);
+/// assert_eq!(*map.get_index_checked(2).unwrap_err().kind(), IndexErrorKind::Bounds(2, 2));
+/// assert_eq!(
+///     map.get_checked("c").unwrap_err().to_string(),
+///     "key \"c\" not found in map of length 2"
+/// );
+/// ```
+pub trait GetCheckedIndexMap<K, V>
+{
+    /// Returns the key-value pair at position `index`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Bounds` if `index` is out of bounds.
+    fn get_index_checked(&self, index: usize) -> Result<(&K, &V), IndexError>;
+
+    /// Returns the key and a mutable reference to the value at position `index`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Bounds` if `index` is out of bounds.
+    fn get_index_checked_mut(&mut self, index: usize) -> Result<(&K, &mut V), IndexError>;
+
+    /// Returns the entries at the positions in `range`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] describing the invalid range as
+    /// [`get_checked`](crate::GetChecked::get_checked) would.
+    fn get_range_checked<R>(&self, range: R) -> Result<&map::Slice<K, V>, IndexError>
+    where R: RangeBounds<usize>;
+
+    /// Returns the entries at the positions in `range`, with mutable values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] describing the invalid range as
+    /// [`get_checked`](crate::GetChecked::get_checked) would.
+    fn get_range_checked_mut<R>(&mut self, range: R) -> Result<&mut map::Slice<K, V>, IndexError>
+    where R: RangeBounds<usize>;
+
+    /// Returns a reference to the value stored under `key`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`KeyError`] if `key` is not in the map.
+    fn get_checked<Q>(&self, key: &Q) -> Result<&V, KeyError>
+    where Q: Hash + Equivalent<K> + Debug + ?Sized;
+
+    /// Returns a mutable reference to the value stored under `key`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`KeyError`] if `key` is not in the map.
+    fn get_checked_mut<Q>(&mut self, key: &Q) -> Result<&mut V, KeyError>
+    where Q: Hash + Equivalent<K> + Debug + ?Sized;
+}
+
+impl<K, V, S> GetCheckedIndexMap<K, V> for IndexMap<K, V, S>
+where
+    K: Hash + Eq,
+    S: core::hash::BuildHasher,
+{
+    fn get_index_checked(&self, index: usize) -> Result<(&K, &V), IndexError>
+    {
+        self.get_index(index).ok_or(Error::new(Bounds(index, self.len())))
+    }
+
+    fn get_index_checked_mut(&mut self, index: usize) -> Result<(&K, &mut V), IndexError>
+    {
+        let len = self.len();
+        self.get_index_mut(index).ok_or(Error::new(Bounds(index, len)))
+    }
+
+    fn get_range_checked<R>(&self, range: R) -> Result<&map::Slice<K, V>, IndexError>
+    where R: RangeBounds<usize>
+    {
+        let range = try_range(range, self.len())?;
+        Ok(&self.as_slice()[range])
+    }
+
+    fn get_range_checked_mut<R>(&mut self, range: R) -> Result<&mut map::Slice<K, V>, IndexError>
+    where R: RangeBounds<usize>
+    {
+        let range = try_range(range, self.len())?;
+        Ok(&mut self.as_mut_slice()[range])
+    }
+
+    fn get_checked<Q>(&self, key: &Q) -> Result<&V, KeyError>
+    where Q: Hash + Equivalent<K> + Debug + ?Sized
+    {
+        self.get(key).ok_or_else(|| KeyError::new(key, self.len()))
+    }
+
+    fn get_checked_mut<Q>(&mut self, key: &Q) -> Result<&mut V, KeyError>
+    where Q: Hash + Equivalent<K> + Debug + ?Sized
+    {
+        let len = self.len();
+        self.get_mut(key).ok_or_else(|| KeyError::new(key, len))
+    }
+}
+
+/// Trait adding checked positional and keyed access to [`IndexSet`].
+///
+/// Only available with the `indexmap` feature.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{GetCheckedIndexSet, IndexErrorKind};
+/// use indexmap::IndexSet;
+///
+/// let set: IndexSet<_> = ["a", "b"].iter().cloned().collect();
+/// assert_eq!(set.get_index_checked(0), Ok(&"a"));
+/// assert_eq!(set.get_checked("b"), Ok(&"b"));
+/// assert_eq!(*set.get_index_checked(2).unwrap_err().kind(), IndexErrorKind::Bounds(2, 2));
+/// assert!(set.get_checked("c").is_err());
+/// ```
+pub trait GetCheckedIndexSet<T>
+{
+    /// Returns the value at position `index`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Bounds` if `index` is out of bounds.
+    fn get_index_checked(&self, index: usize) -> Result<&T, IndexError>;
+
+    /// Returns the values at the positions in `range`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] describing the invalid range as
+    /// [`get_checked`](crate::GetChecked::get_checked) would.
+    fn get_range_checked<R>(&self, range: R) -> Result<&set::Slice<T>, IndexError>
+    where R: RangeBounds<usize>;
+
+    /// Returns the stored value equivalent to `value`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`KeyError`] if `value` is not in the set.
+    fn get_checked<Q>(&self, value: &Q) -> Result<&T, KeyError>
+    where Q: Hash + Equivalent<T> + Debug + ?Sized;
+}
+
+impl<T, S> GetCheckedIndexSet<T> for IndexSet<T, S>
+where
+    T: Hash + Eq,
+    S: core::hash::BuildHasher,
+{
+    fn get_index_checked(&self, index: usize) -> Result<&T, IndexError>
+    {
+        self.get_index(index).ok_or(Error::new(Bounds(index, self.len())))
+    }
+
+    fn get_range_checked<R>(&self, range: R) -> Result<&set::Slice<T>, IndexError>
+    where R: RangeBounds<usize>
+    {
+        let range = try_range(range, self.len())?;
+        Ok(&self.as_slice()[range])
+    }
+
+    fn get_checked<Q>(&self, value: &Q) -> Result<&T, KeyError>
+    where Q: Hash + Equivalent<T> + Debug + ?Sized
+    {
+        self.get(value).ok_or_else(|| KeyError::new(value, self.len()))
+    }
+}
@@ -0,0 +1,98 @@
+use alloc::{borrow::Cow, boxed::Box, rc::Rc, sync::Arc, vec::Vec};
+
+use crate::{GetChecked, GetCheckedMut};
+
+/// Indexes the elements of a `Vec` as a slice, so generic code bounded on [`GetChecked`] accepts
+/// a `Vec` directly.
+///
+/// # Examples
+/// ```
+/// # use get_checked::GetChecked;
+/// fn second<S>(s: &S) -> Option<&u8>
+/// where S: GetChecked<u8, Target = [u8]>
+/// {
+///     s.get_checked(1).ok()
+/// }
+///
+/// assert_eq!(second(&vec![1, 2, 3]), Some(&2));
+/// assert_eq!(second(&vec![1]), None);
+/// ```
+impl<T> GetChecked<T> for Vec<T>
+{
+    type Target = [T];
+
+    #[inline]
+    fn checked_target(&self) -> &[T]
+    {
+        self
+    }
+}
+
+impl<T> GetCheckedMut<T> for Vec<T>
+{
+    #[inline]
+    fn checked_target_mut(&mut self) -> &mut [T]
+    {
+        self
+    }
+}
+
+/// Indexes the elements of a boxed slice.
+impl<T> GetChecked<T> for Box<[T]>
+{
+    type Target = [T];
+
+    #[inline]
+    fn checked_target(&self) -> &[T]
+    {
+        self
+    }
+}
+
+impl<T> GetCheckedMut<T> for Box<[T]>
+{
+    #[inline]
+    fn checked_target_mut(&mut self) -> &mut [T]
+    {
+        self
+    }
+}
+
+/// Indexes the elements of a shared slice. Mutable access is not provided, since the elements
+/// may be shared.
+impl<T> GetChecked<T> for Rc<[T]>
+{
+    type Target = [T];
+
+    #[inline]
+    fn checked_target(&self) -> &[T]
+    {
+        self
+    }
+}
+
+/// Indexes the elements of a shared slice. Mutable access is not provided, since the elements
+/// may be shared.
+impl<T> GetChecked<T> for Arc<[T]>
+{
+    type Target = [T];
+
+    #[inline]
+    fn checked_target(&self) -> &[T]
+    {
+        self
+    }
+}
+
+/// Indexes the elements of a borrowed or owned slice. Mutable access is not provided, since it
+/// would clone borrowed elements.
+impl<T: Clone> GetChecked<T> for Cow<'_, [T]>
+{
+    type Target = [T];
+
+    #[inline]
+    fn checked_target(&self) -> &[T]
+    {
+        self
+    }
+}
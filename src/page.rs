@@ -0,0 +1,88 @@
+use crate::{
+    Error, IndexError,
+    IndexErrorKind::{Bounds, ZeroChunk},
+};
+
+/// Trait adding checked pagination to slices.
+///
+/// Pages are validated against the page count before any offset is computed, so a large
+/// `page_number` reports `Bounds` rather than overflowing `page_size * page_number`.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{GetPageChecked, IndexErrorKind};
+/// let v = [1, 2, 3, 4, 5];
+/// assert_eq!(v.page_count_checked(2), Ok(3));
+/// assert_eq!(v.page_checked(2, 1), Ok(&[3, 4][..]));
+/// assert_eq!(v.page_checked_clamped(2, 2), Ok(&[5][..]));
+/// assert_eq!(*v.page_checked(2, 2).unwrap_err().kind(), IndexErrorKind::Bounds(2, 2));
+/// ```
+pub trait GetPageChecked<T>
+{
+    /// Returns the number of pages of `page_size` elements, counting a final partial page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `ZeroChunk` if `page_size` is zero.
+    fn page_count_checked(&self, page_size: usize) -> Result<usize, IndexError>;
+
+    /// Returns the full page of `page_size` elements at `page_number`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `ZeroChunk` if `page_size` is zero, or `Bounds`
+    /// containing `page_number` and the number of full pages if the page does not exist or is
+    /// only partially filled.
+    fn page_checked(&self, page_size: usize, page_number: usize) -> Result<&[T], IndexError>;
+
+    /// Returns the page of up to `page_size` elements at `page_number`, allowing the final page
+    /// to be partially filled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `ZeroChunk` if `page_size` is zero, or `Bounds`
+    /// containing `page_number` and the page count if the page does not exist.
+    fn page_checked_clamped(
+        &self,
+        page_size: usize,
+        page_number: usize,
+    ) -> Result<&[T], IndexError>;
+}
+
+impl<T> GetPageChecked<T> for [T]
+{
+    fn page_count_checked(&self, page_size: usize) -> Result<usize, IndexError>
+    {
+        match page_size
+        {
+            | 0 => Err(Error::new(ZeroChunk())),
+            | _ => Ok(self.len().div_ceil(page_size)),
+        }
+    }
+
+    fn page_checked(&self, page_size: usize, page_number: usize) -> Result<&[T], IndexError>
+    {
+        let pages = self.len() / page_size.max(1);
+        match page_number
+        {
+            | _ if page_size == 0 => Err(Error::new(ZeroChunk())),
+            | _ if page_number >= pages => Err(Error::new(Bounds(page_number, pages))),
+            | _ => Ok(&self[page_number * page_size..][..page_size]),
+        }
+    }
+
+    fn page_checked_clamped(&self, page_size: usize, page_number: usize)
+    -> Result<&[T], IndexError>
+    {
+        let pages = self.page_count_checked(page_size)?;
+        match page_number
+        {
+            | _ if page_number >= pages => Err(Error::new(Bounds(page_number, pages))),
+            | _ =>
+            {
+                let rest = &self[page_number * page_size..];
+                Ok(&rest[..page_size.min(rest.len())])
+            },
+        }
+    }
+}
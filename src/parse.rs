@@ -0,0 +1,116 @@
+use core::{fmt, ops::RangeBounds, str, str::FromStr};
+#[cfg(not(feature = "no_std"))]
+use std::error;
+
+use crate::{Error, IndexError, IndexErrorKind::CharBoundary, try_range};
+
+/// An error that can be returned when parsing a value from a checked range of text.
+///
+/// Distinguishes a range that could not be sliced from text that could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError<E>
+{
+    /// The range was invalid.
+    Index(IndexError),
+
+    /// The bytes within the range were not valid UTF-8.
+    Utf8(str::Utf8Error),
+
+    /// The text within the range could not be parsed.
+    Parse(E),
+}
+
+impl<E> From<IndexError> for ParseError<E>
+{
+    fn from(err: IndexError) -> Self
+    {
+        ParseError::Index(err)
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for ParseError<E>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            | ParseError::Index(err) => fmt::Display::fmt(err, f),
+            | ParseError::Utf8(err) => fmt::Display::fmt(err, f),
+            | ParseError::Parse(err) => fmt::Display::fmt(err, f),
+        }
+    }
+}
+
+/// Only available when the `no_std` feature is disabled.
+#[cfg(not(feature = "no_std"))]
+impl<E> error::Error for ParseError<E>
+where E: error::Error + 'static
+{
+    fn source(&self) -> Option<&(dyn error::Error + 'static)>
+    {
+        match self
+        {
+            | ParseError::Index(err) => Some(err),
+            | ParseError::Utf8(err) => Some(err),
+            | ParseError::Parse(err) => Some(err),
+        }
+    }
+}
+
+/// Trait adding checked slicing followed by parsing to text.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{ParseChecked, ParseError};
+/// let record = "00042  3.50";
+/// assert_eq!(record.parse_checked::<u32, _>(..5), Ok(42));
+/// assert_eq!(record.parse_checked::<f64, _>(7..), Ok(3.5));
+///
+/// assert!(matches!(record.parse_checked::<u32, _>(7..12), Err(ParseError::Index(_))));
+/// assert!(matches!(record.parse_checked::<u32, _>(7..), Err(ParseError::Parse(_))));
+/// ```
+pub trait ParseChecked
+{
+    /// Parses the text within `range` as a `T`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError::Index`] describing the invalid range as
+    /// [`get_checked`](crate::GetChecked::get_checked) would, or of kind `CharBoundary` if either
+    /// end of a `str` range does not lie on a char boundary. Returns [`ParseError::Utf8`] if the
+    /// bytes within the range are not valid UTF-8, or [`ParseError::Parse`] if parsing fails.
+    fn parse_checked<T, R>(&self, range: R) -> Result<T, ParseError<T::Err>>
+    where
+        T: FromStr,
+        R: RangeBounds<usize>;
+}
+
+impl ParseChecked for str
+{
+    fn parse_checked<T, R>(&self, range: R) -> Result<T, ParseError<T::Err>>
+    where
+        T: FromStr,
+        R: RangeBounds<usize>,
+    {
+        let range = try_range(range, self.len())?;
+        let boundary =
+            [range.start, range.end].iter().copied().find(|&i| !self.is_char_boundary(i));
+        match boundary
+        {
+            | Some(i) => Err(ParseError::Index(Error::new(CharBoundary(i)))),
+            | None => self[range].parse().map_err(ParseError::Parse),
+        }
+    }
+}
+
+impl ParseChecked for [u8]
+{
+    fn parse_checked<T, R>(&self, range: R) -> Result<T, ParseError<T::Err>>
+    where
+        T: FromStr,
+        R: RangeBounds<usize>,
+    {
+        let bytes = &self[try_range(range, self.len())?];
+        str::from_utf8(bytes).map_err(ParseError::Utf8)?.parse().map_err(ParseError::Parse)
+    }
+}
@@ -0,0 +1,69 @@
+use core::ops::RangeBounds;
+
+use im::Vector;
+
+use crate::{Error, IndexError, IndexErrorKind::Bounds, try_range};
+
+/// Trait adding checked element and range access to the persistent [`Vector`].
+///
+/// Only available with the `im` feature.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{GetCheckedImVector, IndexErrorKind};
+/// use im::vector;
+///
+/// let mut v = vector![1, 2, 3];
+/// assert_eq!(v.get_checked(1), Ok(&2));
+/// *v.get_checked_mut(2).unwrap() = 4;
+/// assert_eq!(v.slice_checked(1..).unwrap(), vector![2, 4]);
+/// assert_eq!(*v.get_checked(3).unwrap_err().kind(), IndexErrorKind::Bounds(3, 3));
+/// ```
+pub trait GetCheckedImVector<A>
+{
+    /// Returns a reference to the element at `index`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Bounds` if `index` is out of bounds.
+    fn get_checked(&self, index: usize) -> Result<&A, IndexError>;
+
+    /// Returns a mutable reference to the element at `index`, copying the underlying chunk if it
+    /// is shared.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Bounds` if `index` is out of bounds.
+    fn get_checked_mut(&mut self, index: usize) -> Result<&mut A, IndexError>;
+
+    /// Returns a new vector sharing structure with `self` over `range`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] describing the invalid range as
+    /// [`get_checked`](crate::GetChecked::get_checked) would.
+    fn slice_checked<R>(&self, range: R) -> Result<Vector<A>, IndexError>
+    where R: RangeBounds<usize>;
+}
+
+impl<A: Clone> GetCheckedImVector<A> for Vector<A>
+{
+    fn get_checked(&self, index: usize) -> Result<&A, IndexError>
+    {
+        let len = self.len();
+        self.get(index).ok_or(Error::new(Bounds(index, len)))
+    }
+
+    fn get_checked_mut(&mut self, index: usize) -> Result<&mut A, IndexError>
+    {
+        let len = self.len();
+        self.get_mut(index).ok_or(Error::new(Bounds(index, len)))
+    }
+
+    fn slice_checked<R>(&self, range: R) -> Result<Vector<A>, IndexError>
+    where R: RangeBounds<usize>
+    {
+        let range = try_range(range, self.len())?;
+        Ok(self.skip(range.start).take(range.end - range.start))
+    }
+}
@@ -0,0 +1,79 @@
+use core::mem;
+
+use bytemuck::Pod;
+
+use crate::{
+    Error, IndexError,
+    IndexErrorKind::{Bounds, EndOverflow, Misaligned, ZeroChunk},
+};
+
+/// Trait adding checked access to arrays of plain-old-data structs stored in byte buffers.
+///
+/// Only available with the `bytemuck` feature.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{GetPodChecked, IndexErrorKind};
+/// let entries = [1u32, 2, 3];
+/// let bytes: &[u8] = bytemuck::cast_slice(&entries);
+///
+/// assert_eq!(bytes.get_pod_checked::<u32>(2), Ok(&3));
+/// assert_eq!(*bytes.get_pod_checked::<u32>(3).unwrap_err().kind(), IndexErrorKind::Bounds(3, 3));
+/// ```
+pub trait GetPodChecked
+{
+    /// Returns a reference to the `index`th `S` in the buffer, i.e. the value stored in the
+    /// bytes `index * size_of::<S>()..(index + 1) * size_of::<S>()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `ZeroChunk` if `S` is zero-sized, `EndOverflow` if the
+    /// byte range overflows, `Bounds` containing `index` and the number of complete `S` in the
+    /// buffer if the value does not exist, or `Misaligned` containing the byte offset and the
+    /// alignment of `S` if the value is not suitably aligned.
+    fn get_pod_checked<S: Pod>(&self, index: usize) -> Result<&S, IndexError>;
+
+    /// Returns a mutable reference to the `index`th `S` in the buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`get_pod_checked`](GetPodChecked::get_pod_checked).
+    fn get_pod_checked_mut<S: Pod>(&mut self, index: usize) -> Result<&mut S, IndexError>;
+}
+
+impl GetPodChecked for [u8]
+{
+    fn get_pod_checked<S: Pod>(&self, index: usize) -> Result<&S, IndexError>
+    {
+        let start = pod_offset::<S>(self, index)?;
+        Ok(bytemuck::from_bytes(&self[start..start + mem::size_of::<S>()]))
+    }
+
+    fn get_pod_checked_mut<S: Pod>(&mut self, index: usize) -> Result<&mut S, IndexError>
+    {
+        let start = pod_offset::<S>(self, index)?;
+        Ok(bytemuck::from_bytes_mut(&mut self[start..start + mem::size_of::<S>()]))
+    }
+}
+
+/// Validates the `index`th `S` in `bytes` and returns its byte offset.
+fn pod_offset<S: Pod>(bytes: &[u8], index: usize) -> Result<usize, IndexError>
+{
+    let (size, align) = (mem::size_of::<S>(), mem::align_of::<S>());
+    if size == 0
+    {
+        return Err(Error::new(ZeroChunk()));
+    }
+
+    let start = index.checked_mul(size).ok_or(Error::new(EndOverflow()))?;
+    let count = bytes.len() / size;
+    match start
+    {
+        | _ if index >= count => Err(Error::new(Bounds(index, count))),
+        | _ if bytes[start..].as_ptr().align_offset(align) != 0 =>
+        {
+            Err(Error::new(Misaligned(start, align)))
+        },
+        | _ => Ok(start),
+    }
+}
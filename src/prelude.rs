@@ -0,0 +1,45 @@
+//! Convenience re-exports of the crate's extension traits.
+//!
+//! Importing the prelude brings every checked accessor into scope at once:
+//!
+//! ```
+//! use get_checked::prelude::*;
+//!
+//! let v = vec![1, 2, 3];
+//! assert_eq!(v.get_checked(1), Ok(&2));
+//! assert_eq!(b"a,b".record_checked(1, b','), Ok(&b"b"[..]));
+//! ```
+
+#[cfg(feature = "bytes")]
+pub use crate::GetCheckedBytes;
+#[cfg(any(feature = "heapless", not(feature = "no_std")))]
+pub use crate::GetCheckedDeque;
+#[cfg(feature = "im")]
+pub use crate::GetCheckedImVector;
+#[cfg(feature = "ropey")]
+pub use crate::GetCheckedRope;
+#[cfg(feature = "slab")]
+pub use crate::GetCheckedSlab;
+#[cfg(feature = "slotmap")]
+pub use crate::GetCheckedSlotMap;
+#[cfg(feature = "crc")]
+pub use crate::GetCrcChecked;
+#[cfg(feature = "bytemuck")]
+pub use crate::GetPodChecked;
+#[cfg(all(feature = "anyhow", not(feature = "no_std")))]
+pub use crate::IndexContext;
+pub use crate::{
+    DynGetChecked, FillPatternChecked, FindSubsliceChecked, GetAsciiChecked, GetChecked,
+    GetCheckedAtomic, GetCheckedCell, GetCheckedDebug, GetCheckedMut, GetCheckedSeq,
+    GetCheckedSliceIndex, GetCheckedStr, GetCheckedUninit, GetFlatChecked, GetFrameChecked,
+    GetFromEndChecked, GetNibbleChecked, GetPageChecked, GetPixelChecked, GetRecordChecked,
+    GetTlvChecked, GetUtf16Checked, GetWindowChecked, HexChecked, IndexRecover, ParseChecked,
+    ReadChecked, ReadEndianChecked, StripChecked, WriteEndianChecked,
+};
+#[cfg(not(feature = "no_std"))]
+pub use crate::{
+    GetCheckedBTreeMap, GetCheckedBTreeSet, GetCheckedHeap, GetCheckedList, GetCheckedLocked,
+    GetCheckedMap, GetCheckedPath, GetCheckedVec, GetUniqueChecked, SetAllChecked,
+};
+#[cfg(all(feature = "indexmap", not(feature = "no_std")))]
+pub use crate::{GetCheckedIndexMap, GetCheckedIndexSet};
@@ -0,0 +1,94 @@
+//! Kani proof harnesses showing that checked access succeeds exactly when std `get` returns
+//! `Some`, and returns the same element or subslice.
+//!
+//! Run with `cargo kani`. Sized slices are bounded to `MAX_LEN` elements to keep the proofs
+//! tractable; zero-sized slices cover every length up to [`usize::MAX`].
+
+use core::{ops::Bound, ptr::NonNull, slice};
+
+use crate::{GetChecked, try_range};
+
+const MAX_LEN: usize = 4;
+
+/// Returns a slice of arbitrary contents and length up to `MAX_LEN`.
+fn any_slice(array: &[u8; MAX_LEN]) -> &[u8]
+{
+    let len: usize = kani::any();
+    kani::assume(len <= MAX_LEN);
+    &array[..len]
+}
+
+/// Returns a slice of zero-sized elements of arbitrary length.
+fn any_zst_slice<'a>() -> &'a [()]
+{
+    unsafe { slice::from_raw_parts(NonNull::dangling().as_ptr(), kani::any()) }
+}
+
+/// Returns an arbitrary bound.
+fn any_bound() -> Bound<usize>
+{
+    match kani::any::<u8>() % 3
+    {
+        | 0 => Bound::Included(kani::any()),
+        | 1 => Bound::Excluded(kani::any()),
+        | _ => Bound::Unbounded,
+    }
+}
+
+/// Asserts that `get_checked` and `get_checked_mut` agree with `get` for `index` on `v`.
+macro_rules! assert_agrees {
+    ($v:expr, $index:expr) => {{
+        let v = $v;
+        let index = $index;
+        let expected = v.get(index.clone()).map(|r| r as *const _);
+        assert!(v.get_checked(index.clone()).ok().map(|r| r as *const _) == expected);
+
+        let mut owned = v.to_vec();
+        let expected = owned.get_mut(index.clone()).map(|r| r as *mut _);
+        assert!(owned.get_checked_mut(index).ok().map(|r| r as *mut _) == expected);
+    }};
+}
+
+#[kani::proof]
+fn usize_agrees_with_get()
+{
+    let array: [u8; MAX_LEN] = kani::any();
+    assert_agrees!(any_slice(&array), kani::any::<usize>());
+}
+
+#[kani::proof]
+fn range_agrees_with_get()
+{
+    let array: [u8; MAX_LEN] = kani::any();
+    let (start, end): (usize, usize) = kani::any();
+    assert_agrees!(any_slice(&array), start..end);
+    assert_agrees!(any_slice(&array), start..);
+    assert_agrees!(any_slice(&array), ..end);
+}
+
+#[kani::proof]
+fn range_inclusive_agrees_with_get()
+{
+    let array: [u8; MAX_LEN] = kani::any();
+    let (start, end): (usize, usize) = kani::any();
+    assert_agrees!(any_slice(&array), start..=end);
+    assert_agrees!(any_slice(&array), ..=end);
+}
+
+#[kani::proof]
+fn zst_agrees_with_get()
+{
+    let (start, end): (usize, usize) = kani::any();
+    let v = any_zst_slice();
+    assert!(v.get_checked(start).ok() == v.get(start));
+    assert!(v.get_checked(start..end).ok() == v.get(start..end));
+    assert!(v.get_checked(start..=end).ok() == v.get(start..=end));
+}
+
+#[kani::proof]
+fn try_range_agrees_with_get()
+{
+    let v = any_zst_slice();
+    let bounds = (any_bound(), any_bound());
+    assert!(try_range(bounds, v.len()).ok().map(|r| &v[r]) == v.get(bounds));
+}
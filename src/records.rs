@@ -0,0 +1,144 @@
+use core::{iter::FusedIterator, slice};
+
+use crate::{
+    Error, GetChecked, IndexError,
+    IndexErrorKind::{Bounds, EndOverflow},
+};
+
+/// Trait adding zero-copy checked record splitting to byte slices.
+///
+/// Unlike `split().nth()`, a failed lookup reports how many records were present.
+pub trait GetRecordChecked
+{
+    /// Returns the `n`th record of the slice when split on `delimiter`.
+    ///
+    /// Records follow the semantics of [`slice::split`]; a trailing delimiter yields a final
+    /// empty record.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Bounds` containing `n` and the number of records
+    /// found if the record does not exist.
+    ///
+    /// # Examples
+    /// ```
+    /// # use get_checked::{GetRecordChecked, IndexErrorKind};
+    /// let v = b"a,bc,d";
+    /// assert_eq!(v.record_checked(1, b','), Ok(&b"bc"[..]));
+    /// assert_eq!(*v.record_checked(3, b',').unwrap_err().kind(), IndexErrorKind::Bounds(3, 3));
+    /// ```
+    fn record_checked(&self, n: usize, delimiter: u8) -> Result<&[u8], IndexError>;
+
+    /// Returns the `n`th line of the slice, without its line terminator.
+    ///
+    /// Lines follow the semantics of [`str::lines`]; lines end with either `\n` or `\r\n`, and
+    /// a final line ending does not start an empty line. A bare `\r` ending the final line is
+    /// not a line ending and is kept.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Bounds` containing `n` and the number of lines found
+    /// if the line does not exist.
+    ///
+    /// # Examples
+    /// ```
+    /// # use get_checked::{GetRecordChecked, IndexErrorKind};
+    /// let v = b"first\r\nsecond\n";
+    /// assert_eq!(v.line_checked(1), Ok(&b"second"[..]));
+    /// assert_eq!(*v.line_checked(2).unwrap_err().kind(), IndexErrorKind::Bounds(2, 2));
+    /// ```
+    fn line_checked(&self, n: usize) -> Result<&[u8], IndexError>;
+
+    /// Returns an iterator over consecutive fixed-width fields of the slice, with field `n`
+    /// spanning `widths[n]` bytes and starting where the previous one ended.
+    ///
+    /// Each field is checked on its own, so a short record still yields the fields that fit.
+    /// Bytes after the last field are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Each item is an [`IndexError`] describing the field's range as
+    /// [`get_checked`](crate::GetChecked::get_checked) would if the field does not fit, or of
+    /// kind `EndOverflow` if its end, or that of an earlier field, overflows.
+    ///
+    /// # Examples
+    /// ```
+    /// # use get_checked::{GetRecordChecked, IndexErrorKind};
+    /// let line = b"ACME  0042 NY";
+    /// let mut fields = line.fields_checked(&[6, 4, 1, 3]);
+    ///
+    /// assert_eq!(fields.next(), Some(Ok(&b"ACME  "[..])));
+    /// assert_eq!(fields.next(), Some(Ok(&b"0042"[..])));
+    /// assert_eq!(fields.next(), Some(Ok(&b" "[..])));
+    /// assert_eq!(*fields.next().unwrap().unwrap_err().kind(), IndexErrorKind::EndRange(14, 13));
+    /// assert_eq!(fields.next(), None);
+    /// ```
+    fn fields_checked<'w>(&self, widths: &'w [usize]) -> FixedFields<'_, 'w>;
+}
+
+impl GetRecordChecked for [u8]
+{
+    fn record_checked(&self, n: usize, delimiter: u8) -> Result<&[u8], IndexError>
+    {
+        let records = || self.split(move |b| *b == delimiter);
+        records().nth(n).ok_or_else(|| Error::new(Bounds(n, records().count())))
+    }
+
+    fn line_checked(&self, n: usize) -> Result<&[u8], IndexError>
+    {
+        let body = self.strip_suffix(b"\n").unwrap_or(self);
+        let lines = || body.split(|b| *b == b'\n');
+        let mut rest = lines();
+        match rest.nth(n)
+        {
+            | _ if self.is_empty() => Err(Error::new(Bounds(n, 0))),
+            // Only a line terminated by `\n` can end with a `\r\n` terminator.
+            | Some(line) if rest.next().is_none() && body.len() == self.len() => Ok(line),
+            | Some(line) => Ok(line.strip_suffix(b"\r").unwrap_or(line)),
+            | None => Err(Error::new(Bounds(n, lines().count()))),
+        }
+    }
+
+    fn fields_checked<'w>(&self, widths: &'w [usize]) -> FixedFields<'_, 'w>
+    {
+        FixedFields { bytes: self, widths: widths.iter(), offset: Some(0) }
+    }
+}
+
+/// An iterator over the fixed-width fields of a record.
+///
+/// This struct is created by [`GetRecordChecked::fields_checked`].
+#[derive(Debug, Clone)]
+pub struct FixedFields<'a, 'w>
+{
+    bytes:  &'a [u8],
+    widths: slice::Iter<'w, usize>,
+    offset: Option<usize>,
+}
+
+impl<'a, 'w> Iterator for FixedFields<'a, 'w>
+{
+    type Item = Result<&'a [u8], IndexError>;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        let width = *self.widths.next()?;
+        let start = self.offset;
+        self.offset = start.and_then(|start| start.checked_add(width));
+
+        Some(match (start, self.offset)
+        {
+            | (Some(start), Some(end)) => self.bytes.get_checked(start..end),
+            | _ => Err(Error::new(EndOverflow())),
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        self.widths.size_hint()
+    }
+}
+
+impl ExactSizeIterator for FixedFields<'_, '_> {}
+
+impl FusedIterator for FixedFields<'_, '_> {}
@@ -0,0 +1,63 @@
+use crate::IndexError;
+
+/// Extension trait adding recovery combinators to the `Result` returned by range-based
+/// [`get_checked`] calls.
+///
+/// These allow callers to express fallback policies fluently after a failed strict access.
+///
+/// [`get_checked`]: crate::GetChecked::get_checked
+///
+/// # Examples
+/// ```
+/// # use get_checked::{GetChecked, IndexRecover};
+/// let v = [1, 2, 3, 4];
+/// assert_eq!(v.get_checked(2..6).or_clamped(&v), &[3, 4]);
+/// assert_eq!(v.get_checked(2..6).or_empty(), &[]);
+/// assert_eq!(v.get_checked(2..6).or_full(&v), &[1, 2, 3, 4]);
+/// ```
+pub trait IndexRecover<'a, T>
+{
+    /// Returns the subslice on success. On failure, returns the requested range of `slice`
+    /// clamped to its bounds.
+    ///
+    /// An end beyond the length of `slice` is clamped to the length, and a start after the end
+    /// yields an empty subslice positioned at the clamped end.
+    fn or_clamped(self, slice: &'a [T]) -> &'a [T];
+
+    /// Returns the subslice on success or an empty slice on failure.
+    fn or_empty(self) -> &'a [T];
+
+    /// Returns the subslice on success or the whole of `slice` on failure.
+    fn or_full(self, slice: &'a [T]) -> &'a [T];
+}
+
+impl<'a, T> IndexRecover<'a, T> for Result<&'a [T], IndexError>
+{
+    #[inline]
+    fn or_clamped(self, slice: &'a [T]) -> &'a [T]
+    {
+        let len = slice.len();
+        match self
+        {
+            | Ok(ok) => ok,
+            | Err(e) =>
+            {
+                let (start, end) = e.range.unwrap_or((len, len));
+                let end = end.min(len);
+                &slice[start.min(end)..end]
+            },
+        }
+    }
+
+    #[inline]
+    fn or_empty(self) -> &'a [T]
+    {
+        self.unwrap_or(&[])
+    }
+
+    #[inline]
+    fn or_full(self, slice: &'a [T]) -> &'a [T]
+    {
+        self.unwrap_or(slice)
+    }
+}
@@ -0,0 +1,102 @@
+use crate::{
+    Error, IndexError,
+    IndexErrorKind::{Bounds, EndRange},
+};
+
+/// Trait adding checked access counted from the end of a slice, avoiding the `len - n`
+/// underflow of computing the index by hand.
+///
+/// Errors report the requested offset from the end rather than a computed index.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{GetFromEndChecked, IndexErrorKind};
+/// let v = [1, 2, 3, 4];
+/// assert_eq!(v.get_from_end_checked(0), Ok(&4));
+/// assert_eq!(v.range_from_end_checked(2), Ok(&[3, 4][..]));
+///
+/// assert_eq!(*v.get_from_end_checked(4).unwrap_err().kind(), IndexErrorKind::Bounds(4, 4));
+/// assert_eq!(*v.range_from_end_checked(5).unwrap_err().kind(), IndexErrorKind::EndRange(5, 4));
+/// ```
+pub trait GetFromEndChecked<T>
+{
+    /// Returns a reference to the element `n` places before the last one, so that `0` refers to
+    /// the last element.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Bounds` containing `n` and the length of the slice if
+    /// `n` is not less than the length.
+    fn get_from_end_checked(&self, n: usize) -> Result<&T, IndexError>;
+
+    /// Returns a mutable reference to the element `n` places before the last one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Bounds` containing `n` and the length of the slice if
+    /// `n` is not less than the length.
+    fn get_from_end_checked_mut(&mut self, n: usize) -> Result<&mut T, IndexError>;
+
+    /// Returns the last `n` elements of the slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `EndRange` containing `n` and the length of the slice
+    /// if `n` is greater than the length.
+    fn range_from_end_checked(&self, n: usize) -> Result<&[T], IndexError>;
+
+    /// Returns the last `n` elements of the slice mutably.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `EndRange` containing `n` and the length of the slice
+    /// if `n` is greater than the length.
+    fn range_from_end_checked_mut(&mut self, n: usize) -> Result<&mut [T], IndexError>;
+}
+
+impl<T> GetFromEndChecked<T> for [T]
+{
+    #[inline]
+    fn get_from_end_checked(&self, n: usize) -> Result<&T, IndexError>
+    {
+        let index = from_end(self.len(), n)?;
+        Ok(&self[index])
+    }
+
+    #[inline]
+    fn get_from_end_checked_mut(&mut self, n: usize) -> Result<&mut T, IndexError>
+    {
+        let index = from_end(self.len(), n)?;
+        Ok(&mut self[index])
+    }
+
+    #[inline]
+    fn range_from_end_checked(&self, n: usize) -> Result<&[T], IndexError>
+    {
+        let start = range_from_end(self.len(), n)?;
+        Ok(&self[start..])
+    }
+
+    #[inline]
+    fn range_from_end_checked_mut(&mut self, n: usize) -> Result<&mut [T], IndexError>
+    {
+        let start = range_from_end(self.len(), n)?;
+        Ok(&mut self[start..])
+    }
+}
+
+/// Returns the index `n` places before the last of `len` elements.
+fn from_end(len: usize, n: usize) -> Result<usize, IndexError>
+{
+    match n
+    {
+        | _ if n < len => Ok(len - 1 - n),
+        | _ => Err(Error::new(Bounds(n, len))),
+    }
+}
+
+/// Returns the start of the last `n` of `len` elements.
+fn range_from_end(len: usize, n: usize) -> Result<usize, IndexError>
+{
+    len.checked_sub(n).ok_or(Error::new(EndRange(n, len)))
+}
@@ -0,0 +1,65 @@
+use core::ops::RangeBounds;
+
+use ropey::{Rope, RopeSlice};
+
+use crate::{Error, IndexError, IndexErrorKind::Bounds, try_range};
+
+/// Trait adding checked char and line access to [`Rope`].
+///
+/// Only available with the `ropey` feature.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{GetCheckedRope, IndexErrorKind};
+/// use ropey::Rope;
+///
+/// let rope = Rope::from_str("héllo\nworld");
+/// assert_eq!(rope.char_checked(1), Ok('é'));
+/// assert_eq!(rope.slice_checked(1..3).unwrap(), "él");
+/// assert_eq!(rope.line_checked(1).unwrap(), "world");
+/// assert_eq!(*rope.line_checked(2).unwrap_err().kind(), IndexErrorKind::Bounds(2, 2));
+/// ```
+pub trait GetCheckedRope
+{
+    /// Returns the char at `char_idx`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Bounds` if `char_idx` is out of bounds.
+    fn char_checked(&self, char_idx: usize) -> Result<char, IndexError>;
+
+    /// Returns a slice of the rope over the char range `char_range`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] describing the invalid range as
+    /// [`get_checked`](crate::GetChecked::get_checked) would, measured in chars.
+    fn slice_checked<R>(&self, char_range: R) -> Result<RopeSlice<'_>, IndexError>
+    where R: RangeBounds<usize>;
+
+    /// Returns the line at `line_idx`, including its line break if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Bounds` if `line_idx` is out of bounds.
+    fn line_checked(&self, line_idx: usize) -> Result<RopeSlice<'_>, IndexError>;
+}
+
+impl GetCheckedRope for Rope
+{
+    fn char_checked(&self, char_idx: usize) -> Result<char, IndexError>
+    {
+        self.get_char(char_idx).ok_or(Error::new(Bounds(char_idx, self.len_chars())))
+    }
+
+    fn slice_checked<R>(&self, char_range: R) -> Result<RopeSlice<'_>, IndexError>
+    where R: RangeBounds<usize>
+    {
+        Ok(self.slice(try_range(char_range, self.len_chars())?))
+    }
+
+    fn line_checked(&self, line_idx: usize) -> Result<RopeSlice<'_>, IndexError>
+    {
+        self.get_line(line_idx).ok_or(Error::new(Bounds(line_idx, self.len_lines())))
+    }
+}
@@ -0,0 +1,164 @@
+use crate::{
+    Error, IndexError,
+    IndexErrorKind::{Bounds, Duplicate},
+    MultiIndexError,
+};
+
+/// Trait adding all-or-nothing scatter writes to slices.
+///
+/// Only available when the `no_std` feature is disabled.
+pub trait SetAllChecked<T>
+{
+    /// Writes each `(index, value)` pair of `writes` into the slice, in order, after checking
+    /// that every index is in bounds. If any index is invalid, nothing is written.
+    ///
+    /// When an index appears more than once, the last write to it wins.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`MultiIndexError`] listing the position within `writes` of every invalid pair,
+    /// each with an [`IndexError`] of kind `Bounds`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use get_checked::{IndexErrorKind, SetAllChecked};
+    /// let mut v = [0; 4];
+    /// v.set_all_checked(&[(3, 30), (1, 10)]).unwrap();
+    /// assert_eq!(v, [0, 10, 0, 30]);
+    ///
+    /// let err = v.set_all_checked(&[(0, 1), (4, 2), (9, 3)]).unwrap_err();
+    /// assert_eq!(err.errors().len(), 2);
+    /// assert_eq!(*err.errors()[0].1.kind(), IndexErrorKind::Bounds(4, 4));
+    /// assert_eq!(v, [0, 10, 0, 30]);
+    /// ```
+    fn set_all_checked(&mut self, writes: &[(usize, T)]) -> Result<(), MultiIndexError>
+    where T: Clone;
+}
+
+impl<T> SetAllChecked<T> for [T]
+{
+    fn set_all_checked(&mut self, writes: &[(usize, T)]) -> Result<(), MultiIndexError>
+    where T: Clone
+    {
+        let errors: Vec<_> = writes
+            .iter()
+            .enumerate()
+            .filter(|(_, (index, _))| *index >= self.len())
+            .map(|(entry, (index, _))| (entry, Error::new(Bounds(*index, self.len()))))
+            .collect();
+
+        match errors.is_empty()
+        {
+            | true =>
+            {
+                for (index, value) in writes
+                {
+                    self[*index] = value.clone();
+                }
+                Ok(())
+            },
+            | false => Err(MultiIndexError { errors }),
+        }
+    }
+}
+
+/// Trait adding lookups of several elements by a list of indices, for batch systems that must
+/// not alias elements.
+///
+/// Only available when the `no_std` feature is disabled.
+pub trait GetUniqueChecked<T>
+{
+    /// Returns references to the elements at the distinct indices in `indices`, in the order
+    /// each index first appears. Repeated indices are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Bounds` for the first index in `indices` that is out
+    /// of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// # use get_checked::GetUniqueChecked;
+    /// let v = [10, 20, 30, 40];
+    /// assert_eq!(v.get_unique_checked(&[3, 1, 3, 0]), Ok(vec![&40, &20, &10]));
+    /// ```
+    fn get_unique_checked(&self, indices: &[usize]) -> Result<Vec<&T>, IndexError>;
+
+    /// Returns mutable references to the elements at `indices`, in the same order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Bounds` for the first index in `indices` that is out
+    /// of bounds, or `Duplicate` containing the smallest index that appears more than once,
+    /// since it would be aliased.
+    ///
+    /// # Examples
+    /// ```
+    /// # use get_checked::{GetUniqueChecked, IndexErrorKind};
+    /// let mut v = [10, 20, 30, 40];
+    /// for x in v.get_unique_checked_mut(&[3, 0]).unwrap()
+    /// {
+    ///     *x += 1;
+    /// }
+    /// assert_eq!(v, [11, 20, 30, 41]);
+    ///
+    /// let err = v.get_unique_checked_mut(&[1, 2, 1]).unwrap_err();
+    /// assert_eq!(*err.kind(), IndexErrorKind::Duplicate(1));
+    /// ```
+    fn get_unique_checked_mut(&mut self, indices: &[usize]) -> Result<Vec<&mut T>, IndexError>;
+}
+
+impl<T> GetUniqueChecked<T> for [T]
+{
+    fn get_unique_checked(&self, indices: &[usize]) -> Result<Vec<&T>, IndexError>
+    {
+        check_bounds(indices, self.len())?;
+
+        let mut unique = indices.to_vec();
+        unique.sort_unstable();
+        unique.dedup();
+
+        let mut seen = vec![false; unique.len()];
+        let mut elems = Vec::with_capacity(unique.len());
+        for &index in indices
+        {
+            let slot = unique.binary_search(&index).unwrap_or_default();
+            if !seen[slot]
+            {
+                seen[slot] = true;
+                elems.push(&self[index]);
+            }
+        }
+        Ok(elems)
+    }
+
+    fn get_unique_checked_mut(&mut self, indices: &[usize]) -> Result<Vec<&mut T>, IndexError>
+    {
+        check_bounds(indices, self.len())?;
+
+        let mut order: Vec<(usize, usize)> = indices.iter().copied().zip(0..).collect();
+        order.sort_unstable();
+        if let Some(pair) = order.windows(2).find(|pair| pair[0].0 == pair[1].0)
+        {
+            return Err(Error::new(Duplicate(pair[0].0)));
+        }
+
+        let mut elems: Vec<Option<&mut T>> = indices.iter().map(|_| None).collect();
+        let mut iter = self.iter_mut().enumerate();
+        for (index, position) in order
+        {
+            elems[position] = iter.find(|(i, _)| *i == index).map(|(_, elem)| elem);
+        }
+        Ok(elems.into_iter().flatten().collect())
+    }
+}
+
+/// Returns an error for the first index that is not less than `len`.
+fn check_bounds(indices: &[usize], len: usize) -> Result<(), IndexError>
+{
+    match indices.iter().find(|&&index| index >= len)
+    {
+        | Some(&index) => Err(Error::new(Bounds(index, len))),
+        | None => Ok(()),
+    }
+}
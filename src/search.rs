@@ -0,0 +1,170 @@
+use core::ops::Range;
+
+use crate::{
+    Error, IndexError,
+    IndexErrorKind::{EndRange, Mismatch, NotFound, Unclosed},
+};
+
+/// Trait adding subslice search that reports a missing needle as an [`IndexError`].
+///
+/// The returned range can be passed straight to [`get_checked`](crate::GetChecked::get_checked),
+/// so finding a delimiter and slicing around it stays in one error domain.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{FindSubsliceChecked, GetChecked, IndexErrorKind};
+/// let packet = b"HDR\r\n\r\npayload";
+/// let header = packet.find_subslice_checked(b"\r\n\r\n").unwrap();
+/// assert_eq!(header, 3..7);
+/// assert_eq!(packet.get_checked(header.end..), Ok(&b"payload"[..]));
+///
+/// let err = packet.find_subslice_checked(b"\0").unwrap_err();
+/// assert_eq!(*err.kind(), IndexErrorKind::NotFound(1));
+/// ```
+pub trait FindSubsliceChecked<T>
+{
+    /// Returns the range of the first occurrence of `needle`. An empty needle matches at the
+    /// start of the slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `NotFound` containing the length of `needle` if it does
+    /// not occur in the slice.
+    fn find_subslice_checked(&self, needle: &[T]) -> Result<Range<usize>, IndexError>
+    where T: PartialEq;
+
+    /// Returns the range strictly between the first occurrence of `open` and the first
+    /// occurrence of `close` after it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `NotFound` containing the length of `open` if it does
+    /// not occur in the slice, or `Unclosed` containing the index of the opener if `close` does
+    /// not occur after it.
+    ///
+    /// # Examples
+    /// ```
+    /// # use get_checked::{FindSubsliceChecked, IndexErrorKind};
+    /// let template = b"Hello, {{name}}!";
+    /// let inner = template.between_checked(b"{{", b"}}").unwrap();
+    /// assert_eq!(&template[inner], b"name");
+    ///
+    /// let err = b"Hello, {{name!".between_checked(b"{{", b"}}").unwrap_err();
+    /// assert_eq!(*err.kind(), IndexErrorKind::Unclosed(7));
+    /// ```
+    fn between_checked(&self, open: &[T], close: &[T]) -> Result<Range<usize>, IndexError>
+    where T: PartialEq;
+}
+
+impl<T> FindSubsliceChecked<T> for [T]
+{
+    fn find_subslice_checked(&self, needle: &[T]) -> Result<Range<usize>, IndexError>
+    where T: PartialEq
+    {
+        if needle.is_empty()
+        {
+            return Ok(0..0);
+        }
+
+        self.windows(needle.len())
+            .position(|window| window == needle)
+            .map(|start| start..start + needle.len())
+            .ok_or_else(|| Error::new(NotFound(needle.len())))
+    }
+
+    fn between_checked(&self, open: &[T], close: &[T]) -> Result<Range<usize>, IndexError>
+    where T: PartialEq
+    {
+        let opener = self.find_subslice_checked(open)?;
+        match self[opener.end..].find_subslice_checked(close)
+        {
+            | Ok(closer) => Ok(opener.end..opener.end + closer.start),
+            | Err(_) => Err(Error::new(Unclosed(opener.start))),
+        }
+    }
+}
+
+/// Trait adding variants of `strip_prefix` and `strip_suffix` that describe why the affix does
+/// not match instead of returning `None`.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{IndexErrorKind, StripChecked};
+/// let frame = b"\x7fELF\x02rest";
+/// assert_eq!(frame.strip_prefix_checked(b"\x7fELF"), Ok(&b"\x02rest"[..]));
+///
+/// // The byte at index 2 should have been `prefix[2]`, i.e. `b'L'`.
+/// let err = frame.strip_prefix_checked(b"\x7fEXE").unwrap_err();
+/// assert_eq!(*err.kind(), IndexErrorKind::Mismatch(2));
+/// ```
+pub trait StripChecked<T>
+{
+    /// Returns the slice with `prefix` removed from its start.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Mismatch` containing the index `i` of the first
+    /// element that differs from `prefix`, whose expected element is therefore `prefix[i]`, or
+    /// `EndRange` containing the length of `prefix` and the length of the slice if the slice
+    /// ends before `prefix` does.
+    fn strip_prefix_checked(&self, prefix: &[T]) -> Result<&[T], IndexError>
+    where T: PartialEq;
+
+    /// Returns the slice with `suffix` removed from its end.
+    ///
+    /// Elements are compared from the end, so the reported mismatch is the last one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Mismatch` containing the index `i` of the last element
+    /// that differs from `suffix`, whose expected element is therefore
+    /// `suffix[i + suffix.len() - self.len()]`, or
+    /// `EndRange` containing the length of `suffix` and the length of the slice if the slice
+    /// starts after `suffix` does.
+    ///
+    /// # Examples
+    /// ```
+    /// # use get_checked::{IndexErrorKind, StripChecked};
+    /// let line = b"key=value\r\n";
+    /// assert_eq!(line.strip_suffix_checked(b"\r\n"), Ok(&b"key=value"[..]));
+    ///
+    /// let err = b"key=value\n".strip_suffix_checked(b"\r\n").unwrap_err();
+    /// assert_eq!(*err.kind(), IndexErrorKind::Mismatch(8));
+    /// ```
+    fn strip_suffix_checked(&self, suffix: &[T]) -> Result<&[T], IndexError>
+    where T: PartialEq;
+}
+
+impl<T> StripChecked<T> for [T]
+{
+    fn strip_prefix_checked(&self, prefix: &[T]) -> Result<&[T], IndexError>
+    where T: PartialEq
+    {
+        if let Some(i) = self.iter().zip(prefix).position(|(a, b)| a != b)
+        {
+            return Err(Error::new(Mismatch(i)));
+        }
+
+        match self.len()
+        {
+            | len if len < prefix.len() => Err(Error::new(EndRange(prefix.len(), len))),
+            | _ => Ok(&self[prefix.len()..]),
+        }
+    }
+
+    fn strip_suffix_checked(&self, suffix: &[T]) -> Result<&[T], IndexError>
+    where T: PartialEq
+    {
+        let (len, suffix_len) = (self.len(), suffix.len());
+        if let Some(i) = self.iter().rev().zip(suffix.iter().rev()).position(|(a, b)| a != b)
+        {
+            return Err(Error::new(Mismatch(len - 1 - i)));
+        }
+
+        match len
+        {
+            | _ if len < suffix_len => Err(Error::new(EndRange(suffix_len, len))),
+            | _ => Ok(&self[..len - suffix_len]),
+        }
+    }
+}
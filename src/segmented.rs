@@ -0,0 +1,135 @@
+use core::ops::RangeBounds;
+
+use crate::{Error, IndexError, IndexErrorKind::Bounds, try_range};
+
+/// A view over a sequence of non-contiguous slices (segments) that are indexed as if they were
+/// one contiguous slice.
+///
+/// Errors report the global position and the total length of the view. Lookups scan the
+/// segments linearly, so access is `O(n)` in the number of segments.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{IndexErrorKind, Segmented};
+/// let pages: [&[u8]; 3] = [&[1, 2], &[3], &[4, 5, 6]];
+/// let view = Segmented::new(&pages);
+///
+/// assert_eq!(view.len(), 6);
+/// assert_eq!(view.get_checked(3), Ok(&4));
+/// assert_eq!(*view.get_checked(6).unwrap_err().kind(), IndexErrorKind::Bounds(6, 6));
+///
+/// let sub = view.get_range_checked(1..4).unwrap();
+/// assert_eq!(sub.iter().copied().collect::<Vec<_>>(), [2, 3, 4]);
+/// ```
+#[derive(Debug)]
+pub struct Segmented<'a, T>
+{
+    segments: &'a [&'a [T]],
+    start:    usize,
+    len:      usize,
+}
+
+impl<T> Clone for Segmented<'_, T>
+{
+    fn clone(&self) -> Self
+    {
+        *self
+    }
+}
+
+impl<T> Copy for Segmented<'_, T> {}
+
+impl<'a, T> Segmented<'a, T>
+{
+    /// Creates a view spanning all of `segments`.
+    ///
+    /// If the total length of the segments exceeds [`usize::MAX`], which can only happen when a
+    /// segment is repeated or holds zero-sized elements, the length saturates and the view spans
+    /// only the first `usize::MAX` elements.
+    #[inline]
+    pub fn new(segments: &'a [&'a [T]]) -> Self
+    {
+        let len = segments.iter().fold(0, |len: usize, s| len.saturating_add(s.len()));
+        Segmented { segments, start: 0, len }
+    }
+
+    /// Returns the total number of elements in the view.
+    #[inline]
+    pub fn len(&self) -> usize
+    {
+        self.len
+    }
+
+    /// Returns `true` if the view contains no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool
+    {
+        self.len == 0
+    }
+
+    /// Returns a reference to the element at the global position `index`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Bounds` containing `index` and the total length if
+    /// `index` is out of bounds.
+    pub fn get_checked(&self, index: usize) -> Result<&'a T, IndexError>
+    {
+        if index >= self.len
+        {
+            return Err(Error::new(Bounds(index, self.len)));
+        }
+
+        let mut pos = self.start + index;
+        for segment in self.segments
+        {
+            match segment.get(pos)
+            {
+                | Some(elem) => return Ok(elem),
+                | None => pos -= segment.len(),
+            }
+        }
+        unreachable!()
+    }
+
+    /// Returns a sub-view over the global `range`, which may span several segments.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] describing the invalid range against the total length, as
+    /// [`get_checked`](crate::GetChecked::get_checked) would for a contiguous slice.
+    pub fn get_range_checked<R>(&self, range: R) -> Result<Segmented<'a, T>, IndexError>
+    where R: RangeBounds<usize>
+    {
+        let range = try_range(range, self.len)?;
+        Ok(Segmented {
+            segments: self.segments,
+            start:    self.start + range.start,
+            len:      range.len(),
+        })
+    }
+
+    /// Returns an iterator over the non-empty slices making up the view.
+    pub fn slices(&self) -> impl Iterator<Item = &'a [T]>
+    {
+        let (mut skip, mut remaining) = (self.start, self.len);
+        self.segments.iter().filter_map(move |segment| {
+            if skip >= segment.len()
+            {
+                skip -= segment.len();
+                return None;
+            }
+            let segment = &segment[skip..];
+            let n = segment.len().min(remaining);
+            skip = 0;
+            remaining -= n;
+            Some(&segment[..n]).filter(|s| !s.is_empty())
+        })
+    }
+
+    /// Returns an iterator over the elements of the view.
+    pub fn iter(&self) -> impl Iterator<Item = &'a T>
+    {
+        self.slices().flatten()
+    }
+}
@@ -0,0 +1,53 @@
+use core::ops;
+
+use crate::{Error, IndexError, IndexErrorKind::Bounds};
+
+/// Trait adding checked positional access to ranges, treating them as lazily materialized
+/// sequences of integers.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{GetCheckedSeq, IndexErrorKind};
+/// assert_eq!((10..20).nth_checked(3), Ok(13));
+/// assert_eq!(*(10..20).nth_checked(10).unwrap_err().kind(), IndexErrorKind::Bounds(10, 10));
+/// assert_eq!((0..=usize::MAX).nth_checked(usize::MAX), Ok(usize::MAX));
+/// ```
+pub trait GetCheckedSeq
+{
+    /// Returns the element at `index` of the sequence.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Bounds` containing `index` and the length of the
+    /// sequence if `index` is out of bounds.
+    fn nth_checked(&self, index: usize) -> Result<usize, IndexError>;
+}
+
+impl GetCheckedSeq for ops::Range<usize>
+{
+    #[inline]
+    fn nth_checked(&self, index: usize) -> Result<usize, IndexError>
+    {
+        let len = self.end.saturating_sub(self.start);
+        match index
+        {
+            | _ if index < len => Ok(self.start + index),
+            | _ => Err(Error::new(Bounds(index, len))),
+        }
+    }
+}
+
+impl GetCheckedSeq for ops::RangeInclusive<usize>
+{
+    #[inline]
+    fn nth_checked(&self, index: usize) -> Result<usize, IndexError>
+    {
+        let (start, end) = (*self.start(), *self.end());
+        match index
+        {
+            | _ if self.is_empty() => Err(Error::new(Bounds(index, 0))),
+            | _ if index <= end - start => Ok(start + index),
+            | _ => Err(Error::new(Bounds(index, end - start + 1))),
+        }
+    }
+}
@@ -0,0 +1,111 @@
+#[cfg(feature = "slab")]
+use slab::Slab;
+#[cfg(feature = "slotmap")]
+use slotmap::{Key, SlotMap};
+
+#[cfg(feature = "slotmap")]
+use crate::IndexErrorKind::StaleOpaqueKey;
+#[cfg(feature = "slab")]
+use crate::IndexErrorKind::Vacant;
+use crate::{Error, IndexError};
+
+/// Trait adding checked keyed access to [`Slab`].
+///
+/// Only available with the `slab` feature.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{GetCheckedSlab, IndexErrorKind};
+/// use slab::Slab;
+///
+/// let mut slab = Slab::new();
+/// let key = slab.insert("a");
+/// assert_eq!(slab.get_checked(key), Ok(&"a"));
+///
+/// slab.remove(key);
+/// assert_eq!(*slab.get_checked(key).unwrap_err().kind(), IndexErrorKind::Vacant(key));
+/// ```
+#[cfg(feature = "slab")]
+pub trait GetCheckedSlab<T>
+{
+    /// Returns a reference to the value stored under `key`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Vacant` if no value is stored under `key`.
+    fn get_checked(&self, key: usize) -> Result<&T, IndexError>;
+
+    /// Returns a mutable reference to the value stored under `key`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Vacant` if no value is stored under `key`.
+    fn get_checked_mut(&mut self, key: usize) -> Result<&mut T, IndexError>;
+}
+
+#[cfg(feature = "slab")]
+impl<T> GetCheckedSlab<T> for Slab<T>
+{
+    fn get_checked(&self, key: usize) -> Result<&T, IndexError>
+    {
+        self.get(key).ok_or(Error::new(Vacant(key)))
+    }
+
+    fn get_checked_mut(&mut self, key: usize) -> Result<&mut T, IndexError>
+    {
+        self.get_mut(key).ok_or(Error::new(Vacant(key)))
+    }
+}
+
+/// Trait adding checked keyed access to [`SlotMap`].
+///
+/// Unlike a slab key, a slot map key carries a version, so a key whose value was removed stays
+/// invalid even after its slot is reused. The key does not expose the index of its slot, so
+/// lookups of such a key fail with kind `StaleOpaqueKey` rather than `StaleKey`.
+///
+/// Only available with the `slotmap` feature.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{GetCheckedSlotMap, IndexErrorKind};
+/// use slotmap::{DefaultKey, SlotMap};
+///
+/// let mut map: SlotMap<DefaultKey, _> = SlotMap::new();
+/// let old = map.insert("a");
+/// map.remove(old);
+/// let new = map.insert("b");
+///
+/// assert_eq!(map.get_checked(new), Ok(&"b"));
+/// assert_eq!(*map.get_checked(old).unwrap_err().kind(), IndexErrorKind::StaleOpaqueKey());
+/// ```
+#[cfg(feature = "slotmap")]
+pub trait GetCheckedSlotMap<K, V>
+{
+    /// Returns a reference to the value stored under `key`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `StaleOpaqueKey` if `key` is no longer valid.
+    fn get_checked(&self, key: K) -> Result<&V, IndexError>;
+
+    /// Returns a mutable reference to the value stored under `key`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `StaleOpaqueKey` if `key` is no longer valid.
+    fn get_checked_mut(&mut self, key: K) -> Result<&mut V, IndexError>;
+}
+
+#[cfg(feature = "slotmap")]
+impl<K: Key, V> GetCheckedSlotMap<K, V> for SlotMap<K, V>
+{
+    fn get_checked(&self, key: K) -> Result<&V, IndexError>
+    {
+        self.get(key).ok_or(Error::new(StaleOpaqueKey()))
+    }
+
+    fn get_checked_mut(&mut self, key: K) -> Result<&mut V, IndexError>
+    {
+        self.get_mut(key).ok_or(Error::new(StaleOpaqueKey()))
+    }
+}
@@ -0,0 +1,58 @@
+use crate::{GetChecked, IndexError};
+
+/// A slice sorted in ascending order, allowing elements to be retrieved by rank.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{IndexErrorKind, SortedSlice};
+/// let mut scores = [30, 10, 20];
+/// let sorted = SortedSlice::new(&mut scores);
+///
+/// assert_eq!(sorted.rank_checked(0), Ok(&10));
+/// assert_eq!(*sorted.rank_checked(3).unwrap_err().kind(), IndexErrorKind::Bounds(3, 3));
+/// ```
+#[derive(Debug)]
+pub struct SortedSlice<'a, T>
+{
+    slice: &'a [T],
+}
+
+impl<'a, T: Ord> SortedSlice<'a, T>
+{
+    /// Sorts `slice` in place and wraps it.
+    #[inline]
+    pub fn new(slice: &'a mut [T]) -> Self
+    {
+        slice.sort_unstable();
+        SortedSlice { slice }
+    }
+
+    /// Returns the `n`th smallest element, starting from `0`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Bounds` if `n` is out of bounds.
+    #[inline]
+    pub fn rank_checked(&self, n: usize) -> Result<&'a T, IndexError>
+    {
+        self.slice.get_checked(n)
+    }
+
+    /// Returns the underlying sorted slice.
+    #[inline]
+    pub fn as_slice(&self) -> &'a [T]
+    {
+        self.slice
+    }
+}
+
+impl<T> GetChecked<T> for SortedSlice<'_, T>
+{
+    type Target = [T];
+
+    #[inline]
+    fn checked_target(&self) -> &[T]
+    {
+        self.slice
+    }
+}
@@ -0,0 +1,187 @@
+use core::ops::Range;
+
+use http::StatusCode;
+
+use crate::{
+    Error, IndexError,
+    IndexErrorKind::{
+        AxisBounds, Bounds, CapacityOverflow, CharBoundary, Discontiguous, Duplicate, EndOverflow,
+        EndRange, InvalidDigit, LengthMismatch, LimitExceeded, Misaligned, Mismatch, NotAscii,
+        NotFound, Order, Overlap, QuotaExceeded, Rejected, StaleKey, StaleOpaqueKey, StartOverflow,
+        StartRange, StartUnderflow, SurrogateBoundary, Unclosed, Unread, Vacant, ZeroChunk,
+    },
+};
+
+/// Problem details fields, as described by RFC 9457, suggested for an [`IndexError`] caused by a
+/// client-provided index or range.
+///
+/// Only available with the `http` feature.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProblemDetails
+{
+    /// Suggested response status.
+    pub status:       StatusCode,
+    /// Short identifier for the problem type, e.g. `"invalid-range"`, intended to be appended to
+    /// a service specific base URI.
+    pub problem_type: &'static str,
+    /// Human-readable summary of the problem type.
+    pub title:        &'static str,
+    /// Human-readable explanation of this occurrence, i.e. the error message.
+    pub detail:       String,
+}
+
+/// HTTP helpers for errors caused by client-provided indices or ranges.
+impl IndexError
+{
+    /// Suggests the response status for the error.
+    ///
+    /// - `Bounds`, `Vacant`, `StaleKey` and `StaleOpaqueKey` map to `404 Not Found`.
+    /// - `StartRange` and `EndRange` map to `416 Range Not Satisfiable`.
+    /// - All other kinds describe a malformed request and map to `400 Bad Request`.
+    ///
+    /// Only available with the `http` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// # use get_checked::GetChecked;
+    /// use http::StatusCode;
+    ///
+    /// let v = [1, 2, 3];
+    /// assert_eq!(v.get_checked(2..5).unwrap_err().http_status(), StatusCode::RANGE_NOT_SATISFIABLE);
+    /// ```
+    pub fn http_status(&self) -> StatusCode
+    {
+        self.problem().0
+    }
+
+    /// Suggests the problem details fields for the error.
+    ///
+    /// Only available with the `http` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// # use get_checked::GetChecked;
+    /// use http::StatusCode;
+    ///
+    /// let v = [1, 2, 3];
+    /// let problem = v.get_checked(2..1).unwrap_err().problem_details();
+    /// assert_eq!(problem.status, StatusCode::BAD_REQUEST);
+    /// assert_eq!(problem.problem_type, "invalid-range");
+    /// assert_eq!(problem.detail, "slice index starts at 2 but ends at 1");
+    /// ```
+    pub fn problem_details(&self) -> ProblemDetails
+    {
+        let (status, problem_type, title) = self.problem();
+        ProblemDetails { status, problem_type, title, detail: self.to_string() }
+    }
+
+    #[rustfmt::skip]
+    fn problem(&self) -> (StatusCode, &'static str, &'static str)
+    {
+        match self.kind
+        {
+            | Bounds(..) | AxisBounds(..)     => (StatusCode::NOT_FOUND, "index-not-found", "Index out of bounds"),
+            | Vacant(..) | StaleKey(..)       => (StatusCode::NOT_FOUND, "index-not-found", "No entry at index"),
+            | StaleOpaqueKey()                => (StatusCode::NOT_FOUND, "index-not-found", "No entry at index"),
+            | NotFound(..)                    => (StatusCode::NOT_FOUND, "not-found", "Not found"),
+            | StartUnderflow(..)              => (StatusCode::RANGE_NOT_SATISFIABLE, "range-not-satisfiable", "Range not satisfiable"),
+            | StartRange(..) | EndRange(..)   => (StatusCode::RANGE_NOT_SATISFIABLE, "range-not-satisfiable", "Range not satisfiable"),
+            | Order(..) | Overlap(..)         => (StatusCode::BAD_REQUEST, "invalid-range", "Invalid range"),
+            | Discontiguous(..)               => (StatusCode::BAD_REQUEST, "invalid-range", "Invalid range"),
+            | Duplicate(..)                   => (StatusCode::BAD_REQUEST, "invalid-index", "Duplicate index"),
+            | LimitExceeded(..)               => (StatusCode::RANGE_NOT_SATISFIABLE, "limit-exceeded", "Access limit exceeded"),
+            | QuotaExceeded(..)               => (StatusCode::PAYLOAD_TOO_LARGE, "quota-exceeded", "Quota exceeded"),
+            | StartOverflow() | EndOverflow() => (StatusCode::BAD_REQUEST, "invalid-range", "Invalid range"),
+            | CharBoundary(..)                => (StatusCode::BAD_REQUEST, "invalid-offset", "Invalid offset"),
+            | SurrogateBoundary(..)           => (StatusCode::BAD_REQUEST, "invalid-offset", "Invalid offset"),
+            | Misaligned(..)                  => (StatusCode::BAD_REQUEST, "invalid-offset", "Invalid offset"),
+            | Mismatch(..)                    => (StatusCode::BAD_REQUEST, "invalid-format", "Invalid format"),
+            | Unclosed(..)                    => (StatusCode::BAD_REQUEST, "invalid-format", "Invalid format"),
+            | Unread(..)                      => (StatusCode::BAD_REQUEST, "invalid-format", "Invalid format"),
+            | InvalidDigit(..)                => (StatusCode::BAD_REQUEST, "invalid-encoding", "Invalid encoding"),
+            | NotAscii(..)                    => (StatusCode::BAD_REQUEST, "invalid-encoding", "Invalid encoding"),
+            | LengthMismatch(..)              => (StatusCode::BAD_REQUEST, "invalid-length", "Invalid length"),
+            | Rejected(..)                    => (StatusCode::UNPROCESSABLE_ENTITY, "rejected", "Rejected"),
+            | ZeroChunk()                     => (StatusCode::BAD_REQUEST, "invalid-size", "Invalid size"),
+            | CapacityOverflow()              => (StatusCode::BAD_REQUEST, "invalid-size", "Invalid size"),
+        }
+    }
+}
+
+/// A single byte range as written in an HTTP `Range` header, with inclusive positions.
+///
+/// Only available with the `http` feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HttpRange
+{
+    /// `first-last`, e.g. `bytes=0-499`.
+    FromTo(usize, usize),
+    /// `first-`, e.g. `bytes=500-`.
+    From(usize),
+    /// `-length`, the final `length` bytes, e.g. `bytes=-500`.
+    Suffix(usize),
+}
+
+/// Resolves an HTTP byte range against a representation of `total_len` bytes into a concrete
+/// `Range<usize>` following RFC 9110 semantics.
+///
+/// A last position at or past the end, or a suffix longer than the representation, is clamped to
+/// `total_len`. A first position at or past the end, a zero-length suffix, or any range over an
+/// empty representation is unsatisfiable.
+///
+/// A first position after the last makes the range invalid rather than unsatisfiable. RFC 9110
+/// §14.1.1 requires a server to ignore such a `Range` header and serve the full representation
+/// with `200 OK`. This function deviates by reporting it as an `Order` error, so that a caller
+/// can tell a full response apart from a partial one. On that error, callers should serve the
+/// full representation rather than the `400` suggested by [`IndexError::http_status`].
+///
+/// Only available with the `http` feature.
+///
+/// # Errors
+///
+/// Returns an [`IndexError`] of kind `Order` if the first position is after the last, which
+/// RFC 9110 says to ignore as described above, or `StartRange` if the range is unsatisfiable,
+/// which maps to `416` via [`IndexError::http_status`].
+///
+/// # Examples
+/// ```
+/// # use get_checked::{validate_http_range, HttpRange, IndexErrorKind};
+/// assert_eq!(validate_http_range(HttpRange::FromTo(0, 499), 1000), Ok(0..500));
+/// assert_eq!(validate_http_range(HttpRange::FromTo(900, 1999), 1000), Ok(900..1000));
+/// assert_eq!(validate_http_range(HttpRange::From(500), 1000), Ok(500..1000));
+/// assert_eq!(validate_http_range(HttpRange::Suffix(100), 1000), Ok(900..1000));
+///
+/// let err = validate_http_range(HttpRange::From(1000), 1000).unwrap_err();
+/// assert_eq!(*err.kind(), IndexErrorKind::StartRange(1000, 1000));
+///
+/// // An invalid range is ignored, serving the full representation.
+/// let range = match validate_http_range(HttpRange::FromTo(500, 499), 1000)
+/// {
+///     | Err(err) if matches!(err.kind(), IndexErrorKind::Order(..)) => 0..1000,
+///     | result => result.unwrap(),
+/// };
+/// assert_eq!(range, 0..1000);
+/// ```
+pub fn validate_http_range(range: HttpRange, total_len: usize) -> Result<Range<usize>, IndexError>
+{
+    let (start, end) = match range
+    {
+        | HttpRange::FromTo(first, last) if first > last =>
+        {
+            return Err(Error::new(Order(first, last)).with_range(first, last, total_len));
+        },
+        | HttpRange::FromTo(first, last) => (first, last.saturating_add(1).min(total_len)),
+        | HttpRange::From(first) => (first, total_len),
+        | HttpRange::Suffix(0) => (total_len, total_len),
+        | HttpRange::Suffix(length) => (total_len.saturating_sub(length), total_len),
+    };
+
+    match start
+    {
+        | _ if start >= total_len =>
+        {
+            Err(Error::new(StartRange(start, total_len)).with_range(start, end, total_len))
+        },
+        | _ => Ok(start..end),
+    }
+}
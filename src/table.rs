@@ -0,0 +1,157 @@
+use std::{error, fmt, ops::Range};
+
+use crate::{
+    Error, GetCheckedSliceIndex, IndexError,
+    IndexErrorKind::{EndOverflow, Overlap},
+    try_range,
+};
+
+/// An error that can be returned when validating several indices at once, recording every
+/// invalid entry rather than only the first.
+///
+/// Only available when the `no_std` feature is disabled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultiIndexError
+{
+    pub(crate) errors: Vec<(usize, IndexError)>,
+}
+
+impl MultiIndexError
+{
+    /// Outputs the position of each invalid entry along with its error, in entry order.
+    pub fn errors(&self) -> &[(usize, IndexError)]
+    {
+        &self.errors
+    }
+}
+
+impl fmt::Display for MultiIndexError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        let (entry, err) = &self.errors[0];
+        write!(f, "{} invalid entries, first at entry {}: {}", self.errors.len(), entry, err)
+    }
+}
+
+impl error::Error for MultiIndexError
+{
+    fn source(&self) -> Option<&(dyn error::Error + 'static)>
+    {
+        Some(&self.errors[0].1)
+    }
+}
+
+/// Resolves a table of `(offset, length)` entries against `slice`, as found in the headers of
+/// archive and container formats.
+///
+/// Every entry is validated, without overflow, before any subslice is returned.
+///
+/// Only available when the `no_std` feature is disabled.
+///
+/// # Errors
+///
+/// Returns a [`MultiIndexError`] listing every entry whose range is invalid, each with an
+/// [`IndexError`] of kind `EndOverflow` if `offset + length` overflows or describing the range
+/// as [`get_checked`](crate::GetChecked::get_checked) would.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{resolve_offset_table_checked, IndexErrorKind};
+/// let data = b"headerbodytail";
+///
+/// let parts = resolve_offset_table_checked(data, &[(0, 6), (6, 4), (10, 4)]).unwrap();
+/// assert_eq!(parts, [&b"header"[..], b"body", b"tail"]);
+///
+/// let err = resolve_offset_table_checked(data, &[(0, 6), (12, 4), (20, 1)]).unwrap_err();
+/// assert_eq!(err.errors().len(), 2);
+/// assert_eq!(err.errors()[0].0, 1);
+/// assert_eq!(*err.errors()[0].1.kind(), IndexErrorKind::EndRange(16, 14));
+/// ```
+pub fn resolve_offset_table_checked<'a, T>(
+    slice: &'a [T],
+    entries: &[(usize, usize)],
+) -> Result<Vec<&'a [T]>, MultiIndexError>
+{
+    let mut resolved = Vec::with_capacity(entries.len());
+    let mut errors = Vec::new();
+
+    for (entry, &(offset, length)) in entries.iter().enumerate()
+    {
+        let part = match offset.checked_add(length)
+        {
+            | Some(end) => (offset..end).get_checked(slice),
+            | None => Err(Error::new(EndOverflow()).with_range(offset, usize::MAX, slice.len())),
+        };
+
+        match part
+        {
+            | Ok(part) => resolved.push(part),
+            | Err(e) => errors.push((entry, e)),
+        }
+    }
+
+    match errors.is_empty()
+    {
+        | true => Ok(resolved),
+        | false => Err(MultiIndexError { errors }),
+    }
+}
+
+/// Validates every range in `ranges` against a slice of length `len`, optionally also requiring
+/// that the ranges be `sorted` in ascending order and non-overlapping, as scatter writes need.
+///
+/// Empty ranges never overlap their neighbours.
+///
+/// Only available when the `no_std` feature is disabled.
+///
+/// # Errors
+///
+/// Returns a [`MultiIndexError`] listing every invalid range, each with an [`IndexError`]
+/// describing the range as [`get_checked`](crate::GetChecked::get_checked) would, or, if
+/// `sorted` is set, of kind `Overlap` containing its start and the end of the nearest preceding
+/// valid range if it starts before that end.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{validate_ranges_checked, IndexErrorKind};
+/// assert!(validate_ranges_checked(&[0..2, 4..6, 2..4], 8, false).is_ok());
+///
+/// let err = validate_ranges_checked(&[0..2, 4..6, 2..4, 6..9], 8, true).unwrap_err();
+/// assert_eq!(err.errors()[0].0, 2);
+/// assert_eq!(*err.errors()[0].1.kind(), IndexErrorKind::Overlap(2, 6));
+/// assert_eq!(*err.errors()[1].1.kind(), IndexErrorKind::EndRange(9, 8));
+/// ```
+pub fn validate_ranges_checked(
+    ranges: &[Range<usize>],
+    len: usize,
+    sorted: bool,
+) -> Result<(), MultiIndexError>
+{
+    let mut errors = Vec::new();
+    let mut end = 0;
+
+    for (entry, range) in ranges.iter().enumerate()
+    {
+        let result = match try_range(range.clone(), len)
+        {
+            | Ok(r) if sorted && !r.is_empty() && r.start < end =>
+            {
+                Err(Error::new(Overlap(r.start, end)).with_range(r.start, r.end, len))
+            },
+            | result => result,
+        };
+
+        match result
+        {
+            | Ok(r) => end = end.max(r.end),
+            | Err(e) => errors.push((entry, e)),
+        }
+    }
+
+    match errors.is_empty()
+    {
+        | true => Ok(()),
+        | false => Err(MultiIndexError { errors }),
+    }
+}
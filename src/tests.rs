@@ -1,3 +1,12 @@
+// The baseline tests clone arrays and index with reversed ranges on purpose.
+#![allow(clippy::clone_on_copy, clippy::reversed_empty_ranges)]
+
+// The test harness links std even when the library is `no_std`; bring in what the tests use.
+#[cfg(feature = "no_std")]
+extern crate std;
+#[cfg(feature = "no_std")]
+use std::{prelude::v1::*, vec};
+
 #[cfg(test)]
 use super::GetChecked;
 use crate::ErrorKind;
@@ -228,7 +237,7 @@ fn immut_range_to_inclusive_error()
     ];
 
     let err = bytes.get_checked(..=16).unwrap_err();
-    assert_eq!(*err.kind(), ErrorKind::EndRange(17, 16));
+    assert_eq!(*err.kind(), ErrorKind::EndRange(16, 16));
 }
 
 #[test]
@@ -239,8 +248,8 @@ fn immut_range_from_slice_error()
         0x1F,
     ];
 
-    let err = bytes.get_checked(17..5).unwrap_err();
-    assert_eq!(*err.kind(), ErrorKind::Order(17, 5));
+    let err = bytes.get_checked(15..5).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::Order(15, 5));
 }
 
 #[test]
@@ -251,8 +260,8 @@ fn immut_range_slice_inclusive_error()
         0x1F,
     ];
 
-    let err = bytes.get_checked(17..=4).unwrap_err();
-    assert_eq!(*err.kind(), ErrorKind::Order(17, 5));
+    let err = bytes.get_checked(15..=4).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::Order(15, 5));
 }
 
 #[test]
@@ -264,7 +273,22 @@ fn immut_range_overflow_error()
     ];
 
     let err = bytes.get_checked(0..=usize::MAX).unwrap_err();
-    assert_eq!(*err.kind(), ErrorKind::EndOverflow());
+    assert_eq!(*err.kind(), ErrorKind::EndRange(usize::MAX, 16));
+}
+
+#[test]
+fn get_if_checked()
+{
+    let bytes = [0xA0, 0x11, 0xB2];
+
+    assert_eq!(bytes.get_if_checked(0, |b| *b >= 0x80), Ok(&0xA0));
+    assert_eq!(bytes.to_vec().get_if_checked(2, |b| *b >= 0x80), Ok(&0xB2));
+
+    let err = bytes.get_if_checked(1, |b| *b >= 0x80).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::Rejected(1));
+    assert_eq!(err.to_string(), "element at index 1 was rejected");
+    let err = bytes.get_if_checked(3, |_| unreachable!()).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::Bounds(3, 3));
 }
 
 // Mutable tests:
@@ -510,7 +534,7 @@ fn mut_range_to_inclusive_error()
     ];
 
     let err = bytes.get_checked_mut(..=16).unwrap_err();
-    assert_eq!(*err.kind(), ErrorKind::EndRange(17, 16));
+    assert_eq!(*err.kind(), ErrorKind::EndRange(16, 16));
 }
 
 #[test]
@@ -521,8 +545,8 @@ fn mut_range_slice_error()
         0x1F,
     ];
 
-    let err = bytes.get_checked_mut(17..5).unwrap_err();
-    assert_eq!(*err.kind(), ErrorKind::Order(17, 5));
+    let err = bytes.get_checked_mut(15..5).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::Order(15, 5));
 }
 
 #[test]
@@ -533,8 +557,8 @@ fn mut_range_slice_inclusive_error()
         0x1F,
     ];
 
-    let err = bytes.get_checked_mut(17..=4).unwrap_err();
-    assert_eq!(*err.kind(), ErrorKind::Order(17, 5));
+    let err = bytes.get_checked_mut(15..=4).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::Order(15, 5));
 }
 
 #[test]
@@ -546,5 +570,2934 @@ fn mut_range_overflow_error()
     ];
 
     let err = bytes.get_checked_mut(0..=usize::MAX).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::EndRange(usize::MAX, 16));
+}
+
+// Error tests:
+
+#[test]
+#[cfg(not(feature = "no_std"))]
+fn error_with_source()
+{
+    use std::error::Error;
+
+    let bytes = [0x61, 0xFF];
+    let parse_err = "0xFF".parse::<u8>().unwrap_err();
+
+    let err = bytes.get_checked(2).unwrap_err();
+    assert!(err.source().is_none());
+
+    let err = err.with_source(parse_err.clone());
+    assert_eq!(err.source().unwrap().to_string(), parse_err.to_string());
+    assert_eq!(err.to_string(), "index out of bounds: the len is 2 but the index is 2");
+    assert_eq!(err, bytes.get_checked(2).unwrap_err());
+}
+
+#[test]
+fn error_send_sync()
+{
+    #[cfg(not(feature = "no_std"))]
+    use std::error::Error;
+
+    #[cfg(feature = "no_std")]
+    use core_error::Error;
+
+    fn boxed(index: usize) -> Result<u8, Box<dyn Error + Send + Sync + 'static>>
+    {
+        let bytes = [0xA0, 0x11];
+        Ok(*bytes.get_checked(index)?)
+    }
+
+    assert_eq!(boxed(1).ok(), Some(0x11));
+    let err = std::thread::spawn(|| boxed(2)).join().unwrap().unwrap_err();
+    assert_eq!(err.to_string(), "index out of bounds: the len is 2 but the index is 2");
+
+    #[cfg(not(feature = "no_std"))]
+    {
+        let err = err.downcast::<crate::IndexError>().unwrap();
+        assert_eq!(*err.kind(), ErrorKind::Bounds(2, 2));
+
+        let sourced = [0u8; 0].get_checked(0).unwrap_err().with_source(*err);
+        let err = std::thread::spawn(move || sourced).join().unwrap();
+        assert!(err.source().is_some());
+    }
+}
+
+#[test]
+fn error_kind_all()
+{
+    use crate::{Axis, IndexError};
+
+    let kinds = ErrorKind::all();
+    assert_eq!(kinds.len(), ErrorKind::COUNT);
+    assert_eq!(
+        ErrorKind::all().last().map(|kind| usize::from(kind.discriminant())),
+        Some(ErrorKind::COUNT - 1)
+    );
+    for (i, kind) in kinds.enumerate()
+    {
+        assert_eq!(usize::from(kind.discriminant()), i);
+        assert_ne!(IndexError::new(kind).to_string(), "");
+    }
+
+    assert_eq!(ErrorKind::Bounds(4, 3).discriminant(), ErrorKind::Bounds(0, 0).discriminant());
+    assert_eq!(ErrorKind::AxisBounds(Axis::Y, 1, 2).discriminant(), 12);
+    assert_eq!(ErrorKind::Unread(1, 2).discriminant(), 27);
+    assert_eq!(ErrorKind::StaleOpaqueKey().discriminant(), 28);
+}
+
+#[test]
+#[cfg(all(feature = "anyhow", not(feature = "no_std")))]
+fn error_context_index()
+{
+    use crate::{IndexContext, IndexError};
+
+    let bytes = [0xA0, 0x11, 0xB2];
+
+    let ok = bytes.get_checked(1).context_index().unwrap();
+    assert_eq!(*ok, 0x11);
+
+    let line = line!() + 1;
+    let err = bytes.get_checked(1..4).context_index().unwrap_err();
+    let location = format!("at {}:{}:", file!(), line);
+    assert!(err.to_string().starts_with(&location));
+    let msg = format!("{:#}", err);
+    assert!(msg.starts_with(&location));
+    assert!(msg.ends_with(": range end index 4 out of range for slice of length 3"));
+    assert_eq!(msg.matches("range end index").count(), 1);
+    assert_eq!(*err.downcast_ref::<IndexError>().unwrap().kind(), ErrorKind::EndRange(4, 3));
+}
+
+// Recovery tests:
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn recover_or_clamped()
+{
+    use crate::IndexRecover;
+
+    let bytes = [0xA0, 0x11, 0xB2, 0xD3, 0x0F4, 0x35];
+
+    assert_eq!(bytes.get_checked(1..3).or_clamped(&bytes), &bytes[1..3]);
+    assert_eq!(bytes.get_checked(2..10).or_clamped(&bytes), &bytes[2..]);
+    assert_eq!(bytes.get_checked(..10).or_clamped(&bytes), &bytes[..]);
+    assert_eq!(bytes.get_checked(3..=usize::MAX).or_clamped(&bytes), &bytes[3..]);
+    assert_eq!(bytes.get_checked(8..).or_clamped(&bytes), &[]);
+    assert_eq!(bytes.get_checked(4..2).or_clamped(&bytes), &[]);
+    assert_eq!(bytes.get_checked(9..7).or_clamped(&bytes), &[]);
+}
+
+#[test]
+fn recover_or_empty_or_full()
+{
+    use crate::IndexRecover;
+
+    let bytes = [0xA0, 0x11, 0xB2, 0xD3, 0x0F4, 0x35];
+
+    assert_eq!(bytes.get_checked(1..3).or_empty(), &bytes[1..3]);
+    assert_eq!(bytes.get_checked(1..7).or_empty(), &[]);
+    assert_eq!(bytes.get_checked(1..3).or_full(&bytes), &bytes[1..3]);
+    assert_eq!(bytes.get_checked(1..7).or_full(&bytes), &bytes[..]);
+}
+
+// Generic receiver tests:
+
+#[test]
+fn generic_ref_receivers()
+{
+    use crate::GetCheckedSliceIndex;
+
+    fn sum<S>(s: S, range: core::ops::Range<usize>) -> Result<i32, crate::IndexError>
+    where
+        S: GetChecked<i32>,
+        core::ops::Range<usize>: GetCheckedSliceIndex<S::Target, Output = [i32]>,
+    {
+        Ok(s.get_checked(range)?.iter().sum())
+    }
+
+    let mut bytes = [0xA0, 0x11, 0xB2, 0xD3];
+
+    assert_eq!(sum(&bytes[..], 1..3), Ok(0x11 + 0xB2));
+    assert_eq!(sum(&mut bytes[..], 1..3), Ok(0x11 + 0xB2));
+    assert_eq!(*sum(&bytes[..], 1..5).unwrap_err().kind(), ErrorKind::EndRange(5, 4));
+}
+
+#[test]
+fn generic_mut_ref_receivers()
+{
+    use crate::{GetCheckedMut, GetCheckedSliceIndex};
+
+    fn zero<S>(mut s: S, index: usize) -> Result<(), crate::IndexError>
+    where
+        S: GetCheckedMut<i32>,
+        usize: GetCheckedSliceIndex<S::Target, Output = i32>,
+    {
+        *s.get_checked_mut(index)? = 0;
+        Ok(())
+    }
+
+    let mut bytes = [0xA0, 0x11, 0xB2, 0xD3];
+
+    zero(&mut bytes[..], 2).unwrap();
+    assert_eq!(bytes, [0xA0, 0x11, 0, 0xD3]);
+    assert_eq!(*zero(&mut bytes[..], 4).unwrap_err().kind(), ErrorKind::Bounds(4, 4));
+}
+
+#[test]
+fn generic_array_receivers()
+{
+    use crate::{GetCheckedMut, GetCheckedSliceIndex};
+
+    fn swap_ends<S>(s: &mut S) -> Result<(), crate::IndexError>
+    where
+        S: GetCheckedMut<i32, Target = [i32]>,
+        usize: GetCheckedSliceIndex<S::Target, Output = i32>,
+    {
+        let last = s.checked_target().len().saturating_sub(1);
+        let first = *s.get_checked(0)?;
+        *s.get_checked_mut(0)? = *s.get_checked(last)?;
+        *s.get_checked_mut(last)? = first;
+        Ok(())
+    }
+
+    let mut bytes = [0xA0, 0x11, 0xB2, 0xD3];
+
+    swap_ends(&mut bytes).unwrap();
+    assert_eq!(bytes, [0xD3, 0x11, 0xB2, 0xA0]);
+    assert_eq!(*swap_ends(&mut []).unwrap_err().kind(), ErrorKind::Bounds(0, 0));
+    assert_eq!(bytes.get_checked(1..3), Ok(&[0x11, 0xB2][..]));
+    assert_eq!(*bytes.get_checked(4).unwrap_err().kind(), ErrorKind::Bounds(4, 4));
+}
+
+#[test]
+#[cfg(any(feature = "alloc", not(feature = "no_std")))]
+fn generic_owned_receivers()
+{
+    use std::{borrow::Cow, rc::Rc, sync::Arc};
+
+    use crate::GetCheckedMut;
+
+    fn sum<S>(s: &S) -> Result<i32, crate::IndexError>
+    where S: GetChecked<i32, Target = [i32]>
+    {
+        Ok(s.get_checked(1..3)?.iter().sum())
+    }
+
+    fn zero<S>(s: &mut S) -> Result<(), crate::IndexError>
+    where S: GetCheckedMut<i32, Target = [i32]>
+    {
+        *s.get_checked_mut(3)? = 0;
+        Ok(())
+    }
+
+    let bytes = [0xA0, 0x11, 0xB2, 0xD3];
+
+    let mut v = bytes.to_vec();
+    assert_eq!(sum(&v), Ok(0x11 + 0xB2));
+    zero(&mut v).unwrap();
+    assert_eq!(v, [0xA0, 0x11, 0xB2, 0]);
+
+    let mut b: Box<[i32]> = bytes.into();
+    assert_eq!(sum(&b), Ok(0x11 + 0xB2));
+    zero(&mut b).unwrap();
+    assert_eq!(*b, [0xA0, 0x11, 0xB2, 0]);
+    assert_eq!(*zero(&mut Box::<[i32]>::from([0; 3])).unwrap_err().kind(), ErrorKind::Bounds(3, 3));
+
+    assert_eq!(sum(&Rc::<[i32]>::from(bytes)), Ok(0x11 + 0xB2));
+    assert_eq!(sum(&Arc::<[i32]>::from(bytes)), Ok(0x11 + 0xB2));
+    assert_eq!(sum(&Cow::Borrowed(&bytes[..])), Ok(0x11 + 0xB2));
+    assert_eq!(sum(&Cow::<[i32]>::Owned(bytes.to_vec())), Ok(0x11 + 0xB2));
+    assert_eq!(*sum(&Arc::<[i32]>::from([0; 2])).unwrap_err().kind(), ErrorKind::EndRange(3, 2));
+}
+
+// Dynamic dispatch tests:
+
+#[test]
+fn dyn_get_checked()
+{
+    use crate::DynGetChecked;
+
+    let bytes = [0xA0, 0x11, 0xB2, 0xD3];
+    let slice = &bytes[..];
+    let buf: &dyn DynGetChecked<i32> = &slice;
+
+    assert_eq!(buf.get_checked_usize(1), Ok(&0x11));
+    assert_eq!(buf.get_checked_range(1..3), Ok(&bytes[1..3]));
+    assert_eq!(*buf.get_checked_usize(4).unwrap_err().kind(), ErrorKind::Bounds(4, 4));
+    assert_eq!(*buf.get_checked_range(2..5).unwrap_err().kind(), ErrorKind::EndRange(5, 4));
+}
+
+// Bounds helper tests:
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn bounds_try_range()
+{
+    use crate::try_range;
+
+    let bytes = [0xA0, 0x11, 0xB2, 0xD3, 0x0F4, 0x35];
+
+    assert_eq!(try_range(1..3, 6), Ok(1..3));
+    assert_eq!(try_range(..=5, 6), Ok(0..6));
+    assert_eq!(try_range(.., 6), Ok(0..6));
+    assert_eq!(try_range(6.., 6), Ok(6..6));
+    assert_eq!(try_range(2..7, 6), bytes.get_checked(2..7).map(|_| 0..0));
+    assert_eq!(try_range(7.., 6), bytes.get_checked(7..).map(|_| 0..0));
+    assert_eq!(try_range(4..2, 6), bytes.get_checked(4..2).map(|_| 0..0));
+    assert_eq!(try_range(4..=2, 6), bytes.get_checked(4..=2).map(|_| 0..0));
+    assert_eq!(try_range(..=usize::MAX, 6), bytes.get_checked(..=usize::MAX).map(|_| 0..0));
+
+    let err = try_range(0..=usize::MAX, usize::MAX).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::EndRange(usize::MAX, usize::MAX));
+}
+
+#[test]
+fn bounds_resolve_bounds()
+{
+    use core::ops::Bound;
+
+    use crate::resolve_bounds;
+
+    assert_eq!(resolve_bounds(Bound::Included(&2), Bound::Excluded(&9), 6), Ok((2, 9)));
+    assert_eq!(resolve_bounds(Bound::Excluded(&2), Bound::Included(&3), 6), Ok((3, 4)));
+    assert_eq!(resolve_bounds(Bound::Unbounded, Bound::Unbounded, 6), Ok((0, 6)));
+
+    let err = resolve_bounds(Bound::Excluded(&usize::MAX), Bound::Unbounded, 6).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::StartOverflow());
+    let err = resolve_bounds(Bound::Unbounded, Bound::Included(&usize::MAX), 6).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::EndOverflow());
+}
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn bounds_overlap_checked()
+{
+    use crate::{overlap_checked, union_checked};
+
+    assert_eq!(overlap_checked(1..4, 3..=5, 6), Ok(Some(3..4)));
+    assert_eq!(overlap_checked(.., 2..3, 6), Ok(Some(2..3)));
+    assert_eq!(overlap_checked(1..3, 3..6, 6), Ok(None));
+    assert_eq!(overlap_checked(2..2, 0..6, 6), Ok(None));
+
+    assert_eq!(union_checked(1..4, 3..=5, 6), Ok(Some(1..6)));
+    assert_eq!(union_checked(4.., ..2, 6), Ok(None));
+    assert_eq!(union_checked(5..5, 1..2, 6), Ok(Some(1..2)));
+    assert_eq!(union_checked(1..2, 6.., 6), Ok(Some(1..2)));
+
+    let err = overlap_checked(7.., 1..2, 6).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::StartRange(7, 6));
+    let err = union_checked(1..2, 4..3, 6).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::Order(4, 3));
+}
+
+// Partially initialized buffer tests:
+
+#[test]
+fn uninit_get_checked()
+{
+    use core::mem::MaybeUninit;
+
+    use crate::PartialInit;
+
+    let mut buf = [MaybeUninit::<i32>::uninit(); 4];
+    let mut init = PartialInit::new(&mut buf);
+    assert_eq!(*init.get_checked(0).unwrap_err().kind(), ErrorKind::Bounds(0, 0));
+
+    *init.push_checked(0xA0).unwrap() += 1;
+    init.push_checked(0x11).unwrap();
+    init.push_checked(0xB2).unwrap();
+
+    assert_eq!(init.len(), 3);
+    assert_eq!(init.capacity(), 4);
+    assert_eq!(init.get_checked(0), Ok(&0xA1));
+    assert_eq!(init.get_checked(1..), Ok(&[0x11, 0xB2][..]));
+    assert_eq!(*init.get_checked(3).unwrap_err().kind(), ErrorKind::Bounds(3, 3));
+    assert_eq!(*init.get_checked(1..4).unwrap_err().kind(), ErrorKind::EndRange(4, 3));
+
+    *init.get_checked_mut(2).unwrap() = 0xD3;
+    assert_eq!(init.as_slice(), &[0xA1, 0x11, 0xD3]);
+
+    init.push_checked(0x0F4).unwrap();
+    assert_eq!(*init.push_checked(0x35).unwrap_err().kind(), ErrorKind::Bounds(4, 4));
+}
+
+#[test]
+fn uninit_with_init()
+{
+    use core::mem::MaybeUninit;
+
+    use crate::PartialInit;
+
+    let mut buf = [MaybeUninit::new(0xA0), MaybeUninit::new(0x11), MaybeUninit::uninit()];
+
+    let err = unsafe { PartialInit::with_init(&mut buf, 4) }.unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::Bounds(4, 3));
+
+    let init = unsafe { PartialInit::with_init(&mut buf, 2) }.unwrap();
+    assert_eq!(init.get_checked(..), Ok(&[0xA0, 0x11][..]));
+}
+
+// Option flattening tests:
+
+#[test]
+fn flat_get_checked()
+{
+    use crate::GetFlatChecked;
+
+    let mut slots = [Some(0xA0), None, Some(0xB2)];
+
+    assert_eq!(slots.get_flat_checked(2), Ok(&0xB2));
+    assert_eq!(*slots.get_flat_checked(1).unwrap_err().kind(), ErrorKind::Vacant(1));
+    assert_eq!(*slots.get_flat_checked(3).unwrap_err().kind(), ErrorKind::Bounds(3, 3));
+    assert_eq!(slots.get_flat_checked(1).unwrap_err().to_string(), "slot at index 1 is vacant");
+
+    *slots.get_flat_checked_mut(0).unwrap() = 0x11;
+    assert_eq!(slots, [Some(0x11), None, Some(0xB2)]);
+    assert_eq!(*slots.get_flat_checked_mut(1).unwrap_err().kind(), ErrorKind::Vacant(1));
+    assert_eq!(*slots.get_flat_checked_mut(3).unwrap_err().kind(), ErrorKind::Bounds(3, 3));
+}
+
+// Segmented view tests:
+
+#[test]
+fn segmented_get_checked()
+{
+    use crate::Segmented;
+
+    let pages: [&[i32]; 4] = [&[0xA0, 0x11], &[], &[0xB2], &[0xD3, 0x0F4, 0x35]];
+    let view = Segmented::new(&pages);
+
+    assert_eq!(view.len(), 6);
+    assert_eq!(view.get_checked(0), Ok(&0xA0));
+    assert_eq!(view.get_checked(2), Ok(&0xB2));
+    assert_eq!(view.get_checked(5), Ok(&0x35));
+    assert_eq!(*view.get_checked(6).unwrap_err().kind(), ErrorKind::Bounds(6, 6));
+
+    let units = [(); usize::MAX];
+    let pages: [&[()]; 2] = [&units, &units];
+    let view = Segmented::new(&pages);
+
+    assert_eq!(view.len(), usize::MAX);
+    assert_eq!(view.get_checked(usize::MAX - 1), Ok(&()));
+    assert_eq!(
+        *view.get_checked(usize::MAX).unwrap_err().kind(),
+        ErrorKind::Bounds(usize::MAX, usize::MAX)
+    );
+}
+
+#[test]
+fn segmented_get_range_checked()
+{
+    use crate::Segmented;
+
+    let pages: [&[i32]; 4] = [&[0xA0, 0x11], &[], &[0xB2], &[0xD3, 0x0F4, 0x35]];
+    let view = Segmented::new(&pages);
+
+    let sub = view.get_range_checked(1..5).unwrap();
+    assert_eq!(sub.len(), 4);
+    assert_eq!(sub.iter().copied().collect::<Vec<_>>(), [0x11, 0xB2, 0xD3, 0x0F4]);
+    assert_eq!(sub.slices().count(), 3);
+    assert_eq!(sub.get_checked(3), Ok(&0x0F4));
+    assert_eq!(*sub.get_checked(4).unwrap_err().kind(), ErrorKind::Bounds(4, 4));
+
+    let sub = sub.get_range_checked(2..).unwrap();
+    assert_eq!(sub.iter().copied().collect::<Vec<_>>(), [0xD3, 0x0F4]);
+    assert!(view.get_range_checked(6..).unwrap().is_empty());
+    assert_eq!(*view.get_range_checked(4..7).unwrap_err().kind(), ErrorKind::EndRange(7, 6));
+}
+
+// Memory map tests:
+
+#[test]
+#[cfg(feature = "mmap")]
+fn mmap_get_checked()
+{
+    use memmap2::{Mmap, MmapMut};
+
+    let path = std::env::temp_dir().join(format!("get_checked_mmap_{}", std::process::id()));
+    std::fs::write(&path, [0xA0, 0x11, 0xB2, 0xD3]).unwrap();
+    let file = std::fs::File::open(&path).unwrap();
+    let map = unsafe { Mmap::map(&file).unwrap() };
+
+    assert_eq!(map.get_checked(1..3), Ok(&[0x11, 0xB2][..]));
+    assert_eq!(*map.get_checked(2..5).unwrap_err().kind(), ErrorKind::EndRange(5, 4));
+
+    let mut map = MmapMut::map_anon(4).unwrap();
+    *map.get_checked_mut(3).unwrap() = 0xD3;
+    assert_eq!(map.get_checked(3), Ok(&0xD3));
+    assert_eq!(*map.get_checked_mut(4).unwrap_err().kind(), ErrorKind::Bounds(4, 4));
+
+    drop(file);
+    std::fs::remove_file(&path).unwrap();
+}
+
+// Record splitting tests:
+
+#[test]
+fn records_record_checked()
+{
+    use crate::GetRecordChecked;
+
+    let bytes = b"ab,,cd,";
+
+    assert_eq!(bytes.record_checked(0, b','), Ok(&b"ab"[..]));
+    assert_eq!(bytes.record_checked(1, b','), Ok(&b""[..]));
+    assert_eq!(bytes.record_checked(3, b','), Ok(&b""[..]));
+    assert_eq!(*bytes.record_checked(4, b',').unwrap_err().kind(), ErrorKind::Bounds(4, 4));
+    assert_eq!(b"".record_checked(0, b','), Ok(&b""[..]));
+}
+
+#[test]
+fn records_line_checked()
+{
+    use crate::GetRecordChecked;
+
+    let bytes = b"ab\r\n\ncd";
+
+    assert_eq!(bytes.line_checked(0), Ok(&b"ab"[..]));
+    assert_eq!(bytes.line_checked(1), Ok(&b""[..]));
+    assert_eq!(bytes.line_checked(2), Ok(&b"cd"[..]));
+    assert_eq!(*bytes.line_checked(3).unwrap_err().kind(), ErrorKind::Bounds(3, 3));
+    assert_eq!(*b"ab\n".line_checked(1).unwrap_err().kind(), ErrorKind::Bounds(1, 1));
+    assert_eq!(*b"".line_checked(0).unwrap_err().kind(), ErrorKind::Bounds(0, 0));
+    assert_eq!(b"\n".line_checked(0), Ok(&b""[..]));
+    assert_eq!(b"a\r\nb\r".line_checked(0), Ok(&b"a"[..]));
+    assert_eq!(b"a\r\nb\r".line_checked(1), Ok(&b"b\r"[..]));
+    assert_eq!(b"a\r\nb\r\n".line_checked(1), Ok(&b"b"[..]));
+
+    for text in ["", "\n", "\r", "\r\n", "a\r", "a\n\r", "a\r\r\n", "a\n\nb\r", "\n\r\n\r"]
+    {
+        let lines: Vec<_> = text.lines().collect();
+        for (n, line) in lines.iter().enumerate()
+        {
+            assert_eq!(text.as_bytes().line_checked(n), Ok(line.as_bytes()), "{:?}", text);
+        }
+        let err = text.as_bytes().line_checked(lines.len()).unwrap_err();
+        assert_eq!(*err.kind(), ErrorKind::Bounds(lines.len(), lines.len()), "{:?}", text);
+    }
+}
+
+#[test]
+fn records_fields_checked()
+{
+    use crate::GetRecordChecked;
+
+    let bytes = [0xA0, 0x11, 0xB2, 0xD3, 0xF4, 0x35];
+
+    let fields: Vec<_> = bytes.fields_checked(&[2, 0, 3]).collect();
+    assert_eq!(fields, [Ok(&[0xA0, 0x11][..]), Ok(&[][..]), Ok(&[0xB2, 0xD3, 0xF4][..])]);
+
+    let mut fields = bytes.fields_checked(&[4, 3, 1, usize::MAX, 1]);
+    assert_eq!(fields.len(), 5);
+    assert_eq!(fields.next(), Some(Ok(&[0xA0, 0x11, 0xB2, 0xD3][..])));
+    assert_eq!(*fields.next().unwrap().unwrap_err().kind(), ErrorKind::EndRange(7, 6));
+    assert_eq!(*fields.next().unwrap().unwrap_err().kind(), ErrorKind::StartRange(7, 6));
+    assert_eq!(*fields.next().unwrap().unwrap_err().kind(), ErrorKind::EndOverflow());
+    assert_eq!(*fields.next().unwrap().unwrap_err().kind(), ErrorKind::EndOverflow());
+    assert_eq!(fields.next(), None);
+}
+
+// UTF-16 tests:
+
+#[test]
+fn utf16_get_checked()
+{
+    use crate::GetUtf16Checked;
+
+    let units = [0x61, 0xD83D, 0xDE00, 0x62, 0xDC00];
+
+    assert_eq!(units.get_utf16_checked(1..3), Ok(&units[1..3]));
+    assert_eq!(units.get_utf16_checked(3..), Ok(&units[3..]));
+    assert_eq!(units.get_utf16_checked(..), Ok(&units[..]));
+    assert_eq!(*units.get_utf16_checked(2..).unwrap_err().kind(), ErrorKind::SurrogateBoundary(2));
+    assert_eq!(*units.get_utf16_checked(..2).unwrap_err().kind(), ErrorKind::SurrogateBoundary(2));
+    assert_eq!(*units.get_utf16_checked(3..6).unwrap_err().kind(), ErrorKind::EndRange(6, 5));
+    assert_eq!(
+        units.get_utf16_checked(2..4).unwrap_err().to_string(),
+        "index 2 splits a UTF-16 surrogate pair"
+    );
+}
+
+// ASCII tests:
+
+#[test]
+fn ascii_get_checked()
+{
+    use crate::GetAsciiChecked;
+
+    let bytes = [0x48, 0x54, 0x54, 0x50, 0xFF, 0x2F];
+
+    assert_eq!(bytes.get_ascii_checked(..4), Ok("HTTP"));
+    assert_eq!(bytes.get_ascii_checked(5..), Ok("/"));
+    assert_eq!(bytes.get_ascii_checked(6..), Ok(""));
+    assert_eq!(*bytes.get_ascii_checked(2..).unwrap_err().kind(), ErrorKind::NotAscii(4));
+    assert_eq!(*bytes.get_ascii_checked(2..7).unwrap_err().kind(), ErrorKind::EndRange(7, 6));
+    assert_eq!(
+        bytes.get_ascii_checked(..).unwrap_err().to_string(),
+        "byte at index 4 is not ASCII"
+    );
+}
+
+// Vec tests:
+
+#[test]
+#[cfg(not(feature = "no_std"))]
+fn vec_get_or_grow_mut()
+{
+    use crate::{GetCheckedVec, Growth};
+
+    let mut bytes = vec![0xA0, 0x11];
+
+    let (elem, growth) = bytes.get_or_grow_mut(1).unwrap();
+    *elem = 0xB2;
+    assert_eq!(growth, Growth::Existing);
+
+    let (elem, growth) = bytes.get_or_grow_mut(3).unwrap();
+    *elem = 0xD3;
+    assert_eq!(growth, Growth::Grown(2));
+    assert_eq!(bytes, [0xA0, 0xB2, 0, 0xD3]);
+
+    let err = bytes.get_or_grow_mut(usize::MAX).unwrap_err();
     assert_eq!(*err.kind(), ErrorKind::EndOverflow());
+    let err = bytes.get_or_grow_mut(isize::MAX as usize).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::CapacityOverflow());
+    assert_eq!(bytes.len(), 4);
+}
+
+#[test]
+#[cfg(not(feature = "no_std"))]
+fn vec_insert_slice_checked()
+{
+    use crate::GetCheckedVec;
+
+    let mut bytes = vec![0xA0, 0x0F4];
+
+    bytes.insert_slice_checked(1, &[0x11, 0xB2]).unwrap();
+    bytes.insert_slice_checked(4, &[0x35]).unwrap();
+    assert_eq!(bytes, [0xA0, 0x11, 0xB2, 0x0F4, 0x35]);
+
+    let err = bytes.insert_slice_checked(6, &[0x66]).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::StartRange(6, 5));
+    assert_eq!(bytes.len(), 5);
+}
+
+#[test]
+#[cfg(not(feature = "no_std"))]
+fn vec_extend_from_within_checked()
+{
+    use crate::GetCheckedVec;
+
+    let mut bytes = vec![0xA0, 0x11, 0xB2];
+
+    bytes.extend_from_within_checked(1..).unwrap();
+    assert_eq!(bytes, [0xA0, 0x11, 0xB2, 0x11, 0xB2]);
+
+    let err = bytes.extend_from_within_checked(4..7).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::EndRange(7, 5));
+    let err = bytes.extend_from_within_checked(6..).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::StartRange(6, 5));
+    assert_eq!(bytes.len(), 5);
+}
+
+#[test]
+#[cfg(not(feature = "no_std"))]
+#[allow(clippy::reversed_empty_ranges)]
+fn vec_splice_checked()
+{
+    use crate::GetCheckedVec;
+
+    let mut bytes = vec![0xA0, 0x11, 0xB2, 0xD3];
+
+    let removed: Vec<_> = bytes.splice_checked(1..=2, vec![0x0F4]).unwrap().collect();
+    assert_eq!(removed, [0x11, 0xB2]);
+    assert_eq!(bytes, [0xA0, 0x0F4, 0xD3]);
+
+    let removed: Vec<_> = bytes.splice_checked(3.., vec![0x35, 0x66]).unwrap().collect();
+    assert!(removed.is_empty());
+    assert_eq!(bytes, [0xA0, 0x0F4, 0xD3, 0x35, 0x66]);
+
+    let err = bytes.splice_checked(3..1, vec![]).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::Order(3, 1));
+    let err = bytes.splice_checked(..9, vec![]).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::EndRange(9, 5));
+    assert_eq!(bytes.len(), 5);
+}
+
+#[test]
+#[cfg(not(feature = "no_std"))]
+fn vec_writer()
+{
+    use crate::{VecWriter, WriteError};
+
+    let mut bytes = vec![0xA0];
+    let mut w = VecWriter::new(&mut bytes);
+    assert_eq!(w.append_default_checked(2), Ok(1..3));
+    assert_eq!(w.append_checked(&[0xD3, 0xF4]), Ok(3..5));
+    assert_eq!(w.write_at_checked(1, &[0x11, 0xB2]), Ok(()));
+    assert_eq!(w.write_at_checked(5, &[]), Ok(()));
+    assert_eq!(w.as_slice(), [0xA0, 0x11, 0xB2, 0xD3, 0xF4]);
+
+    let err = w.write_at_checked(4, &[0x35, 0x66]).unwrap_err();
+    assert!(matches!(err, WriteError::Positional(_)));
+    assert_eq!(*err.index_error().kind(), ErrorKind::EndRange(6, 5));
+    let err = w.write_at_checked(6, &[]).unwrap_err();
+    assert_eq!(*err.index_error().kind(), ErrorKind::StartRange(6, 5));
+    let err = w.write_at_checked(usize::MAX, &[0x35]).unwrap_err();
+    assert_eq!(err, WriteError::Positional(ErrorKind::EndOverflow().into()));
+
+    let err = w.append_default_checked(usize::MAX).unwrap_err();
+    assert_eq!(err, WriteError::Append(ErrorKind::CapacityOverflow().into()));
+    assert_eq!(w.len(), 5);
+}
+
+// Sorted access tests:
+
+#[test]
+fn sorted_rank_checked()
+{
+    use crate::SortedSlice;
+
+    let mut bytes = [0xD3, 0x11, 0xB2, 0xA0];
+    let sorted = SortedSlice::new(&mut bytes);
+
+    assert_eq!(sorted.rank_checked(0), Ok(&0x11));
+    assert_eq!(sorted.rank_checked(3), Ok(&0xD3));
+    assert_eq!(sorted.get_checked(1..3), Ok(&[0xA0, 0xB2][..]));
+    assert_eq!(*sorted.rank_checked(4).unwrap_err().kind(), ErrorKind::Bounds(4, 4));
+}
+
+#[test]
+#[cfg(not(feature = "no_std"))]
+fn sorted_peek_nth_checked()
+{
+    use std::collections::BinaryHeap;
+
+    use crate::GetCheckedHeap;
+
+    let heap: BinaryHeap<_> = vec![0xD3, 0x11, 0xB2, 0xA0].into();
+
+    assert_eq!(heap.peek_nth_checked(0), Ok(&0xD3));
+    assert_eq!(heap.peek_nth_checked(1), Ok(&0xB2));
+    assert_eq!(heap.peek_nth_checked(3), Ok(&0x11));
+    assert_eq!(*heap.peek_nth_checked(4).unwrap_err().kind(), ErrorKind::Bounds(4, 4));
+}
+
+// Linked list tests:
+
+#[test]
+#[cfg(not(feature = "no_std"))]
+fn list_nth_checked()
+{
+    use std::collections::LinkedList;
+
+    use crate::GetCheckedList;
+
+    let mut list: LinkedList<_> = [0xA0, 0x11, 0xB2, 0xD3, 0x0F4].iter().copied().collect();
+
+    for (n, byte) in [0xA0, 0x11, 0xB2, 0xD3, 0x0F4].iter().enumerate()
+    {
+        assert_eq!(list.nth_checked(n), Ok(byte));
+    }
+    assert_eq!(*list.nth_checked(5).unwrap_err().kind(), ErrorKind::Bounds(5, 5));
+
+    *list.nth_checked_mut(1).unwrap() = 0x35;
+    *list.nth_checked_mut(4).unwrap() = 0x66;
+    assert_eq!(list.iter().copied().collect::<Vec<_>>(), [0xA0, 0x35, 0xB2, 0xD3, 0x66]);
+    assert_eq!(*list.nth_checked_mut(5).unwrap_err().kind(), ErrorKind::Bounds(5, 5));
+}
+
+// Deque tests:
+
+#[test]
+#[cfg(not(feature = "no_std"))]
+fn deque_get_checked()
+{
+    use std::collections::VecDeque;
+
+    use crate::GetCheckedDeque;
+
+    let mut v: VecDeque<_> = vec![0xB2, 0xD3, 0x0F4].into();
+    v.push_front(0x11);
+    v.push_front(0xA0);
+
+    assert_eq!(v.get_checked(0), Ok(&0xA0));
+    assert_eq!(v.get_checked(4), Ok(&0x0F4));
+    assert_eq!(*v.get_checked(5).unwrap_err().kind(), ErrorKind::Bounds(5, 5));
+
+    *v.get_checked_mut(1).unwrap() = 0x35;
+    assert_eq!(v[1], 0x35);
+    assert_eq!(*v.get_checked_mut(5).unwrap_err().kind(), ErrorKind::Bounds(5, 5));
+}
+
+#[test]
+#[cfg(not(feature = "no_std"))]
+fn deque_slices_checked()
+{
+    use std::collections::VecDeque;
+
+    use crate::GetCheckedDeque;
+
+    let mut v: VecDeque<_> = VecDeque::with_capacity(5);
+    v.extend([0xB2, 0xD3, 0x0F4].iter().copied());
+    v.push_front(0x11);
+    v.push_front(0xA0);
+    let head = v.as_slices().0.len();
+    assert!(head > 0 && head < v.len());
+
+    let (front, back) = v.slices_checked(..).unwrap();
+    assert_eq!([front, back].concat(), [0xA0, 0x11, 0xB2, 0xD3, 0x0F4]);
+    let (front, back) = v.slices_checked(head..).unwrap();
+    assert_eq!((front, back), (v.as_slices().1, &[][..]));
+    assert_eq!(v.slices_checked(..head).unwrap().1, []);
+    assert_eq!(*v.slices_checked(2..6).unwrap_err().kind(), ErrorKind::EndRange(6, 5));
+
+    assert_eq!(v.slice_checked(..head), Ok(v.as_slices().0));
+    assert_eq!(v.slice_checked(head..head), Ok(&[][..]));
+    assert_eq!(*v.slice_checked(..).unwrap_err().kind(), ErrorKind::Discontiguous(head));
+    assert_eq!(*v.slice_checked(6..).unwrap_err().kind(), ErrorKind::StartRange(6, 5));
+
+    let (front, back) = v.slices_checked_mut(head - 1..=head).unwrap();
+    front[0] = 1;
+    back[0] = 2;
+    assert_eq!((v[head - 1], v[head]), (1, 2));
+    v.slice_checked_mut(head..).unwrap().fill(3);
+    assert_eq!(v[v.len() - 1], 3);
+    assert_eq!(*v.slice_checked_mut(..=head).unwrap_err().kind(), ErrorKind::Discontiguous(head));
+}
+
+// FFI string and path tests:
+
+#[test]
+fn ffi_cstr_get_checked()
+{
+    let s = core::ffi::CStr::from_bytes_with_nul(&[0xA0, 0x11, 0xB2, 0x00]).unwrap();
+    assert_eq!(s.get_checked(1..), Ok(&[0x11, 0xB2][..]));
+    assert_eq!(*s.get_checked(3).unwrap_err().kind(), ErrorKind::Bounds(3, 3));
+    assert_eq!(*s.get_checked(..4).unwrap_err().kind(), ErrorKind::EndRange(4, 3));
+}
+
+#[test]
+#[cfg(all(unix, not(feature = "no_std")))]
+fn ffi_os_str_get_checked()
+{
+    use std::os::unix::ffi::OsStrExt;
+
+    let s = std::ffi::OsStr::from_bytes(&[0xA0, 0x11, 0xB2]);
+    assert_eq!(s.get_checked(..2), Ok(&[0xA0, 0x11][..]));
+    assert_eq!(*s.get_checked(2..4).unwrap_err().kind(), ErrorKind::EndRange(4, 3));
+}
+
+#[test]
+#[cfg(not(feature = "no_std"))]
+fn ffi_path_nth_component_checked()
+{
+    use std::path::{Component, Path};
+
+    use crate::GetCheckedPath;
+
+    let path = Path::new("a/../b/");
+    assert_eq!(path.nth_component_checked(1), Ok(Component::ParentDir));
+    assert_eq!(path.nth_component_checked(2), Ok(Component::Normal("b".as_ref())));
+    assert_eq!(*path.nth_component_checked(3).unwrap_err().kind(), ErrorKind::Bounds(3, 3));
+    assert_eq!(
+        *Path::new("").nth_component_checked(0).unwrap_err().kind(),
+        ErrorKind::Bounds(0, 0)
+    );
+}
+
+// Environment tests:
+
+#[test]
+#[cfg(not(feature = "no_std"))]
+fn env_args_checked()
+{
+    use crate::args_checked;
+
+    let count = std::env::args().count();
+
+    assert_eq!(args_checked(0).ok(), std::env::args().next());
+    assert_eq!(*args_checked(count).unwrap_err().kind(), ErrorKind::Bounds(count, count));
+}
+
+#[test]
+#[cfg(not(feature = "no_std"))]
+fn env_vars_checked()
+{
+    use crate::vars_checked;
+
+    let count = std::env::vars().count();
+
+    assert_eq!(vars_checked(0).ok(), std::env::vars().next());
+    assert_eq!(*vars_checked(usize::MAX).unwrap_err().kind(), ErrorKind::Bounds(usize::MAX, count));
+}
+
+// Range sequence tests:
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn seq_nth_checked()
+{
+    use crate::{GetCheckedSeq, GetCheckedSliceIndex};
+
+    assert_eq!((10..20).nth_checked(0), Ok(10));
+    assert_eq!((10..20).nth_checked(9), Ok(19));
+    assert_eq!(*(10..20).nth_checked(10).unwrap_err().kind(), ErrorKind::Bounds(10, 10));
+    assert_eq!(*(20..10).nth_checked(0).unwrap_err().kind(), ErrorKind::Bounds(0, 0));
+
+    assert_eq!((10..=20).nth_checked(10), Ok(20));
+    assert_eq!(*(10..=20).nth_checked(11).unwrap_err().kind(), ErrorKind::Bounds(11, 11));
+    assert_eq!(*(20..=10).nth_checked(0).unwrap_err().kind(), ErrorKind::Bounds(0, 0));
+    assert_eq!((1..=usize::MAX).nth_checked(usize::MAX - 1), Ok(usize::MAX));
+    assert_eq!(
+        *(1..=usize::MAX).nth_checked(usize::MAX).unwrap_err().kind(),
+        ErrorKind::Bounds(usize::MAX, usize::MAX)
+    );
+
+    let v = [0xA0, 0x11, 0xB2];
+    assert_eq!((1..3).get_checked(&v[..]), Ok(&[0x11, 0xB2][..]));
+}
+
+// Text tests:
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn text_get_checked_str()
+{
+    let mut s = String::from("héllo");
+    let s = s.as_mut_str();
+
+    assert_eq!(s.get_checked(..), Ok("héllo"));
+    assert_eq!(s.get_checked(1..3), Ok("é"));
+    assert_eq!(s.get_checked(..=2), Ok("hé"));
+    assert_eq!(s.get_checked(3..), Ok("llo"));
+    assert_eq!(s.get_checked(6..), Ok(""));
+    assert_eq!(*s.get_checked(2..4).unwrap_err().kind(), ErrorKind::CharBoundary(2));
+    assert_eq!(*s.get_checked(..2).unwrap_err().kind(), ErrorKind::CharBoundary(2));
+    assert_eq!(*s.get_checked(1..=1).unwrap_err().kind(), ErrorKind::CharBoundary(2));
+    assert_eq!(*s.get_checked(7..).unwrap_err().kind(), ErrorKind::StartRange(7, 6));
+    assert_eq!(*s.get_checked(..7).unwrap_err().kind(), ErrorKind::EndRange(7, 6));
+    assert_eq!(*s.get_checked(3..1).unwrap_err().kind(), ErrorKind::Order(3, 1));
+    assert_eq!(
+        *s.get_checked(..=usize::MAX).unwrap_err().kind(),
+        ErrorKind::EndRange(usize::MAX, 6)
+    );
+
+    s.get_checked_mut(3..).unwrap().make_ascii_uppercase();
+    assert_eq!(s, "héLLO");
+    assert_eq!(*s.get_checked_mut(..2).unwrap_err().kind(), ErrorKind::CharBoundary(2));
+}
+
+#[test]
+#[cfg(not(feature = "no_std"))]
+fn text_get_checked_string()
+{
+    use crate::{GetChecked, GetCheckedMut};
+
+    fn first_char<S>(s: &mut S) -> Result<&mut str, crate::IndexError>
+    where S: GetChecked<u8, Target = str> + GetCheckedMut<u8>
+    {
+        let len = s.checked_target().chars().next().map_or(0, char::len_utf8);
+        s.get_checked_mut(..len)
+    }
+
+    let mut s = String::from("éa");
+
+    assert_eq!(s.get_checked(0..2), Ok("é"));
+    assert_eq!(*s.get_checked(1..).unwrap_err().kind(), ErrorKind::CharBoundary(1));
+    assert_eq!(*s.get_checked(..4).unwrap_err().kind(), ErrorKind::EndRange(4, 3));
+    assert_eq!(first_char(&mut s).map(|c| &*c), Ok("é"));
+    first_char(&mut s).unwrap().make_ascii_uppercase();
+    s.get_checked_mut(2..).unwrap().make_ascii_uppercase();
+    assert_eq!(s, "éA");
+    assert_eq!(first_char(&mut String::new()).map(|c| &*c), Ok(""));
+}
+
+#[test]
+fn text_byte_to_char_index_checked()
+{
+    use crate::byte_to_char_index_checked;
+
+    let text = "aé😀b";
+
+    assert_eq!(byte_to_char_index_checked(text, 0), Ok(0));
+    assert_eq!(byte_to_char_index_checked(text, 3), Ok(2));
+    assert_eq!(byte_to_char_index_checked(text, 7), Ok(3));
+    assert_eq!(byte_to_char_index_checked(text, 8), Ok(4));
+    assert_eq!(
+        *byte_to_char_index_checked(text, 4).unwrap_err().kind(),
+        ErrorKind::CharBoundary(4)
+    );
+    assert_eq!(*byte_to_char_index_checked(text, 9).unwrap_err().kind(), ErrorKind::Bounds(9, 8));
+}
+
+#[test]
+fn text_char_to_byte_index_checked()
+{
+    use crate::char_to_byte_index_checked;
+
+    let text = "aé😀b";
+
+    assert_eq!(char_to_byte_index_checked(text, 0), Ok(0));
+    assert_eq!(char_to_byte_index_checked(text, 2), Ok(3));
+    assert_eq!(char_to_byte_index_checked(text, 4), Ok(8));
+    assert_eq!(*char_to_byte_index_checked(text, 5).unwrap_err().kind(), ErrorKind::Bounds(5, 4));
+    assert_eq!(char_to_byte_index_checked("", 0), Ok(0));
+}
+
+#[test]
+fn text_position_checked()
+{
+    use crate::GetCheckedStr;
+
+    let text = "ab\n\né😀x\n";
+
+    assert_eq!(text.position_checked(0), Ok((1, 1)));
+    assert_eq!(text.position_checked(2), Ok((1, 3)));
+    assert_eq!(text.position_checked(3), Ok((2, 1)));
+    assert_eq!(text.position_checked(4), Ok((3, 1)));
+    assert_eq!(text.position_checked(6), Ok((3, 2)));
+    assert_eq!(text.position_checked(10), Ok((3, 3)));
+    assert_eq!(text.position_checked(12), Ok((4, 1)));
+    assert_eq!(*text.position_checked(5).unwrap_err().kind(), ErrorKind::CharBoundary(5));
+    assert_eq!(*text.position_checked(13).unwrap_err().kind(), ErrorKind::Bounds(13, 12));
+}
+
+#[test]
+fn text_field_checked()
+{
+    use crate::GetCheckedStr;
+
+    let row = "a\tbär\t\t";
+
+    assert_eq!(row.field_checked(0, '\t'), Ok("a"));
+    assert_eq!(row.field_checked(1, '\t'), Ok("bär"));
+    assert_eq!(row.field_checked(3, '\t'), Ok(""));
+    assert_eq!("".field_checked(0, ','), Ok(""));
+
+    let err = row.field_checked(4, '\t').unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::Bounds(4, 4));
+    let err = row.field_checked(2, 'ä').unwrap_err();
+    assert_eq!(err.to_string(), "index out of bounds: the len is 2 but the index is 2");
+}
+
+#[test]
+fn text_char_boundary_checked()
+{
+    use crate::GetCheckedStr;
+
+    let s = "a€b😀";
+
+    assert_eq!(s.next_char_boundary_checked(0, 0), Ok(0));
+    assert_eq!(s.next_char_boundary_checked(0, 2), Ok(4));
+    assert_eq!(s.next_char_boundary_checked(4, 2), Ok(9));
+    assert_eq!(s.prev_char_boundary_checked(9, 1), Ok(5));
+    assert_eq!(s.prev_char_boundary_checked(9, 4), Ok(0));
+    assert_eq!(s.prev_char_boundary_checked(9, 0), Ok(9));
+
+    let err = s.next_char_boundary_checked(5, 3).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::Bounds(3, 1));
+    let err = s.prev_char_boundary_checked(1, 2).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::Bounds(2, 1));
+    let err = s.next_char_boundary_checked(2, 1).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::CharBoundary(2));
+    let err = s.prev_char_boundary_checked(10, 1).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::Bounds(10, 9));
+}
+
+// Rope tests:
+
+#[test]
+#[cfg(feature = "ropey")]
+fn rope_get_checked()
+{
+    use ropey::Rope;
+
+    use crate::GetCheckedRope;
+
+    let rope = Rope::from_str("aé\n😀b\n");
+
+    assert_eq!(rope.char_checked(3), Ok('😀'));
+    assert_eq!(*rope.char_checked(6).unwrap_err().kind(), ErrorKind::Bounds(6, 6));
+    assert_eq!(rope.slice_checked(1..4).unwrap(), "é\n😀");
+    assert_eq!(*rope.slice_checked(4..7).unwrap_err().kind(), ErrorKind::EndRange(7, 6));
+    assert_eq!(rope.line_checked(0).unwrap(), "aé\n");
+    assert_eq!(rope.line_checked(2).unwrap(), "");
+    assert_eq!(*rope.line_checked(3).unwrap_err().kind(), ErrorKind::Bounds(3, 3));
+}
+
+// Persistent vector tests:
+
+#[test]
+#[cfg(feature = "im")]
+#[allow(clippy::reversed_empty_ranges)]
+fn im_vector_get_checked()
+{
+    use im::vector;
+
+    use crate::GetCheckedImVector;
+
+    let mut v = vector![1, 2, 3, 4];
+    let snapshot = v.clone();
+
+    assert_eq!(v.get_checked(3), Ok(&4));
+    assert_eq!(*v.get_checked(4).unwrap_err().kind(), ErrorKind::Bounds(4, 4));
+    *v.get_checked_mut(0).unwrap() = 9;
+    assert_eq!(*v.get_checked_mut(4).unwrap_err().kind(), ErrorKind::Bounds(4, 4));
+    assert_eq!(snapshot.get_checked(0), Ok(&1));
+    assert_eq!(v.slice_checked(..2).unwrap(), vector![9, 2]);
+    assert_eq!(v.slice_checked(4..).unwrap(), vector![]);
+    assert_eq!(*v.slice_checked(3..2).unwrap_err().kind(), ErrorKind::Order(3, 2));
+    assert_eq!(*v.slice_checked(5..).unwrap_err().kind(), ErrorKind::StartRange(5, 4));
+}
+
+// Slot tests:
+
+#[test]
+#[cfg(feature = "slab")]
+fn slab_get_checked()
+{
+    use slab::Slab;
+
+    use crate::GetCheckedSlab;
+
+    let mut slab = Slab::new();
+    let a = slab.insert(1);
+    let b = slab.insert(2);
+    slab.remove(a);
+
+    assert_eq!(slab.get_checked(b), Ok(&2));
+    *slab.get_checked_mut(b).unwrap() = 3;
+    assert_eq!(slab.get_checked(b), Ok(&3));
+    assert_eq!(*slab.get_checked(a).unwrap_err().kind(), ErrorKind::Vacant(a));
+    assert_eq!(*slab.get_checked_mut(9).unwrap_err().kind(), ErrorKind::Vacant(9));
+}
+
+#[test]
+#[cfg(feature = "slotmap")]
+fn slotmap_get_checked()
+{
+    use slotmap::{DefaultKey, SlotMap};
+
+    use crate::GetCheckedSlotMap;
+
+    let mut map: SlotMap<DefaultKey, i32> = SlotMap::new();
+    let old = map.insert(1);
+    map.remove(old);
+    let new = map.insert(2);
+
+    assert_eq!(map.get_checked(new), Ok(&2));
+    *map.get_checked_mut(new).unwrap() = 3;
+    assert_eq!(map.get_checked(new), Ok(&3));
+    assert_eq!(*map.get_checked(old).unwrap_err().kind(), ErrorKind::StaleOpaqueKey());
+    assert_eq!(map.get_checked(old).unwrap_err().to_string(), "key is stale");
+    assert_eq!(*map.get_checked_mut(old).unwrap_err().kind(), ErrorKind::StaleOpaqueKey());
+}
+
+// Ordered map tests:
+
+#[test]
+#[cfg(not(feature = "no_std"))]
+fn key_error()
+{
+    use crate::KeyError;
+
+    let err = KeyError::new(&3, 2);
+
+    assert_eq!(err.key(), "3");
+    assert_eq!(err.map_len(), 2);
+    assert_eq!(err.to_string(), "key 3 not found in map of length 2");
+}
+
+#[test]
+#[cfg(not(feature = "no_std"))]
+fn map_get_checked()
+{
+    use std::collections::{BTreeMap, HashMap};
+
+    use crate::{GetCheckedMap, KeyError};
+
+    let mut hash: HashMap<String, i32> = HashMap::new();
+    hash.insert("a".into(), 0xA0);
+    hash.insert("b".into(), 0x11);
+
+    assert_eq!(hash.get_checked("a"), Ok(&0xA0));
+    *hash.get_checked_mut("b").unwrap() = 0xB2;
+    assert_eq!(hash["b"], 0xB2);
+    assert_eq!(hash.get_checked("c"), Err(KeyError::new("c", 2)));
+    assert_eq!(hash.get_checked_mut("c"), Err(KeyError::new("c", 2)));
+
+    let mut tree: BTreeMap<_, _> = (0..3).map(|i| (i, i * 0x10)).collect();
+
+    assert_eq!(tree.get_checked(&2), Ok(&0x20));
+    *tree.get_checked_mut(&0).unwrap() = 0xD3;
+    assert_eq!(tree[&0], 0xD3);
+    let err = tree.get_checked(&3).unwrap_err();
+    assert_eq!((err.key(), err.map_len()), ("3", 3));
+    assert_eq!(tree.get_checked_mut(&-1), Err(KeyError::new(&-1, 3)));
+}
+
+#[test]
+#[cfg(not(feature = "no_std"))]
+#[allow(clippy::reversed_empty_ranges)]
+fn btree_range_checked()
+{
+    use std::{
+        collections::{BTreeMap, BTreeSet},
+        ops::Bound::{Excluded, Included, Unbounded},
+    };
+
+    use crate::{GetCheckedBTreeMap, GetCheckedBTreeSet};
+
+    let mut map: BTreeMap<_, _> = (0..5).map(|i| (i * 10, i)).collect();
+
+    let keys: Vec<_> = map.range_checked(10..=30).unwrap().map(|(k, _)| *k).collect();
+    assert_eq!(keys, [10, 20, 30]);
+    assert_eq!(map.range_checked(15..15).unwrap().count(), 0);
+    assert_eq!(map.range_checked((Excluded(20), Included(20))).unwrap().count(), 0);
+    assert_eq!(map.range_checked((Excluded(20), Unbounded)).unwrap().count(), 2);
+    map.range_checked_mut(30..).unwrap().for_each(|(_, v)| *v = 0);
+    assert_eq!(map[&40], 0);
+
+    let err = map.range_checked(30..10).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::Order(3, 1));
+    let err = map.range_checked_mut((Excluded(20), Excluded(20))).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::Order(3, 2));
+    let err = map.range_checked(100..=-5).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::Order(5, 0));
+
+    let set: BTreeSet<_> = (0..5).collect();
+    assert_eq!(set.range_checked(1..3).unwrap().cloned().collect::<Vec<_>>(), [1, 2]);
+    assert_eq!(*set.range_checked(4..=2).unwrap_err().kind(), ErrorKind::Order(4, 3));
+}
+
+#[test]
+#[cfg(all(feature = "indexmap", not(feature = "no_std")))]
+fn indexmap_get_checked()
+{
+    use indexmap::{IndexMap, IndexSet};
+
+    use crate::{GetCheckedIndexMap, GetCheckedIndexSet, KeyError};
+
+    let mut map: IndexMap<String, i32> = IndexMap::new();
+    map.insert("a".to_string(), 1);
+    map.insert("b".to_string(), 2);
+
+    assert_eq!(map.get_index_checked(0), Ok((&"a".to_string(), &1)));
+    *map.get_index_checked_mut(1).unwrap().1 = 3;
+    *map.get_checked_mut("a").unwrap() = 4;
+    assert_eq!(map.get_checked("b"), Ok(&3));
+    assert_eq!(map.get_checked("a"), Ok(&4));
+    assert_eq!(*map.get_index_checked(2).unwrap_err().kind(), ErrorKind::Bounds(2, 2));
+    assert_eq!(*map.get_index_checked_mut(5).unwrap_err().kind(), ErrorKind::Bounds(5, 2));
+    assert_eq!(map.get_checked("c"), Err(KeyError::new("c", 2)));
+    assert_eq!(map.get_checked_mut("c"), Err(KeyError::new("c", 2)));
+
+    assert_eq!(map.get_range_checked(..).unwrap().len(), 2);
+    map.get_range_checked_mut(1..).unwrap()[0] = 5;
+    assert_eq!(map.get_checked("b"), Ok(&5));
+    assert_eq!(*map.get_range_checked(1..3).unwrap_err().kind(), ErrorKind::EndRange(3, 2));
+    assert_eq!(*map.get_range_checked_mut(3..).unwrap_err().kind(), ErrorKind::StartRange(3, 2));
+
+    let set: IndexSet<u8> = (1..4).collect();
+
+    assert_eq!(set.get_index_checked(2), Ok(&3));
+    assert_eq!(set.get_checked(&1), Ok(&1));
+    assert_eq!(*set.get_index_checked(3).unwrap_err().kind(), ErrorKind::Bounds(3, 3));
+    assert_eq!(set.get_checked(&4).unwrap_err().to_string(), "key 4 not found in map of length 3");
+    assert_eq!(set.get_range_checked(1..=2).unwrap().iter().collect::<Vec<_>>(), [&2, &3]);
+    assert_eq!(*set.get_range_checked(..=3).unwrap_err().kind(), ErrorKind::EndRange(3, 3));
+}
+
+// Lock tests:
+
+#[test]
+#[cfg(not(feature = "no_std"))]
+fn locked_get_checked()
+{
+    use std::sync::{Arc, Mutex, RwLock};
+
+    use crate::{GetCheckedLocked, LockError};
+
+    let mutex = Arc::new(Mutex::new(vec![1, 2, 3]));
+    let rwlock = RwLock::new([4, 5, 6]);
+
+    assert_eq!(mutex.with_checked(2, |x| *x), Ok(3));
+    assert_eq!(mutex.with_checked_mut(..2, |s| s.len()), Ok(2));
+    assert_eq!(rwlock.with_checked(1..3, |s| s.to_vec()), Ok(vec![5, 6]));
+    assert_eq!(rwlock.with_checked_mut(0, |x| *x += 1), Ok(()));
+    assert_eq!(rwlock.with_checked(0, |x| *x), Ok(5));
+
+    let err = mutex.with_checked(3, |x| *x).unwrap_err();
+    assert_eq!(err, LockError::Index(crate::Error::new(ErrorKind::Bounds(3, 3))));
+
+    let poisoner = Arc::clone(&mutex);
+    let _ = std::thread::spawn(move || {
+        let _guard = poisoner.lock().unwrap();
+        panic!("poison");
+    })
+    .join();
+
+    assert_eq!(mutex.with_checked(0, |x| *x), Err(LockError::Poisoned));
+    assert_eq!(mutex.with_checked_mut(0, |x| *x), Err(LockError::Poisoned));
+}
+
+// Atomic tests:
+
+#[test]
+fn atomic_get_checked()
+{
+    use core::sync::atomic::{AtomicI8, AtomicU64, Ordering::SeqCst};
+
+    use crate::GetCheckedAtomic;
+
+    let wide = [AtomicU64::new(1), AtomicU64::new(2)];
+    let narrow = [AtomicI8::new(i8::MAX)];
+
+    assert_eq!(wide.store_checked(0, 5, SeqCst), Ok(()));
+    assert_eq!(wide.fetch_add_checked(0, 3, SeqCst), Ok(5));
+    assert_eq!(wide.load_checked(0, SeqCst), Ok(8));
+    assert_eq!(narrow.fetch_add_checked(0, 1, SeqCst), Ok(i8::MAX));
+    assert_eq!(narrow.load_checked(0, SeqCst), Ok(i8::MIN));
+    assert_eq!(*wide.load_checked(2, SeqCst).unwrap_err().kind(), ErrorKind::Bounds(2, 2));
+    assert_eq!(*wide.store_checked(2, 0, SeqCst).unwrap_err().kind(), ErrorKind::Bounds(2, 2));
+    assert_eq!(
+        *narrow.fetch_add_checked(1, 0, SeqCst).unwrap_err().kind(),
+        ErrorKind::Bounds(1, 1)
+    );
+}
+
+// Cell tests:
+
+#[test]
+fn cell_get_checked()
+{
+    use core::cell::Cell;
+
+    use crate::GetCheckedCell;
+
+    let mut values = [0xA0, 0x11, 0xB2];
+    let cells = Cell::from_mut(&mut values[..]);
+    let slice = cells.as_slice_of_cells();
+
+    assert_eq!(slice.set_checked(0, 0xD3), Ok(()));
+    assert_eq!(slice.replace_checked(1, 0xF4), Ok(0x11));
+    assert_eq!(slice.get_value_checked(1), Ok(0xF4));
+    assert_eq!(cells.get_checked(2).map(Cell::get), Ok(0xB2));
+    assert_eq!(cells.get_checked(1..).map(<[_]>::len), Ok(2));
+    assert_eq!(*slice.set_checked(3, 0).unwrap_err().kind(), ErrorKind::Bounds(3, 3));
+    assert_eq!(*slice.replace_checked(3, 0).unwrap_err().kind(), ErrorKind::Bounds(3, 3));
+    assert_eq!(*cells.get_checked(..4).unwrap_err().kind(), ErrorKind::EndRange(4, 3));
+    assert_eq!(values, [0xD3, 0xF4, 0xB2]);
+}
+
+// Debug checked tests:
+
+#[test]
+fn debug_get_checked()
+{
+    use crate::GetCheckedDebug;
+
+    let mut v = [1, 2, 3];
+
+    unsafe {
+        assert_eq!(*v.get_unchecked_checked_in_debug(2), 3);
+        assert_eq!(*v.get_unchecked_checked_in_debug(..=1), [1, 2]);
+        *v.get_unchecked_checked_in_debug_mut(0) = 4;
+        v.get_unchecked_checked_in_debug_mut(1..).copy_from_slice(&[5, 6]);
+    }
+
+    assert_eq!(v, [4, 5, 6]);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "range end index 4 out of range for slice of length 3")]
+fn debug_get_checked_panics()
+{
+    use crate::GetCheckedDebug;
+
+    let v = [1, 2, 3];
+    unsafe {
+        v.get_unchecked_checked_in_debug(1..4);
+    }
+}
+
+// Prelude tests:
+
+#[test]
+fn prelude_imports()
+{
+    use crate::prelude::*;
+
+    let mut v = [1, 2, 3];
+
+    assert_eq!(v.get_checked(0), Ok(&1));
+    assert_eq!((1..).get_checked(&v[..]), Ok(&[2, 3][..]));
+    *v.get_checked_mut(2).unwrap() = 4;
+    assert_eq!(v.get_checked_usize(2), Ok(&4));
+    assert!(v.get_checked(5..).or_empty().is_empty());
+    assert_eq!(b"ab".get_ascii_checked(1..), Ok("b"));
+    assert_eq!("a\nb".position_checked(2), Ok((2, 1)));
+}
+
+// Message style tests:
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn message_style()
+{
+    use crate::{MessageStyle, try_range};
+
+    let v = [1, 2, 3];
+    let descriptive = |e: crate::Error| e.with_style(MessageStyle::Descriptive).to_string();
+
+    assert_eq!(v.get_checked(1..4).unwrap_err().style(), MessageStyle::Std);
+    assert_eq!(
+        descriptive(v.get_checked(3).unwrap_err()),
+        "index out of bounds: the len is 3 but the index is 3 (index: 3, len: 3)"
+    );
+    assert_eq!(
+        descriptive(v.get_checked(2..1).unwrap_err()),
+        "slice index starts at 2 but ends at 1 (range: 2..1, len: 3)"
+    );
+    assert_eq!(
+        descriptive(try_range(5.., 3).unwrap_err()),
+        "range start index 5 out of range for slice of length 3 (range: 5..3, len: 3)"
+    );
+    assert_eq!(descriptive(crate::Error::new(ErrorKind::Vacant(1))), "slot at index 1 is vacant");
+    assert_eq!(
+        v.get_checked(1..4).unwrap_err().with_style(MessageStyle::Std).to_string(),
+        "range end index 4 out of range for slice of length 3"
+    );
+}
+
+// HTTP status tests:
+
+#[test]
+#[cfg(all(feature = "http", not(feature = "no_std")))]
+fn http_status()
+{
+    use http::StatusCode;
+
+    let v = [1, 2, 3];
+
+    assert_eq!(v.get_checked(3).unwrap_err().http_status(), StatusCode::NOT_FOUND);
+    assert_eq!(v.get_checked(4..).unwrap_err().http_status(), StatusCode::RANGE_NOT_SATISFIABLE);
+    assert_eq!(crate::Error::new(ErrorKind::EndOverflow()).http_status(), StatusCode::BAD_REQUEST);
+    assert_eq!(crate::Error::new(ErrorKind::NotAscii(0)).http_status(), StatusCode::BAD_REQUEST);
+
+    let problem = v.get_checked(1..4).unwrap_err().problem_details();
+    assert_eq!(problem.status, StatusCode::RANGE_NOT_SATISFIABLE);
+    assert_eq!(problem.problem_type, "range-not-satisfiable");
+    assert_eq!(problem.title, "Range not satisfiable");
+    assert_eq!(problem.detail, "range end index 4 out of range for slice of length 3");
+}
+
+#[test]
+#[cfg(all(feature = "http", not(feature = "no_std")))]
+fn http_validate_range()
+{
+    use http::StatusCode;
+
+    use crate::{HttpRange::*, validate_http_range};
+
+    assert_eq!(validate_http_range(FromTo(0, 0), 10), Ok(0..1));
+    assert_eq!(validate_http_range(FromTo(9, 9), 10), Ok(9..10));
+    assert_eq!(validate_http_range(FromTo(5, usize::MAX), 10), Ok(5..10));
+    assert_eq!(validate_http_range(From(0), 10), Ok(0..10));
+    assert_eq!(validate_http_range(Suffix(10), 10), Ok(0..10));
+    assert_eq!(validate_http_range(Suffix(20), 10), Ok(0..10));
+
+    let err = validate_http_range(FromTo(5, 4), 10).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::Order(5, 4));
+    assert_eq!(err.http_status(), StatusCode::BAD_REQUEST);
+
+    let err = validate_http_range(FromTo(10, 12), 10).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::StartRange(10, 10));
+    assert_eq!(err.http_status(), StatusCode::RANGE_NOT_SATISFIABLE);
+
+    assert_eq!(
+        *validate_http_range(Suffix(0), 10).unwrap_err().kind(),
+        ErrorKind::StartRange(10, 10)
+    );
+    assert_eq!(*validate_http_range(Suffix(5), 0).unwrap_err().kind(), ErrorKind::StartRange(0, 0));
+    assert_eq!(*validate_http_range(From(0), 0).unwrap_err().kind(), ErrorKind::StartRange(0, 0));
+}
+
+// Pagination tests:
+
+#[test]
+fn page_get_checked()
+{
+    use crate::GetPageChecked;
+
+    let v = [1, 2, 3, 4, 5, 6, 7];
+    let empty: [u8; 0] = [];
+
+    assert_eq!(v.page_count_checked(3), Ok(3));
+    assert_eq!(v.page_count_checked(7), Ok(1));
+    assert_eq!(empty.page_count_checked(3), Ok(0));
+    assert_eq!(*v.page_count_checked(0).unwrap_err().kind(), ErrorKind::ZeroChunk());
+    assert_eq!(v.page_checked(3, 1), Ok(&[4, 5, 6][..]));
+    assert_eq!(*v.page_checked(3, 2).unwrap_err().kind(), ErrorKind::Bounds(2, 2));
+    assert_eq!(*v.page_checked(0, 0).unwrap_err().kind(), ErrorKind::ZeroChunk());
+    assert_eq!(v.page_checked_clamped(3, 2), Ok(&[7][..]));
+    assert_eq!(*v.page_checked_clamped(3, 3).unwrap_err().kind(), ErrorKind::Bounds(3, 3));
+    assert_eq!(
+        *v.page_checked_clamped(2, usize::MAX).unwrap_err().kind(),
+        ErrorKind::Bounds(usize::MAX, 4)
+    );
+    assert_eq!(*v.page_checked(usize::MAX, 2).unwrap_err().kind(), ErrorKind::Bounds(2, 0));
+    assert_eq!(*empty.page_checked_clamped(1, 0).unwrap_err().kind(), ErrorKind::Bounds(0, 0));
+}
+
+// Strided window tests:
+
+#[test]
+fn window_get_checked()
+{
+    use crate::GetWindowChecked;
+
+    let v = [0, 1, 2, 3, 4, 5, 6];
+
+    let windows = v.window_by_checked(0, 3, 2).unwrap();
+    assert_eq!(windows.len(), 3);
+    assert_eq!(windows.collect::<Vec<_>>(), [&[0, 1, 2][..], &[2, 3, 4], &[4, 5, 6]]);
+
+    let mut windows = v.window_by_checked(4, 3, 10).unwrap();
+    assert_eq!(windows.next(), Some(&[4, 5, 6][..]));
+    assert_eq!(windows.len(), 0);
+    assert_eq!(windows.next(), None);
+
+    assert_eq!(*v.window_by_checked(5, 3, 1).unwrap_err().kind(), ErrorKind::EndRange(8, 7));
+    assert_eq!(*v.window_by_checked(0, 0, 1).unwrap_err().kind(), ErrorKind::ZeroChunk());
+    assert_eq!(*v.window_by_checked(0, 1, 0).unwrap_err().kind(), ErrorKind::ZeroChunk());
+    assert_eq!(
+        *v.window_by_checked(1, usize::MAX, 1).unwrap_err().kind(),
+        ErrorKind::EndOverflow()
+    );
+}
+
+#[test]
+fn window_get_around_checked()
+{
+    use crate::{EdgePolicy, GetWindowChecked};
+
+    let bytes = [0xA0, 0x11, 0xB2, 0xD3, 0xF4];
+
+    assert_eq!(bytes.get_around_checked(2, 0, EdgePolicy::Strict), Ok(&[0xB2][..]));
+    assert_eq!(bytes.get_around_checked(2, 2, EdgePolicy::Strict), Ok(&bytes[..]));
+    assert_eq!(bytes.get_around_checked(1, 3, EdgePolicy::Clamp), Ok(&bytes[..]));
+    assert_eq!(bytes.get_around_checked(4, 1, EdgePolicy::Clamp), Ok(&[0xD3, 0xF4][..]));
+    assert_eq!(bytes.get_around_checked(3, usize::MAX, EdgePolicy::Clamp), Ok(&bytes[..]));
+
+    let err = bytes.get_around_checked(1, 2, EdgePolicy::Strict).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::StartUnderflow(1, 2));
+    let err = bytes.get_around_checked(3, 2, EdgePolicy::Strict).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::EndRange(6, 5));
+    let err = bytes.get_around_checked(5, 0, EdgePolicy::Clamp).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::Bounds(5, 5));
+
+    let v = zst_slice(usize::MAX);
+    let err = v.get_around_checked(usize::MAX - 1, 1, EdgePolicy::Strict).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::EndOverflow());
+    let around = v.get_around_checked(usize::MAX - 1, 1, EdgePolicy::Clamp).unwrap();
+    assert_eq!(around.len(), 2);
+}
+
+#[test]
+fn window_column_iter_checked()
+{
+    use crate::{Axis, GetWindowChecked};
+
+    // 3x3 grid with a trailing partial row.
+    let mut grid = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+
+    assert!(grid.column_iter_checked(0, 3).unwrap().eq(&[1, 4, 7]));
+    assert!(grid.column_iter_checked(2, 3).unwrap().eq(&[3, 6, 9]));
+    assert!(grid.column_iter_checked(0, 10).unwrap().eq(&[1]));
+    assert_eq!(grid.column_iter_checked(1, 11).unwrap().count(), 0);
+    assert_eq!(grid[..0].column_iter_checked(0, 1).unwrap().count(), 0);
+    assert_eq!(
+        *grid.column_iter_checked(3, 3).unwrap_err().kind(),
+        ErrorKind::AxisBounds(Axis::X, 3, 3)
+    );
+    assert_eq!(*grid.column_iter_checked(0, 0).unwrap_err().kind(), ErrorKind::ZeroChunk());
+
+    grid.column_iter_checked_mut(1, 3).unwrap().for_each(|x| *x = 0);
+    assert_eq!(grid, [1, 0, 3, 4, 0, 6, 7, 0, 9, 10]);
+    assert_eq!(
+        *grid.column_iter_checked_mut(usize::MAX, 2).unwrap_err().kind(),
+        ErrorKind::AxisBounds(Axis::X, usize::MAX, 2)
+    );
+}
+
+#[test]
+fn window_transpose_into_checked()
+{
+    use crate::GetWindowChecked;
+
+    // 4x2 matrix.
+    let m = [1, 2, 3, 4, 5, 6, 7, 8];
+    let mut t = [0; 8];
+
+    m.transpose_into_checked(&mut t, 4, 2).unwrap();
+    assert_eq!(t, [1, 5, 2, 6, 3, 7, 4, 8]);
+    let mut back = [0; 8];
+    t.transpose_into_checked(&mut back, 2, 4).unwrap();
+    assert_eq!(back, m);
+    m.transpose_into_checked(&mut t, 8, 1).unwrap();
+    assert_eq!(t, m);
+    assert_eq!(m[..0].transpose_into_checked(&mut [], 0, 5), Ok(()));
+
+    t = [0; 8];
+    assert_eq!(
+        *m.transpose_into_checked(&mut t, 3, 2).unwrap_err().kind(),
+        ErrorKind::LengthMismatch(6, 8)
+    );
+    assert_eq!(
+        *m.transpose_into_checked(&mut t[..7], 4, 2).unwrap_err().kind(),
+        ErrorKind::LengthMismatch(8, 7)
+    );
+    assert_eq!(
+        *m.transpose_into_checked(&mut t, usize::MAX, 2).unwrap_err().kind(),
+        ErrorKind::CapacityOverflow()
+    );
+    assert_eq!(t, [0; 8]);
+}
+
+// Audio frame tests:
+
+#[test]
+fn frame_get_checked()
+{
+    use crate::GetFrameChecked;
+
+    let mut samples = [1i16, 2, 3, 4, 5, 6, 7];
+
+    assert_eq!(samples.frame_checked(1, 3), Ok(&[4, 5, 6][..]));
+    assert_eq!(*samples.frame_checked(2, 3).unwrap_err().kind(), ErrorKind::Bounds(2, 2));
+    assert_eq!(samples.frame_checked(6, 1), Ok(&[7][..]));
+    assert_eq!(
+        *samples.frame_checked(usize::MAX, 2).unwrap_err().kind(),
+        ErrorKind::Bounds(usize::MAX, 3)
+    );
+    assert_eq!(*samples.frame_checked(0, 0).unwrap_err().kind(), ErrorKind::ZeroChunk());
+    samples.frame_checked_mut(0, 3).unwrap().reverse();
+    assert_eq!(samples[..3], [3, 2, 1]);
+    assert_eq!(*samples.frame_checked_mut(0, 8).unwrap_err().kind(), ErrorKind::Bounds(0, 0));
+}
+
+#[test]
+fn frame_interleave_checked()
+{
+    use crate::GetFrameChecked;
+
+    let samples = [1i16, -1, 2, -2, 3, -3];
+
+    let channels: Vec<Vec<i16>> =
+        samples.deinterleave_checked(3).unwrap().map(|c| c.copied().collect()).collect();
+    assert_eq!(channels, [[1, -2], [-1, 3], [2, -3]]);
+    assert_eq!(samples.deinterleave_checked(6).unwrap().len(), 6);
+    assert_eq!(samples[..0].deinterleave_checked(2).unwrap().map(|c| c.count()).sum::<usize>(), 0);
+    assert_eq!(
+        *samples.deinterleave_checked(4).unwrap_err().kind(),
+        ErrorKind::LengthMismatch(4, 6)
+    );
+    assert_eq!(*samples.deinterleave_checked(0).unwrap_err().kind(), ErrorKind::ZeroChunk());
+
+    let mut out = [0i16; 6];
+    out.interleave_checked(&[&[1, 2], &[-1, -2], &[5, 6]]).unwrap();
+    assert_eq!(out, [1, -1, 5, 2, -2, 6]);
+    assert_eq!(
+        *out.interleave_checked(&[&[1, 2, 3], &[-1, -2]]).unwrap_err().kind(),
+        ErrorKind::LengthMismatch(3, 2)
+    );
+    assert_eq!(
+        *out.interleave_checked(&[&[1, 2], &[-1, -2]]).unwrap_err().kind(),
+        ErrorKind::LengthMismatch(4, 6)
+    );
+    assert_eq!(*out.interleave_checked(&[]).unwrap_err().kind(), ErrorKind::ZeroChunk());
+    assert_eq!(out, [1, -1, 5, 2, -2, 6]);
+
+    let source: &[()] = zst_slice(usize::MAX);
+    let err = zst_slice(0).interleave_checked(&[source, source]).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::CapacityOverflow());
+}
+
+// Hex tests:
+
+#[test]
+#[cfg(not(feature = "no_std"))]
+fn hex_to_hex_checked()
+{
+    use crate::HexChecked;
+
+    let bytes = [0x00, 0x0F, 0xA5, 0xFF];
+
+    assert_eq!(bytes.to_hex_checked(..).unwrap().to_string(), "000fa5ff");
+    assert_eq!(format!("{:X}", bytes.to_hex_checked(1..=2).unwrap()), "0FA5");
+    assert_eq!(format!("{:x}", bytes.to_hex_checked(4..).unwrap()), "");
+    assert_eq!(*bytes.to_hex_checked(3..5).unwrap_err().kind(), ErrorKind::EndRange(5, 4));
+}
+
+#[test]
+fn hex_decode_hex_into_checked()
+{
+    use crate::HexChecked;
+
+    let mut buf = [0u8; 4];
+
+    buf.decode_hex_into_checked(.., b"09afAF10").unwrap();
+    assert_eq!(buf, [0x09, 0xAF, 0xAF, 0x10]);
+    buf.decode_hex_into_checked(3.., b"7e").unwrap();
+    buf.decode_hex_into_checked(4.., b"").unwrap();
+    assert_eq!(buf, [0x09, 0xAF, 0xAF, 0x7E]);
+
+    assert_eq!(
+        *buf.decode_hex_into_checked(..1, b"123").unwrap_err().kind(),
+        ErrorKind::LengthMismatch(2, 3)
+    );
+    assert_eq!(
+        *buf.decode_hex_into_checked(2..5, b"").unwrap_err().kind(),
+        ErrorKind::EndRange(5, 4)
+    );
+    assert_eq!(
+        *buf.decode_hex_into_checked(..2, b"00fg").unwrap_err().kind(),
+        ErrorKind::InvalidDigit(3)
+    );
+    assert_eq!(
+        *buf.decode_hex_into_checked(..1, b" 0").unwrap_err().kind(),
+        ErrorKind::InvalidDigit(0)
+    );
+    assert_eq!(buf, [0x09, 0xAF, 0xAF, 0x7E]);
+}
+
+// Image tests:
+
+#[test]
+fn pixel_get_checked()
+{
+    use crate::{Axis, GetPixelChecked};
+
+    // 3x2 image with 2 bytes per pixel and a trailing partial row.
+    let mut image: Vec<u8> = (0..13).collect();
+
+    assert_eq!(image.pixel_checked(0, 0, 3, 2), Ok(&[0, 1][..]));
+    assert_eq!(image.pixel_checked(2, 1, 3, 2), Ok(&[10, 11][..]));
+    image.pixel_checked_mut(1, 0, 3, 2).unwrap().copy_from_slice(&[9, 9]);
+    assert_eq!(image[2..4], [9, 9]);
+    assert_eq!(
+        *image.pixel_checked(3, 0, 3, 2).unwrap_err().kind(),
+        ErrorKind::AxisBounds(Axis::X, 3, 3)
+    );
+    assert_eq!(
+        *image.pixel_checked(0, 2, 3, 2).unwrap_err().kind(),
+        ErrorKind::AxisBounds(Axis::Y, 2, 2)
+    );
+    assert_eq!(*image.pixel_checked(0, 0, 3, 0).unwrap_err().kind(), ErrorKind::ZeroChunk());
+    assert_eq!(
+        *image.pixel_checked_mut(0, 0, usize::MAX, 2).unwrap_err().kind(),
+        ErrorKind::EndOverflow()
+    );
+    assert_eq!(
+        image.pixel_checked(0, 5, 3, 2).unwrap_err().to_string(),
+        "y coordinate 5 out of range for height 2"
+    );
+}
+
+#[test]
+fn image_view()
+{
+    use crate::{Axis, ImageView};
+
+    // 2x3 image with 2 bytes per pixel and rows padded to 5 bytes.
+    let bytes: Vec<u8> = (0..14).collect();
+    let image = ImageView::new(&bytes, 2, 3, 5, 2).unwrap();
+
+    assert_eq!((image.width(), image.height(), image.pitch()), (2, 3, 5));
+    assert_eq!(image.row_checked(0), Ok(&[0, 1, 2, 3][..]));
+    assert_eq!(image.row_checked(2), Ok(&[10, 11, 12, 13][..]));
+    assert_eq!(image.pixel_checked(1, 1), Ok(&[7, 8][..]));
+    assert_eq!(*image.row_checked(3).unwrap_err().kind(), ErrorKind::AxisBounds(Axis::Y, 3, 3));
+    assert_eq!(
+        *image.pixel_checked(2, 0).unwrap_err().kind(),
+        ErrorKind::AxisBounds(Axis::X, 2, 2)
+    );
+
+    assert_eq!(*ImageView::new(&bytes, 2, 3, 5, 0).unwrap_err().kind(), ErrorKind::ZeroChunk());
+    assert_eq!(
+        *ImageView::new(&bytes, 3, 3, 5, 2).unwrap_err().kind(),
+        ErrorKind::LengthMismatch(6, 5)
+    );
+    assert_eq!(
+        *ImageView::new(&bytes, 2, 4, 5, 2).unwrap_err().kind(),
+        ErrorKind::EndRange(19, 14)
+    );
+    assert_eq!(
+        *ImageView::new(&bytes, 1, usize::MAX, usize::MAX, 1).unwrap_err().kind(),
+        ErrorKind::EndOverflow()
+    );
+    assert_eq!(ImageView::new(&[], 4, 0, 8, 1).unwrap().height(), 0);
+}
+
+// Checksum tests:
+
+#[test]
+#[cfg(feature = "crc")]
+fn checksum_crc_range_checked()
+{
+    use crc::{CRC_8_SMBUS, CRC_16_IBM_3740, CRC_32_ISO_HDLC, Crc};
+
+    use crate::GetCrcChecked;
+
+    let crc32 = Crc::<u32>::new(&CRC_32_ISO_HDLC);
+    let crc16 = Crc::<u16>::new(&CRC_16_IBM_3740);
+    let crc8 = Crc::<u8>::new(&CRC_8_SMBUS);
+    let bytes = b"123456789\xFF";
+
+    assert_eq!(bytes.crc_range_checked(..9, &crc32), Ok(0xCBF43926));
+    assert_eq!(bytes.crc_range_checked(..=8, &crc16), Ok(0x29B1));
+    assert_eq!(bytes.crc_range_checked(0..9, &crc8), Ok(0xF4));
+    assert_eq!(bytes.crc_range_checked(.., &crc32), Ok(crc32.checksum(bytes)));
+    assert_eq!(bytes.crc_range_checked(10.., &crc32), Ok(crc32.checksum(&[])));
+
+    assert_eq!(
+        *bytes.crc_range_checked(..11, &crc32).unwrap_err().kind(),
+        ErrorKind::EndRange(11, 10)
+    );
+    assert_eq!(
+        *bytes.crc_range_checked(11.., &crc16).unwrap_err().kind(),
+        ErrorKind::StartRange(11, 10)
+    );
+    assert_eq!(
+        *bytes.crc_range_checked(5..=usize::MAX, &crc8).unwrap_err().kind(),
+        ErrorKind::EndRange(usize::MAX, 10)
+    );
+}
+
+// Plain-old-data tests:
+
+#[test]
+#[cfg(feature = "bytemuck")]
+fn pod_get_checked()
+{
+    use crate::GetPodChecked;
+
+    let mut words = [0x0102_0304u32, 5, 6];
+    let bytes: &mut [u8] = bytemuck::cast_slice_mut(&mut words);
+
+    assert_eq!(bytes.get_pod_checked::<u32>(0), Ok(&0x0102_0304));
+    assert_eq!(bytes.get_pod_checked::<[u16; 2]>(1), Ok(&[5, 0]));
+    *bytes.get_pod_checked_mut::<u32>(2).unwrap() = 7;
+    assert_eq!(bytes.get_pod_checked::<u32>(2), Ok(&7));
+    assert_eq!(*bytes.get_pod_checked::<u64>(1).unwrap_err().kind(), ErrorKind::Bounds(1, 1));
+    assert_eq!(*bytes.get_pod_checked::<()>(0).unwrap_err().kind(), ErrorKind::ZeroChunk());
+    assert_eq!(
+        *bytes.get_pod_checked::<u32>(usize::MAX).unwrap_err().kind(),
+        ErrorKind::EndOverflow()
+    );
+    assert_eq!(
+        *bytes[1..].get_pod_checked::<u32>(0).unwrap_err().kind(),
+        ErrorKind::Misaligned(0, 4)
+    );
+    assert_eq!(
+        *bytes[1..].get_pod_checked_mut::<u16>(1).unwrap_err().kind(),
+        ErrorKind::Misaligned(2, 2)
+    );
+}
+
+// Offset table tests:
+
+#[test]
+#[cfg(not(feature = "no_std"))]
+fn offset_table_resolve()
+{
+    use crate::resolve_offset_table_checked;
+
+    let data = [0, 1, 2, 3, 4, 5];
+
+    assert_eq!(resolve_offset_table_checked(&data, &[]), Ok(vec![]));
+    assert_eq!(
+        resolve_offset_table_checked(&data, &[(4, 2), (0, 0), (6, 0)]),
+        Ok(vec![&[4, 5][..], &[], &[]])
+    );
+
+    let err = resolve_offset_table_checked(&data, &[(7, 0), (1, 1), (usize::MAX, 1), (2, 5)])
+        .unwrap_err();
+    let kinds: Vec<_> = err.errors().iter().map(|(i, e)| (*i, e.kind().clone())).collect();
+    assert_eq!(
+        kinds,
+        [
+            (0, ErrorKind::StartRange(7, 6)),
+            (2, ErrorKind::EndOverflow()),
+            (3, ErrorKind::EndRange(7, 6))
+        ]
+    );
+    assert_eq!(
+        err.to_string(),
+        "3 invalid entries, first at entry 0: range start index 7 out of range for slice of length 6"
+    );
+    assert!(std::error::Error::source(&err).is_some());
+}
+
+#[test]
+#[cfg(not(feature = "no_std"))]
+#[allow(clippy::reversed_empty_ranges)]
+fn offset_table_validate_ranges()
+{
+    use crate::validate_ranges_checked;
+
+    assert_eq!(validate_ranges_checked(&[], 6, true), Ok(()));
+    assert_eq!(validate_ranges_checked(&[0..2, 2..2, 2..6], 6, true), Ok(()));
+    assert_eq!(validate_ranges_checked(&[4..6, 0..3, 1..5], 6, false), Ok(()));
+    assert_eq!(validate_ranges_checked(&[4..6, 5..5, 6..6], 6, true), Ok(()));
+
+    let ranges = [1..4, 3..5, 5..2, 2..3, 4..9, 4..6];
+    let err = validate_ranges_checked(&ranges, 6, true).unwrap_err();
+    let kinds: Vec<_> = err.errors().iter().map(|(i, e)| (*i, e.kind().clone())).collect();
+    assert_eq!(
+        kinds,
+        [
+            (1, ErrorKind::Overlap(3, 4)),
+            (2, ErrorKind::Order(5, 2)),
+            (3, ErrorKind::Overlap(2, 4)),
+            (4, ErrorKind::EndRange(9, 6)),
+        ]
+    );
+    assert_eq!(
+        err.errors()[0].1.to_string(),
+        "range starting at 3 overlaps preceding range ending at 4"
+    );
+
+    let err = validate_ranges_checked(&ranges, 6, false).unwrap_err();
+    assert_eq!(err.errors().len(), 2);
+}
+
+// Scatter write tests:
+
+#[test]
+#[cfg(not(feature = "no_std"))]
+fn scatter_set_all_checked()
+{
+    use crate::SetAllChecked;
+
+    let mut bytes = [0xA0, 0x11, 0xB2, 0xD3];
+
+    bytes.set_all_checked(&[]).unwrap();
+    bytes.set_all_checked(&[(2, 0x35), (0, 0x0F4), (2, 0x66)]).unwrap();
+    assert_eq!(bytes, [0x0F4, 0x11, 0x66, 0xD3]);
+
+    let err = bytes.set_all_checked(&[(4, 0), (1, 0), (usize::MAX, 0)]).unwrap_err();
+    let kinds: Vec<_> = err.errors().iter().map(|(i, e)| (*i, e.kind().clone())).collect();
+    assert_eq!(kinds, [(0, ErrorKind::Bounds(4, 4)), (2, ErrorKind::Bounds(usize::MAX, 4))]);
+    assert_eq!(bytes, [0x0F4, 0x11, 0x66, 0xD3]);
+
+    let mut names = vec![String::new(); 2];
+    names.set_all_checked(&[(1, "b".to_string())]).unwrap();
+    assert_eq!(names, ["", "b"]);
+}
+
+#[test]
+#[cfg(not(feature = "no_std"))]
+fn scatter_get_unique_checked()
+{
+    use crate::GetUniqueChecked;
+
+    let mut v = vec![0xA0, 0x11, 0xB2, 0xD3];
+
+    assert_eq!(v.get_unique_checked(&[]), Ok(vec![]));
+    assert_eq!(v.get_unique_checked(&[2, 0, 2, 2, 3]), Ok(vec![&0xB2, &0xA0, &0xD3]));
+    assert_eq!(*v.get_unique_checked(&[1, 4, 9]).unwrap_err().kind(), ErrorKind::Bounds(4, 4));
+
+    let refs = v.get_unique_checked_mut(&[3, 0, 2]).unwrap();
+    assert_eq!(refs.len(), 3);
+    for (r, value) in refs.into_iter().zip([1, 2, 3].iter())
+    {
+        *r = *value;
+    }
+    assert_eq!(v, [2, 0x11, 3, 1]);
+    assert_eq!(
+        *v.get_unique_checked_mut(&[3, 1, 3, 1]).unwrap_err().kind(),
+        ErrorKind::Duplicate(1)
+    );
+    assert_eq!(*v.get_unique_checked_mut(&[0, 0, 5]).unwrap_err().kind(), ErrorKind::Bounds(5, 4));
+    assert_eq!(v.get_unique_checked_mut(&[]).unwrap().len(), 0);
+}
+
+// Arena tests:
+
+#[test]
+#[cfg(not(feature = "no_std"))]
+fn arena_get_checked()
+{
+    use crate::ArenaSlice;
+
+    let mut arena = ArenaSlice::new();
+    let mut other = ArenaSlice::new();
+    let a = arena.push(1);
+    let b = arena.push(2);
+    other.push(3);
+    let foreign = other.push(4);
+
+    assert_eq!((arena.len(), b.index()), (2, 1));
+    assert_eq!(arena.get_checked(a), Ok(&1));
+    *arena.get_checked_mut(b).unwrap() = 5;
+    assert_eq!(arena.as_slice(), [1, 5]);
+
+    arena.clear();
+    let c = arena.push(6);
+    arena.push(7);
+
+    assert!(a != c);
+    assert_eq!(arena.get_checked(c), Ok(&6));
+    assert_eq!(*arena.get_checked(a).unwrap_err().kind(), ErrorKind::StaleKey(0));
+    assert_eq!(*arena.get_checked_mut(b).unwrap_err().kind(), ErrorKind::StaleKey(1));
+    assert_eq!(other.get_checked(foreign), Ok(&4));
+
+    let mut small = ArenaSlice::new();
+    small.push(8);
+    assert_eq!(*small.get_checked(foreign).unwrap_err().kind(), ErrorKind::Bounds(1, 1));
+}
+
+// Fixed length tests:
+
+#[test]
+fn fixed_len()
+{
+    use crate::{ConstIndex, FixedLen};
+
+    let v = [1, 2, 3, 4];
+    let fixed = FixedLen::<_, 4>::new(&v).unwrap();
+
+    assert_eq!(fixed[ConstIndex::<0>], 1);
+    assert_eq!(*fixed.get::<3>(), 4);
+    assert_eq!(fixed.as_array(), &v);
+    assert_eq!(fixed.as_slice(), &v[..]);
+    assert_eq!(*FixedLen::<_, 5>::new(&v).unwrap_err().kind(), ErrorKind::LengthMismatch(5, 4));
+    assert_eq!(*FixedLen::<_, 3>::new(&v).unwrap_err().kind(), ErrorKind::LengthMismatch(3, 4));
+    assert_eq!(
+        FixedLen::<_, 0>::new(&v).unwrap_err().to_string(),
+        "expected slice of length 0 but found length 4"
+    );
+}
+
+#[test]
+fn min_len()
+{
+    use crate::{ConstIndex, MinLen};
+
+    let v = [1, 2, 3, 4];
+    let min = MinLen::<_, 2>::new(&v).unwrap();
+
+    assert_eq!((min.len(), min.is_empty()), (4, false));
+    assert_eq!(min[ConstIndex::<1>], 2);
+    assert_eq!(*min.get::<0>(), 1);
+    assert_eq!(min.head(), &[1, 2]);
+    assert_eq!(min.tail(), [3, 4]);
+    assert_eq!(min.as_slice(), v);
+    assert_eq!(min.get_checked(2..), Ok(&[3, 4][..]));
+    assert_eq!(*min.get_checked(4).unwrap_err().kind(), ErrorKind::Bounds(4, 4));
+    assert_eq!(MinLen::<_, 4>::new(&v).unwrap().tail(), []);
+    assert!(MinLen::<i32, 0>::new(&[]).unwrap().is_empty());
+    assert_eq!(*MinLen::<_, 5>::new(&v).unwrap_err().kind(), ErrorKind::EndRange(5, 4));
+}
+
+// Nibble tests:
+
+#[test]
+fn nibble_checked()
+{
+    use crate::GetNibbleChecked;
+
+    let mut bytes = [0xA0, 0x11, 0xB2];
+
+    assert_eq!(bytes.nibble_checked(0), Ok(0xA));
+    assert_eq!(bytes.nibble_checked(1), Ok(0x0));
+    assert_eq!(bytes.nibble_checked(5), Ok(0x2));
+
+    bytes.set_nibble_checked(4, 0x3C).unwrap();
+    bytes.set_nibble_checked(1, 0xF).unwrap();
+    assert_eq!(bytes, [0xAF, 0x11, 0xC2]);
+
+    let err = bytes.nibble_checked(6).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::Bounds(6, 6));
+    let err = bytes.set_nibble_checked(usize::MAX, 0).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::Bounds(usize::MAX, 6));
+    assert_eq!(bytes, [0xAF, 0x11, 0xC2]);
+}
+
+// Endian tests:
+
+#[test]
+fn endian_read_checked()
+{
+    use crate::{Endian, ReadEndianChecked};
+
+    let bytes = [0xA0, 0x11, 0xB2, 0xD3, 0xF4, 0x35, 0x66, 0x77, 0x88];
+
+    assert_eq!(bytes.read_u16_checked(0, Endian::Little), Ok(0x11A0));
+    assert_eq!(bytes.read_u16_checked(0, Endian::Big), Ok(0xA011));
+    assert_eq!(bytes.read_u32_checked(5, Endian::Big), Ok(0x35667788));
+    assert_eq!(bytes.read_u64_checked(1, Endian::Little), Ok(0x88776635F4D3B211));
+    assert_eq!(
+        bytes.read_u32_checked(1, Endian::NATIVE),
+        Ok(u32::from_ne_bytes([0x11, 0xB2, 0xD3, 0xF4]))
+    );
+
+    let err = bytes.read_u32_checked(6, Endian::Big).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::EndRange(10, 9));
+    let err = bytes.read_u64_checked(10, Endian::Big).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::StartRange(10, 9));
+    let err = bytes.read_u16_checked(usize::MAX, Endian::Little).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::EndOverflow());
+}
+
+#[test]
+fn endian_write_len_prefixed_checked()
+{
+    use crate::{Endian, LenPrefix, ReadEndianChecked, WriteEndianChecked};
+
+    let mut buf = [0xFFu8; 12];
+
+    assert_eq!(buf.write_len_prefixed_checked(1, b"hi", LenPrefix::U32, Endian::Little), Ok(7));
+    assert_eq!(buf[..7], [0xFF, 2, 0, 0, 0, b'h', b'i']);
+    assert_eq!(buf.read_u32_checked(1, Endian::Little), Ok(2));
+    assert_eq!(buf.write_len_prefixed_checked(7, b"", LenPrefix::U8, Endian::Big), Ok(8));
+    assert_eq!(buf[7], 0);
+    assert_eq!(buf.write_len_prefixed_checked(0, b"abcd", LenPrefix::U64, Endian::Big), Ok(12));
+    assert_eq!(buf.read_u64_checked(0, Endian::Big), Ok(4));
+    assert_eq!(buf[8..], *b"abcd");
+
+    let snapshot = buf;
+    let err = buf.write_len_prefixed_checked(10, b"ab", LenPrefix::U16, Endian::Big).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::EndRange(14, 12));
+    let err = buf.write_len_prefixed_checked(13, b"", LenPrefix::U8, Endian::Big).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::EndRange(14, 12));
+    let err = buf.write_len_prefixed_checked(0, &[0; 256], LenPrefix::U8, Endian::Big).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::CapacityOverflow());
+    let err =
+        buf.write_len_prefixed_checked(usize::MAX, b"", LenPrefix::U16, Endian::Big).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::EndOverflow());
+    assert_eq!(buf, snapshot);
+
+    let mut buf = [0u8; 257];
+    assert_eq!(
+        buf.write_len_prefixed_checked(0, &[7; 255], LenPrefix::U8, Endian::Little),
+        Ok(256)
+    );
+    assert_eq!(buf[..2], [255, 7]);
+}
+
+#[test]
+fn tlv_iter_tlv_checked()
+{
+    use crate::{Endian, GetTlvChecked, LenPrefix, MessageStyle, TlvConfig};
+
+    let config = TlvConfig::new(LenPrefix::U16, LenPrefix::U8, Endian::Little);
+    let bytes = [0xA0, 0x11, 0x02, 0xB2, 0xD3, 0x35, 0x00, 0x00, 0x66, 0x77];
+    let records: Vec<_> = bytes[..8].iter_tlv_checked(config).collect();
+    assert_eq!(records, [Ok((0x11A0, &[0xB2, 0xD3][..])), Ok((0x0035, &[][..]))]);
+
+    let mut records = bytes.iter_tlv_checked(config);
+    assert_eq!(records.nth(1).map(|r| r.map(|(tag, _)| tag)), Some(Ok(0x0035)));
+    assert_eq!(records.offset(), Some(8));
+    let err = records.next().unwrap().unwrap_err().with_style(MessageStyle::Descriptive);
+    assert_eq!(
+        err.to_string(),
+        "range end index 11 out of range for slice of length 10 (range: 10..11, len: 10)"
+    );
+    assert_eq!((records.next(), records.offset()), (None, None));
+
+    let config = TlvConfig::new(LenPrefix::U8, LenPrefix::U64, Endian::Big);
+    let bytes = [0x01, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF];
+    let err = bytes.iter_tlv_checked(config).next().unwrap().unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::EndOverflow());
+    assert_eq!([0u8; 0].iter_tlv_checked(config).next(), None);
+}
+
+#[test]
+#[cfg(feature = "derive")]
+fn endian_derive_read_checked()
+{
+    use crate::ReadChecked;
+
+    #[derive(Debug, PartialEq, ReadChecked)]
+    struct Record(i8, i16, u32, i64);
+
+    #[derive(Debug, PartialEq, ReadChecked)]
+    #[checked(endian = "big")]
+    struct Pair
+    {
+        a: u16,
+        b: i16,
+    }
+
+    #[derive(Debug, PartialEq, ReadChecked)]
+    struct Empty;
+
+    let bytes =
+        [0xA0, 0x11, 0xB2, 0xD3, 0xF4, 0x35, 0x66, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF];
+    assert_eq!(Record::SIZE, 15);
+    assert_eq!(Record::read_checked(&bytes, 0), Ok(Record(-0x60, -0x4DEF, 0x6635F4D3, -1)));
+    assert_eq!(Pair::read_checked(&bytes, 1), Ok(Pair { a: 0x11B2, b: -0x2C0C }));
+    assert_eq!(Empty::read_checked(&bytes, 15), Ok(Empty));
+
+    let err = Record::read_checked(&bytes, 1).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::EndRange(16, 15));
+    let err = Pair::read_checked(&bytes, usize::MAX).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::EndOverflow());
+    let err = Empty::read_checked(&bytes, 16).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::StartRange(16, 15));
+}
+
+// Parse tests:
+
+#[test]
+fn parse_checked_str()
+{
+    use crate::{Error, ParseChecked, ParseError};
+
+    let record = "0042 -7 2.5 bär";
+
+    assert_eq!(record.parse_checked::<u16, _>(..4), Ok(42));
+    assert_eq!(record.parse_checked::<i8, _>(5..7), Ok(-7));
+    assert_eq!(record.parse_checked::<f32, _>(8..=10), Ok(2.5));
+    assert_eq!(record.parse_checked::<char, _>(13..15), Ok('ä'));
+
+    let err = record.parse_checked::<u16, _>(12..17).unwrap_err();
+    assert_eq!(err, ParseError::Index(Error::new(ErrorKind::EndRange(17, 16))));
+    let err = record.parse_checked::<char, _>(13..14).unwrap_err();
+    assert_eq!(err, ParseError::Index(Error::new(ErrorKind::CharBoundary(14))));
+    assert!(matches!(record.parse_checked::<u8, _>(5..7), Err(ParseError::Parse(_))));
+}
+
+#[test]
+fn parse_checked_bytes()
+{
+    use crate::{ParseChecked, ParseError};
+
+    let record = b"0042,\xff1";
+
+    assert_eq!(record.parse_checked::<u32, _>(..4), Ok(42));
+    assert!(matches!(record.parse_checked::<u32, _>(5..), Err(ParseError::Utf8(_))));
+    assert!(matches!(record.parse_checked::<u32, _>(..5), Err(ParseError::Parse(_))));
+    assert!(matches!(record.parse_checked::<u32, _>(8..), Err(ParseError::Index(_))));
+}
+
+// Cursor tests:
+
+#[test]
+fn cursor_advance_checked()
+{
+    use crate::IndexCursor;
+
+    let mut cursor = IndexCursor::new(3);
+    assert_eq!((cursor.position(), cursor.remaining()), (0, 3));
+    assert_eq!(cursor.peek_checked(), Ok(0));
+
+    assert_eq!(cursor.advance_checked(0), Ok(0));
+    assert_eq!(cursor.advance_checked(2), Ok(2));
+    assert_eq!(cursor.peek_checked(), Ok(2));
+
+    let err = cursor.advance_checked(2).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::EndRange(4, 3));
+    let err = cursor.advance_checked(usize::MAX).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::EndOverflow());
+    assert_eq!(cursor.position(), 2);
+
+    assert_eq!(cursor.advance_checked(1), Ok(3));
+    assert!(cursor.is_at_end());
+    let err = cursor.peek_checked().unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::Bounds(3, 3));
+
+    let cursor = IndexCursor::new(0);
+    assert!(cursor.is_empty() && cursor.is_at_end());
+}
+
+#[test]
+fn cursor_expect_checked()
+{
+    use crate::IndexCursor;
+
+    let tokens = [0xA0, 0x11, 0xB2];
+    let mut cursor = IndexCursor::new(tokens.len());
+
+    assert_eq!(cursor.lookahead_checked(2), Ok(2));
+    let err = cursor.lookahead_checked(3).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::Bounds(3, 3));
+
+    assert_eq!(cursor.expect_checked(&tokens, |t| *t == 0xA0), Ok(&0xA0));
+    let err = cursor.expect_checked(&tokens, |t| *t == 0xB2).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::Rejected(1));
+    assert_eq!(cursor.eat(&tokens, |t| *t == 0xB2), None);
+    assert_eq!(cursor.eat(&tokens, |t| *t == 0x11), Some(&0x11));
+    assert_eq!(cursor.position(), 2);
+
+    let err = cursor.lookahead_checked(usize::MAX).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::EndOverflow());
+
+    assert_eq!(cursor.expect_checked(&tokens, |_| true), Ok(&0xB2));
+    let err = cursor.expect_checked(&tokens, |_| true).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::Bounds(3, 3));
+    assert_eq!(cursor.eat(&tokens, |_| true), None);
+}
+
+#[test]
+fn cursor_speculate()
+{
+    use crate::IndexCursor;
+
+    let tokens = [1, 2, 3, 4];
+    let mut cursor = IndexCursor::new(tokens.len());
+    cursor.advance_checked(1).unwrap();
+
+    let checkpoint = cursor.checkpoint();
+    cursor.advance_checked(2).unwrap();
+    cursor.rollback(checkpoint);
+    assert_eq!(cursor.position(), 1);
+    cursor.rollback(IndexCursor::new(10).checkpoint());
+    assert_eq!(cursor.position(), 0);
+    cursor.rollback(checkpoint);
+
+    let err = cursor
+        .speculate(|c| {
+            c.expect_checked(&tokens, |t| *t == 2)?;
+            c.advance_checked(5)
+        })
+        .unwrap_err();
+    assert_eq!((err.offset(), err.checkpoint().position()), (2, 1));
+    assert_eq!(*err.error().kind(), ErrorKind::EndRange(7, 4));
+    assert_eq!(cursor.position(), 1);
+
+    let err =
+        cursor.speculate(|c| c.speculate(|c| c.advance_checked(9)).map_err(|e| e.into_error()));
+    assert_eq!(err.unwrap_err().offset(), 1);
+    assert_eq!(cursor.speculate(|c| c.advance_checked(3)), Ok(4));
+    assert!(cursor.is_at_end());
+}
+
+#[test]
+fn cursor_sub_cursor_checked()
+{
+    use crate::{IndexCursor, MessageStyle};
+
+    let tokens = [0, 1, 2, 3, 4, 5, 6, 7];
+    let mut cursor = IndexCursor::new(tokens.len());
+    cursor.advance_checked(1).unwrap();
+
+    let mut outer = cursor.sub_cursor_checked(5).unwrap();
+    assert_eq!((outer.window(), outer.position(), cursor.position()), (1..6, 1, 6));
+    let err = cursor.sub_cursor_checked(3).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::EndRange(9, 8));
+    assert_eq!(cursor.position(), 6);
+
+    outer.advance_checked(1).unwrap();
+    let mut inner = outer.sub_cursor_checked(2).unwrap();
+    assert_eq!((inner.window(), inner.len(), inner.remaining()), (2..4, 2, 2));
+    assert!(!inner.is_empty());
+
+    let descriptive = |e: crate::Error| e.with_style(MessageStyle::Descriptive).to_string();
+    assert_eq!(inner.expect_checked(&tokens, |t| *t == 2), Ok(&2));
+    let err = inner.expect_checked(&tokens, |t| *t == 2).unwrap_err();
+    assert_eq!(descriptive(err), "element at index 3 was rejected (range: 2..4, len: 8)");
+    inner.advance_checked(1).unwrap();
+    let err = inner.peek_checked().unwrap_err();
+    assert_eq!(
+        descriptive(err),
+        "index out of bounds: the len is 4 but the index is 4 (range: 2..4, len: 8)"
+    );
+    let err = inner.lookahead_checked(usize::MAX).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::EndOverflow());
+
+    inner.rollback(cursor.checkpoint());
+    assert_eq!(inner.position(), 4);
+    inner.rollback(IndexCursor::new(8).checkpoint());
+    assert_eq!(inner.position(), 2);
+
+    let empty = outer.sub_cursor_checked(0).unwrap();
+    assert!(empty.is_empty() && empty.is_at_end());
+    assert_eq!((empty.window(), empty.len()), (4..4, 0));
+}
+
+// Reverse indexing tests:
+
+#[test]
+fn reverse_get_from_end_checked()
+{
+    use crate::GetFromEndChecked;
+
+    let mut bytes = [0xA0, 0x11, 0xB2];
+
+    assert_eq!(bytes.get_from_end_checked(0), Ok(&0xB2));
+    assert_eq!(bytes.get_from_end_checked(2), Ok(&0xA0));
+    *bytes.get_from_end_checked_mut(1).unwrap() = 0xD3;
+    assert_eq!(bytes, [0xA0, 0xD3, 0xB2]);
+
+    assert_eq!(bytes.range_from_end_checked(0), Ok(&[][..]));
+    assert_eq!(bytes.range_from_end_checked(3), Ok(&bytes[..]));
+    bytes.range_from_end_checked_mut(2).unwrap().fill(0);
+    assert_eq!(bytes, [0xA0, 0, 0]);
+
+    let err = bytes.get_from_end_checked(3).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::Bounds(3, 3));
+    let err = bytes.get_from_end_checked_mut(usize::MAX).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::Bounds(usize::MAX, 3));
+    let err = bytes.range_from_end_checked(4).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::EndRange(4, 3));
+    let err = [0u8; 0].get_from_end_checked(0).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::Bounds(0, 0));
+}
+
+// Pattern fill tests:
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn fill_pattern_checked()
+{
+    use crate::FillPatternChecked;
+
+    let mut v = [0u8; 7];
+
+    v.fill_pattern_checked(.., &[1, 2, 3]).unwrap();
+    assert_eq!(v, [1, 2, 3, 1, 2, 3, 1]);
+    v.fill_pattern_checked(2..=4, &[9]).unwrap();
+    assert_eq!(v, [1, 2, 9, 9, 9, 3, 1]);
+    v.fill_pattern_checked(5..5, &[7]).unwrap();
+    v.fill_pattern_checked(5.., &[7, 8, 9, 10]).unwrap();
+    assert_eq!(v, [1, 2, 9, 9, 9, 7, 8]);
+
+    assert_eq!(*v.fill_pattern_checked(3..8, &[0]).unwrap_err().kind(), ErrorKind::EndRange(8, 7));
+    assert_eq!(*v.fill_pattern_checked(4..2, &[0]).unwrap_err().kind(), ErrorKind::Order(4, 2));
+    assert_eq!(*v.fill_pattern_checked(..2, &[]).unwrap_err().kind(), ErrorKind::ZeroChunk());
+    assert_eq!(v, [1, 2, 9, 9, 9, 7, 8]);
+
+    let mut names = vec![String::new(); 3];
+    names.fill_pattern_checked(1.., &["a".to_string(), "b".to_string()]).unwrap();
+    assert_eq!(names, ["", "a", "b"]);
+}
+
+// Subslice search tests:
+
+#[test]
+fn search_find_subslice_checked()
+{
+    use crate::{FindSubsliceChecked, GetChecked};
+
+    let v = [1, 2, 3, 2, 3, 4];
+
+    assert_eq!(v.find_subslice_checked(&[2, 3]), Ok(1..3));
+    assert_eq!(v.find_subslice_checked(&[3, 4]), Ok(4..6));
+    assert_eq!(v.find_subslice_checked(&v), Ok(0..6));
+    assert_eq!(v.find_subslice_checked(&[]), Ok(0..0));
+    assert_eq!(v[..0].find_subslice_checked(&[]), Ok(0..0));
+    let range = v.find_subslice_checked(&[3, 2]).unwrap();
+    assert_eq!(v.get_checked(range), Ok(&[3, 2][..]));
+
+    assert_eq!(*v.find_subslice_checked(&[4, 1]).unwrap_err().kind(), ErrorKind::NotFound(2));
+    assert_eq!(*v.find_subslice_checked(&[0; 7]).unwrap_err().kind(), ErrorKind::NotFound(7));
+    assert_eq!(*v[..0].find_subslice_checked(&[1]).unwrap_err().kind(), ErrorKind::NotFound(1));
+}
+
+#[test]
+fn search_between_checked()
+{
+    use crate::FindSubsliceChecked;
+
+    let v = b"a<<b>>c<<>>";
+
+    assert_eq!(v.between_checked(b"<<", b">>"), Ok(3..4));
+    assert_eq!(v[4..].between_checked(b"<<", b">>"), Ok(5..5));
+    assert_eq!(v.between_checked(b"", b">>"), Ok(0..4));
+    assert_eq!(v.between_checked(b"<<", b""), Ok(3..3));
+    assert_eq!(v.between_checked(b"a", b"c"), Ok(1..6));
+
+    assert_eq!(*v.between_checked(b"[", b"]").unwrap_err().kind(), ErrorKind::NotFound(1));
+    assert_eq!(*v.between_checked(b"<<", b"]").unwrap_err().kind(), ErrorKind::Unclosed(1));
+    assert_eq!(*v.between_checked(b"c", b"a").unwrap_err().kind(), ErrorKind::Unclosed(6));
+    assert_eq!(*b"<>".between_checked(b"<>", b">").unwrap_err().kind(), ErrorKind::Unclosed(0));
+}
+
+#[test]
+fn search_strip_checked()
+{
+    use crate::StripChecked;
+
+    let v = [1, 2, 3, 4];
+
+    assert_eq!(v.strip_prefix_checked(&[1, 2]), Ok(&[3, 4][..]));
+    assert_eq!(v.strip_prefix_checked(&[]), Ok(&v[..]));
+    assert_eq!(v.strip_prefix_checked(&v), Ok(&[][..]));
+    assert_eq!(*v.strip_prefix_checked(&[1, 9, 3]).unwrap_err().kind(), ErrorKind::Mismatch(1));
+    assert_eq!(
+        *v.strip_prefix_checked(&[1, 2, 3, 4, 5]).unwrap_err().kind(),
+        ErrorKind::EndRange(5, 4)
+    );
+    assert_eq!(
+        *v.strip_prefix_checked(&[1, 2, 0, 4, 5]).unwrap_err().kind(),
+        ErrorKind::Mismatch(2)
+    );
+
+    assert_eq!(v.strip_suffix_checked(&[3, 4]), Ok(&[1, 2][..]));
+    assert_eq!(v.strip_suffix_checked(&[]), Ok(&v[..]));
+    assert_eq!(v.strip_suffix_checked(&v), Ok(&[][..]));
+    assert_eq!(*v.strip_suffix_checked(&[9, 3, 4]).unwrap_err().kind(), ErrorKind::Mismatch(1));
+    assert_eq!(
+        *v.strip_suffix_checked(&[0, 1, 2, 3, 4]).unwrap_err().kind(),
+        ErrorKind::EndRange(5, 4)
+    );
+    assert_eq!(
+        *v.strip_suffix_checked(&[0, 9, 2, 3, 4]).unwrap_err().kind(),
+        ErrorKind::Mismatch(0)
+    );
+}
+
+// Limited slice tests:
+
+#[test]
+fn limited_get_checked()
+{
+    use crate::LimitedSlice;
+
+    let v = [0xA0, 0x11, 0xB2, 0xD3, 0x0F4, 0x35];
+
+    let limited = LimitedSlice::new(&v, 4);
+    assert_eq!(limited.len(), 4);
+    assert_eq!(limited.limit(), 4);
+    assert_eq!(limited.as_slice(), [0xA0, 0x11, 0xB2, 0xD3]);
+    assert_eq!(limited.get_checked(3), Ok(&0xD3));
+    assert_eq!(*limited.get_checked(4).unwrap_err().kind(), ErrorKind::LimitExceeded(4, 4));
+    assert_eq!(*limited.get_checked(9).unwrap_err().kind(), ErrorKind::LimitExceeded(9, 4));
+
+    let limited = LimitedSlice::new(&v, 8);
+    assert_eq!(limited.len(), 6);
+    assert_eq!(limited.get_checked(5), Ok(&0x35));
+    assert_eq!(*limited.get_checked(6).unwrap_err().kind(), ErrorKind::Bounds(6, 6));
+
+    assert!(LimitedSlice::new(&v, 0).is_empty());
+    assert_eq!(
+        LimitedSlice::new(&v, 0).get_checked(0).unwrap_err().to_string(),
+        "index 0 exceeds access limit of 0"
+    );
+}
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn limited_get_range_checked()
+{
+    use crate::LimitedSlice;
+
+    let v = [0xA0, 0x11, 0xB2, 0xD3, 0x0F4, 0x35];
+
+    let limited = LimitedSlice::new(&v, 4);
+    assert_eq!(limited.get_range_checked(1..4), Ok(&[0x11, 0xB2, 0xD3][..]));
+    assert_eq!(limited.get_range_checked(..), Ok(&v[..4]));
+    assert_eq!(limited.get_range_checked(4..), Ok(&[][..]));
+    assert_eq!(
+        *limited.get_range_checked(2..5).unwrap_err().kind(),
+        ErrorKind::LimitExceeded(5, 4)
+    );
+    assert_eq!(
+        *limited.get_range_checked(..=4).unwrap_err().kind(),
+        ErrorKind::LimitExceeded(4, 4)
+    );
+    assert_eq!(*limited.get_range_checked(5..).unwrap_err().kind(), ErrorKind::LimitExceeded(5, 4));
+    assert_eq!(*limited.get_range_checked(3..2).unwrap_err().kind(), ErrorKind::Order(3, 2));
+
+    let limited = LimitedSlice::new(&v, 8);
+    assert_eq!(limited.get_range_checked(2..6), Ok(&v[2..]));
+    assert_eq!(*limited.get_range_checked(2..7).unwrap_err().kind(), ErrorKind::EndRange(7, 6));
+}
+
+// Metered view tests:
+
+#[test]
+fn metered_get_checked()
+{
+    use crate::Metered;
+
+    let v = [0xA0, 0x11, 0xB2, 0xD3, 0x0F4, 0x35];
+    let mut metered = Metered::new(&v, 4);
+
+    assert_eq!(metered.len(), 6);
+    assert_eq!(metered.budget(), 4);
+    assert_eq!(metered.get_range_checked(1..3), Ok(&[0x11, 0xB2][..]));
+    assert_eq!(metered.get_checked(1), Ok(&0x11));
+    assert_eq!(metered.used(), 3);
+
+    assert_eq!(*metered.get_checked(6).unwrap_err().kind(), ErrorKind::Bounds(6, 6));
+    assert_eq!(*metered.get_range_checked(4..7).unwrap_err().kind(), ErrorKind::EndRange(7, 6));
+    assert_eq!(*metered.get_range_checked(..).unwrap_err().kind(), ErrorKind::QuotaExceeded(6, 1));
+    assert_eq!(metered.remaining(), 1);
+
+    assert_eq!(metered.get_range_checked(6..), Ok(&[][..]));
+    assert_eq!(metered.get_checked(5), Ok(&0x35));
+    assert_eq!(metered.remaining(), 0);
+    assert_eq!(
+        metered.get_checked(0).unwrap_err().to_string(),
+        "access of 1 elements exceeds remaining quota of 0"
+    );
+}
+
+// Coverage tests:
+
+#[test]
+#[cfg(not(feature = "no_std"))]
+#[allow(clippy::single_range_in_vec_init)]
+fn coverage_get_checked()
+{
+    use crate::Coverage;
+
+    let v = [0xA0, 0x11, 0xB2, 0xD3, 0x0F4, 0x35, 0x11];
+    let mut view = Coverage::new(&v);
+
+    assert_eq!(view.unread(), [0..7]);
+    assert_eq!(*view.finish_checked().unwrap_err().kind(), ErrorKind::Unread(0, 7));
+
+    assert_eq!(view.get_range_checked(1..3), Ok(&[0x11, 0xB2][..]));
+    assert_eq!(view.get_checked(5), Ok(&0x35));
+    assert_eq!(view.get_range_checked(2..4), Ok(&[0xB2, 0xD3][..]));
+    assert_eq!(view.get_range_checked(6..6), Ok(&[][..]));
+    assert_eq!(*view.get_checked(7).unwrap_err().kind(), ErrorKind::Bounds(7, 7));
+    assert_eq!(*view.get_range_checked(6..8).unwrap_err().kind(), ErrorKind::EndRange(8, 7));
+
+    assert_eq!(view.reads(), [1..3, 5..6, 2..4, 6..6]);
+    assert_eq!(view.unread(), [0..1, 4..5, 6..7]);
+    assert_eq!(view.reread(), [2..3]);
+    assert_eq!(*view.finish_checked().unwrap_err().kind(), ErrorKind::Unread(0, 1));
+
+    view.get_range_checked(..1).unwrap();
+    assert_eq!(*view.finish_checked().unwrap_err().kind(), ErrorKind::Overlap(2, 3));
+}
+
+#[test]
+#[cfg(not(feature = "no_std"))]
+#[allow(clippy::single_range_in_vec_init)]
+fn coverage_finish_checked()
+{
+    use crate::Coverage;
+
+    let v = [0xA0, 0x11, 0xB2, 0xD3];
+    let mut view = Coverage::new(&v);
+
+    view.get_range_checked(2..).unwrap();
+    view.get_checked(0).unwrap();
+    view.get_checked(1).unwrap();
+    assert!(view.unread().is_empty());
+    assert!(view.reread().is_empty());
+    assert_eq!(view.finish_checked(), Ok(()));
+
+    view.get_range_checked(1..3).unwrap();
+    view.get_range_checked(..).unwrap();
+    assert_eq!(view.reread(), [0..4]);
+    assert_eq!(
+        view.finish_checked().unwrap_err().to_string(),
+        "range starting at 0 overlaps preceding range ending at 1"
+    );
+
+    assert_eq!(Coverage::<u8>::new(&[]).finish_checked(), Ok(()));
+}
+
+// Traced view tests:
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn traced_get_checked()
+{
+    use core::ops::Bound;
+
+    use crate::{Access, AccessRecord, Traced};
+
+    let v = [0xA0, 0x11, 0xB2, 0xD3];
+    let mut log = Vec::new();
+    let mut sink = |record: AccessRecord| log.push(record);
+    let mut view = Traced::new(&v, &mut sink);
+
+    assert_eq!(view.len(), 4);
+    assert_eq!(view.get_checked(3), Ok(&0xD3));
+    assert_eq!(*view.get_checked(4).unwrap_err().kind(), ErrorKind::Bounds(4, 4));
+    assert_eq!(view.get_range_checked(1..=2), Ok(&[0x11, 0xB2][..]));
+    assert_eq!(view.get_range_checked(..), Ok(&v[..]));
+    assert_eq!(*view.get_range_checked(3..1).unwrap_err().kind(), ErrorKind::Order(3, 1));
+
+    assert_eq!(log.len(), 5);
+    assert_eq!(log[0], AccessRecord { access: Access::Index(3), result: Ok(3..4) });
+    assert_eq!(log[1].access, Access::Index(4));
+    assert_eq!(*log[1].result.as_ref().unwrap_err().kind(), ErrorKind::Bounds(4, 4));
+    assert_eq!(log[2].access, Access::Range(Bound::Included(1), Bound::Included(2)));
+    assert_eq!(log[2].result, Ok(1..3));
+    assert_eq!(
+        log[3],
+        AccessRecord {
+            access: Access::Range(Bound::Unbounded, Bound::Unbounded),
+            result: Ok(0..4),
+        }
+    );
+    assert_eq!(*log[4].result.as_ref().unwrap_err().kind(), ErrorKind::Order(3, 1));
+}
+
+#[test]
+fn traced_debounced()
+{
+    use crate::{AccessRecord, Debounced, Traced};
+
+    let v = [0xA0, 0x11, 0xB2, 0xD3];
+    let mut log = Vec::new();
+    let mut debounced = Debounced::new(3, |record: AccessRecord| log.push(record));
+    {
+        let mut sink = |record| debounced.report(record);
+        let mut view = Traced::new(&v, &mut sink);
+        for i in 0..8
+        {
+            assert!(view.get_checked(i).is_ok() == (i < 4));
+        }
+        assert!(view.get_range_checked(..5).is_err());
+    }
+
+    assert_eq!((debounced.failures(), debounced.suppressed()), (5, 3));
+    let forwarded: Vec<_> = log.iter().map(|record| record.result.is_ok()).collect();
+    assert_eq!(forwarded, [true, true, true, true, false, false]);
+    assert_eq!(*log[5].result.as_ref().unwrap_err().kind(), ErrorKind::Bounds(7, 4));
+
+    let mut count = 0;
+    let mut every = Debounced::new(0, |_| count += 1);
+    let mut sink = |record| every.report(record);
+    let mut view = Traced::new(&v, &mut sink);
+    assert!(view.get_checked(4).is_err() && view.get_checked(5).is_err());
+    assert_eq!(every.suppressed(), 0);
+    assert_eq!(count, 2);
+}
+
+// Inline vector tests:
+
+#[test]
+#[cfg(any(feature = "smallvec", feature = "arrayvec", feature = "tinyvec"))]
+fn inline_get_checked()
+{
+    use crate::GetCheckedMut;
+
+    #[allow(dead_code)]
+    fn bump<S>(s: &mut S) -> Result<i32, crate::IndexError>
+    where S: GetCheckedMut<i32, Target = [i32]>
+    {
+        *s.get_checked_mut(1)? += 1;
+        Ok(s.get_checked(..)?.iter().sum())
+    }
+
+    #[cfg(feature = "smallvec")]
+    {
+        let mut v: smallvec::SmallVec<[i32; 2]> = smallvec::smallvec![0xA0, 0x11];
+        assert_eq!(bump(&mut v), Ok(0xA0 + 0x12));
+        v.push(0xB2);
+        assert_eq!(bump(&mut v), Ok(0xA0 + 0x13 + 0xB2));
+        assert_eq!(*v.get_checked(3).unwrap_err().kind(), ErrorKind::Bounds(3, 3));
+    }
+
+    #[cfg(feature = "arrayvec")]
+    {
+        let mut v: arrayvec::ArrayVec<i32, 4> = [0xA0, 0x11].iter().copied().collect();
+        assert_eq!(bump(&mut v), Ok(0xA0 + 0x12));
+        assert_eq!(*v.get_checked(2..4).unwrap_err().kind(), ErrorKind::EndRange(4, 2));
+    }
+
+    #[cfg(feature = "tinyvec")]
+    {
+        let mut v: tinyvec::ArrayVec<[i32; 4]> = tinyvec::array_vec![0xA0, 0x11];
+        assert_eq!(bump(&mut v), Ok(0xA0 + 0x12));
+        assert_eq!(*v.get_checked(2).unwrap_err().kind(), ErrorKind::Bounds(2, 2));
+
+        let mut backing = [0xA0, 0x11, 0xB2];
+        let mut v = tinyvec::SliceVec::from_slice_len(&mut backing, 2);
+        assert_eq!(bump(&mut v), Ok(0xA0 + 0x12));
+        assert_eq!(*v.get_checked(2).unwrap_err().kind(), ErrorKind::Bounds(2, 2));
+    }
+
+    #[cfg(all(feature = "tinyvec", feature = "alloc"))]
+    {
+        let mut v: tinyvec::TinyVec<[i32; 2]> = tinyvec::tiny_vec![0xA0, 0x11, 0xB2];
+        assert!(v.is_heap());
+        assert_eq!(bump(&mut v), Ok(0xA0 + 0x12 + 0xB2));
+        assert_eq!(*v.get_checked(3).unwrap_err().kind(), ErrorKind::Bounds(3, 3));
+    }
+}
+
+#[test]
+#[cfg(feature = "heapless")]
+fn heapless_get_checked()
+{
+    use crate::GetCheckedDeque;
+
+    let mut v = heapless::Vec::<i32, 4>::new();
+    v.extend_from_slice(&[0xA0, 0x11]).unwrap();
+    *v.get_checked_mut(1).unwrap() += 1;
+    assert_eq!(v.get_checked(..), Ok(&[0xA0, 0x12][..]));
+    assert_eq!(*v.as_view().get_checked(2).unwrap_err().kind(), ErrorKind::Bounds(2, 2));
+
+    let mut s = heapless::String::<8>::new();
+    s.push_str("héllo").unwrap();
+    assert_eq!(s.get_checked(1..3), Ok("é"));
+    assert_eq!(*s.get_checked(2..).unwrap_err().kind(), ErrorKind::CharBoundary(2));
+    s.get_checked_mut(3..).unwrap().make_ascii_uppercase();
+    assert_eq!(s.as_view().get_checked(..), Ok("héLLO"));
+
+    let mut d = heapless::Deque::<i32, 4>::new();
+    for x in [0xA0, 0x11, 0xB2, 0xC3]
+    {
+        d.push_back(x).unwrap();
+    }
+    d.pop_front();
+    d.push_back(0xD4).unwrap();
+    assert_eq!(d.get_checked(3), Ok(&0xD4));
+    assert_eq!(d.slices_checked(1..), Ok((&[0xB2, 0xC3][..], &[0xD4][..])));
+    assert_eq!(*d.slice_checked(1..).unwrap_err().kind(), ErrorKind::Discontiguous(3));
+    *d.get_checked_mut(0).unwrap() += 1;
+    assert_eq!(d.slice_checked_mut(..3), Ok(&mut [0x12, 0xB2, 0xC3][..]));
+    assert_eq!(*d.get_checked(4).unwrap_err().kind(), ErrorKind::Bounds(4, 4));
+}
+
+#[test]
+#[cfg(feature = "bytes")]
+fn bytes_get_checked()
+{
+    use crate::GetCheckedBytes;
+
+    let frame = bytes::Bytes::from_static(&[0xA0, 0x11, 0xB2, 0xD3]);
+    assert_eq!(frame.get_checked(1..3), Ok(&[0x11, 0xB2][..]));
+    assert_eq!(*frame.get_checked(4).unwrap_err().kind(), ErrorKind::Bounds(4, 4));
+
+    let tail = frame.slice_checked(2..).unwrap();
+    assert_eq!(&tail[..], [0xB2, 0xD3]);
+    assert_eq!(tail.slice_checked(..=1).unwrap(), frame.slice_checked(2..4).unwrap());
+    assert_eq!(*tail.slice_checked(3..).unwrap_err().kind(), ErrorKind::StartRange(3, 2));
+    assert_eq!(
+        *frame.slice_checked(..usize::MAX).unwrap_err().kind(),
+        ErrorKind::EndRange(usize::MAX, 4)
+    );
+
+    let mut buf = bytes::BytesMut::from(&frame[..]);
+    *buf.get_checked_mut(0).unwrap() = 0x35;
+    assert_eq!(buf.get_checked(..2), Ok(&[0x35, 0x11][..]));
+    assert_eq!(*buf.get_checked_mut(1..5).unwrap_err().kind(), ErrorKind::EndRange(5, 4));
+}
+
+// Quickcheck tests:
+
+#[test]
+#[cfg(all(feature = "quickcheck", not(feature = "no_std")))]
+fn quickcheck_arbitrary_index()
+{
+    use crate::ArbitraryIndex;
+
+    fn agrees_with_get(v: Vec<u8>, a: ArbitraryIndex, b: ArbitraryIndex) -> bool
+    {
+        let (a, b) = (a.0, b.0);
+        v.get_checked(a).ok() == v.get(a)
+            && v.get_checked(a..b).ok() == v.get(a..b)
+            && v.get_checked(a..=b).ok() == v.get(a..=b)
+            && v.get_checked(..b).ok() == v.get(..b)
+            && v.get_checked(a..).ok() == v.get(a..)
+    }
+
+    quickcheck::quickcheck(agrees_with_get as fn(_, _, _) -> _);
+    assert_eq!(quickcheck::Arbitrary::shrink(&ArbitraryIndex(0)).count(), 0);
+}
+
+#[test]
+#[cfg(all(feature = "quickcheck", not(feature = "no_std")))]
+fn quickcheck_arbitrary_kind()
+{
+    use crate::Error;
+
+    fn displays(kind: ErrorKind) -> bool
+    {
+        !Error::new(kind).to_string().is_empty()
+    }
+
+    quickcheck::quickcheck(displays as fn(_) -> _);
+
+    let mut g = quickcheck::Gen::new(16);
+    let mut seen = [false; ErrorKind::COUNT];
+    for _ in 0..ErrorKind::COUNT * 100
+    {
+        let kind: ErrorKind = quickcheck::Arbitrary::arbitrary(&mut g);
+        seen[usize::from(kind.discriminant())] = true;
+    }
+    assert!(seen.iter().all(|seen| *seen));
+}
+
+// Zero-sized type tests:
+
+/// Returns a slice of `len` zero-sized elements without iterating over them.
+fn zst_slice<'a>(len: usize) -> &'a mut [()]
+{
+    unsafe { core::slice::from_raw_parts_mut(core::ptr::NonNull::dangling().as_ptr(), len) }
+}
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn zst_get_checked()
+{
+    let v = zst_slice(usize::MAX);
+
+    assert_eq!(v.get_checked(usize::MAX - 1), Ok(&()));
+    assert_eq!(v.get_checked(1..).map(<[()]>::len), Ok(usize::MAX - 1));
+    assert_eq!(v.get_checked(..=usize::MAX - 1).map(<[()]>::len), Ok(usize::MAX));
+    assert_eq!(v.get_checked_mut(..).map(|s| s.len()), Ok(usize::MAX));
+
+    let err = v.get_checked(usize::MAX).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::Bounds(usize::MAX, usize::MAX));
+    let err = v.get_checked(0..=usize::MAX).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::EndRange(usize::MAX, usize::MAX));
+    let err = v.get_checked_mut(..=usize::MAX).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::EndRange(usize::MAX, usize::MAX));
+    let err = v.get_checked(usize::MAX..1).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::Order(usize::MAX, 1));
+}
+
+#[test]
+fn zst_views()
+{
+    use crate::{GetFrameChecked, GetWindowChecked};
+
+    let v = zst_slice(usize::MAX);
+
+    assert_eq!(v.frame_checked(usize::MAX / 2 - 1, 2).map(<[()]>::len), Ok(2));
+    let err = v.frame_checked(usize::MAX / 2, 2).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::Bounds(usize::MAX / 2, usize::MAX / 2));
+
+    let windows = v.window_by_checked(usize::MAX - 3, 2, 1).unwrap();
+    assert_eq!(windows.count(), 2);
+    let err = v.window_by_checked(usize::MAX, 1, 1).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::EndOverflow());
+}
+
+#[test]
+#[cfg(not(feature = "no_std"))]
+#[allow(clippy::uninit_vec)]
+fn zst_vec()
+{
+    use crate::{GetCheckedVec, Growth};
+
+    let mut v: Vec<()> = Vec::new();
+    // Vectors of zero-sized types have a capacity of `usize::MAX` without allocating, and `()`
+    // has no invalid values.
+    unsafe { v.set_len(usize::MAX - 1) };
+
+    assert_eq!(
+        v.get_or_grow_mut(usize::MAX - 1).map(|(_, g)| g),
+        Ok(Growth::Grown(usize::MAX - 1))
+    );
+    assert_eq!(v.len(), usize::MAX);
+
+    let err = v.insert_slice_checked(0, &[()]).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::CapacityOverflow());
+    let err = v.extend_from_within_checked(..1).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::CapacityOverflow());
+    let err = v.get_or_grow_mut(usize::MAX).unwrap_err();
+    assert_eq!(*err.kind(), ErrorKind::EndOverflow());
+    assert_eq!(v.len(), usize::MAX);
+
+    v.insert_slice_checked(0, &[]).unwrap();
+    v.extend_from_within_checked(..0).unwrap();
+    assert_eq!(v.len(), usize::MAX);
 }
@@ -0,0 +1,316 @@
+use core::ops;
+
+use crate::{
+    Error, GetChecked, GetCheckedMut, GetCheckedSliceIndex, IndexError,
+    IndexErrorKind::{Bounds, CharBoundary},
+    try_range,
+};
+
+/// Converts a byte index of `s` into the index of the `char` starting at it.
+///
+/// A `byte_idx` equal to the length of `s` converts to the number of chars in `s`.
+///
+/// # Errors
+///
+/// Returns an [`IndexError`] of kind `Bounds` if `byte_idx` is greater than the length of `s`,
+/// or `CharBoundary` if it does not lie on a char boundary.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{byte_to_char_index_checked, IndexErrorKind};
+/// let s = "héllo";
+/// assert_eq!(byte_to_char_index_checked(s, 3), Ok(2));
+/// assert_eq!(
+///     *byte_to_char_index_checked(s, 2).unwrap_err().kind(),
+///     IndexErrorKind::CharBoundary(2)
+/// );
+/// ```
+pub fn byte_to_char_index_checked(s: &str, byte_idx: usize) -> Result<usize, IndexError>
+{
+    Ok(s[..check_boundary(s, byte_idx)?].chars().count())
+}
+
+/// Converts the index of a `char` of `s` into the byte index at which it starts.
+///
+/// A `char_idx` equal to the number of chars in `s` converts to the length of `s`.
+///
+/// # Errors
+///
+/// Returns an [`IndexError`] of kind `Bounds` containing `char_idx` and the number of chars in
+/// `s` if `char_idx` is greater than the number of chars.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{char_to_byte_index_checked, IndexErrorKind};
+/// let s = "héllo";
+/// assert_eq!(char_to_byte_index_checked(s, 2), Ok(3));
+/// assert_eq!(char_to_byte_index_checked(s, 5), Ok(6));
+/// assert_eq!(*char_to_byte_index_checked(s, 6).unwrap_err().kind(), IndexErrorKind::Bounds(6, 5));
+/// ```
+pub fn char_to_byte_index_checked(s: &str, char_idx: usize) -> Result<usize, IndexError>
+{
+    let mut indices = s.char_indices().map(|(i, _)| i).chain(Some(s.len()));
+    indices.nth(char_idx).ok_or_else(|| Error::new(Bounds(char_idx, s.chars().count())))
+}
+
+/// Trait adding checked text-position helpers to `str`.
+pub trait GetCheckedStr
+{
+    /// Returns the 1-based `(line, column)` position of `byte_idx`, where lines are terminated
+    /// by `\n` and columns count chars.
+    ///
+    /// A `byte_idx` equal to the length of the string refers to the position just after the
+    /// last char.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Bounds` if `byte_idx` is greater than the length of the
+    /// string, or `CharBoundary` if it does not lie on a char boundary.
+    ///
+    /// # Examples
+    /// ```
+    /// # use get_checked::GetCheckedStr;
+    /// let s = "fn main()\n{\n    bär\n}";
+    /// assert_eq!(s.position_checked(0), Ok((1, 1)));
+    /// assert_eq!(s.position_checked(17), Ok((3, 6)));
+    /// assert!(s.position_checked(18).is_err());
+    /// ```
+    fn position_checked(&self, byte_idx: usize) -> Result<(usize, usize), IndexError>;
+
+    /// Returns the `n`th field of the string when split on `delimiter`.
+    ///
+    /// Fields follow the semantics of [`str::split`]; a trailing delimiter yields a final empty
+    /// field. See [`record_checked`](crate::GetRecordChecked::record_checked) for byte slices.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Bounds` containing `n` and the number of fields found
+    /// if the field does not exist.
+    ///
+    /// # Examples
+    /// ```
+    /// # use get_checked::{GetCheckedStr, IndexErrorKind};
+    /// let row = "id;name;größe";
+    /// assert_eq!(row.field_checked(2, ';'), Ok("größe"));
+    /// assert_eq!(*row.field_checked(3, ';').unwrap_err().kind(), IndexErrorKind::Bounds(3, 3));
+    /// ```
+    fn field_checked(&self, n: usize, delimiter: char) -> Result<&str, IndexError>;
+
+    /// Returns the byte index reached by advancing `n` chars forward from `byte_idx`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Bounds` if `byte_idx` is greater than the length of the
+    /// string, `CharBoundary` if it does not lie on a char boundary, or `Bounds` containing `n`
+    /// and the number of chars after `byte_idx` if fewer than `n` remain.
+    ///
+    /// # Examples
+    /// ```
+    /// # use get_checked::{GetCheckedStr, IndexErrorKind};
+    /// let s = "añb";
+    /// assert_eq!(s.next_char_boundary_checked(0, 2), Ok(3));
+    /// assert_eq!(s.next_char_boundary_checked(3, 1), Ok(4));
+    /// assert_eq!(
+    ///     *s.next_char_boundary_checked(1, 3).unwrap_err().kind(),
+    ///     IndexErrorKind::Bounds(3, 2)
+    /// );
+    /// ```
+    fn next_char_boundary_checked(&self, byte_idx: usize, n: usize) -> Result<usize, IndexError>;
+
+    /// Returns the byte index reached by moving `n` chars backward from `byte_idx`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Bounds` if `byte_idx` is greater than the length of the
+    /// string, `CharBoundary` if it does not lie on a char boundary, or `Bounds` containing `n`
+    /// and the number of chars before `byte_idx` if fewer than `n` precede it.
+    ///
+    /// # Examples
+    /// ```
+    /// # use get_checked::{GetCheckedStr, IndexErrorKind};
+    /// let s = "añb";
+    /// assert_eq!(s.prev_char_boundary_checked(4, 2), Ok(1));
+    /// assert_eq!(
+    ///     *s.prev_char_boundary_checked(2, 1).unwrap_err().kind(),
+    ///     IndexErrorKind::CharBoundary(2)
+    /// );
+    /// ```
+    fn prev_char_boundary_checked(&self, byte_idx: usize, n: usize) -> Result<usize, IndexError>;
+}
+
+impl GetCheckedStr for str
+{
+    fn position_checked(&self, byte_idx: usize) -> Result<(usize, usize), IndexError>
+    {
+        byte_to_char_index_checked(self, byte_idx)?;
+
+        let before = &self[..byte_idx];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let line = before.matches('\n').count() + 1;
+        let column = before[line_start..].chars().count() + 1;
+
+        Ok((line, column))
+    }
+
+    fn field_checked(&self, n: usize, delimiter: char) -> Result<&str, IndexError>
+    {
+        let fields = || self.split(delimiter);
+        fields().nth(n).ok_or_else(|| Error::new(Bounds(n, fields().count())))
+    }
+
+    fn next_char_boundary_checked(&self, byte_idx: usize, n: usize) -> Result<usize, IndexError>
+    {
+        let rest = &self[check_boundary(self, byte_idx)?..];
+        let mut ends = rest.char_indices().map(|(i, c)| byte_idx + i + c.len_utf8());
+        match n
+        {
+            | 0 => Ok(byte_idx),
+            | _ => ends.nth(n - 1).ok_or_else(|| Error::new(Bounds(n, rest.chars().count()))),
+        }
+    }
+
+    fn prev_char_boundary_checked(&self, byte_idx: usize, n: usize) -> Result<usize, IndexError>
+    {
+        let before = &self[..check_boundary(self, byte_idx)?];
+        let mut starts = before.char_indices().rev().map(|(i, _)| i);
+        match n
+        {
+            | 0 => Ok(byte_idx),
+            | _ => starts.nth(n - 1).ok_or_else(|| Error::new(Bounds(n, before.chars().count()))),
+        }
+    }
+}
+
+/// Indexes `str` by byte ranges, so `get_checked` returns a `&str`.
+///
+/// Use [`as_bytes`](str::as_bytes) to index the UTF-8 bytes instead.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{GetChecked, IndexErrorKind};
+/// let s = "héllo";
+/// assert_eq!(s.get_checked(1..3), Ok("é"));
+/// assert_eq!(*s.get_checked(2..).unwrap_err().kind(), IndexErrorKind::CharBoundary(2));
+/// assert_eq!(*s.get_checked(..7).unwrap_err().kind(), IndexErrorKind::EndRange(7, 6));
+/// assert_eq!(s.as_bytes().get_checked(1..3), Ok(&[0xC3, 0xA9][..]));
+/// ```
+impl GetChecked<u8> for str
+{
+    type Target = str;
+
+    #[inline]
+    fn checked_target(&self) -> &str
+    {
+        self
+    }
+}
+
+impl GetCheckedMut<u8> for str
+{
+    #[inline]
+    fn checked_target_mut(&mut self) -> &mut str
+    {
+        self
+    }
+}
+
+/// Indexes the contents of a `String` by byte ranges, as for `str`, so generic code bounded on
+/// [`GetChecked`] accepts a `String` directly.
+///
+/// Only available when the `no_std` feature is disabled.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{GetChecked, GetCheckedSliceIndex};
+/// fn head<S>(s: &S) -> Option<&str>
+/// where
+///     S: GetChecked<u8> + ?Sized,
+///     std::ops::RangeTo<usize>: GetCheckedSliceIndex<S::Target, Output = str>,
+/// {
+///     s.get_checked(..2).ok()
+/// }
+///
+/// assert_eq!(head(&String::from("héllo")), None);
+/// assert_eq!(head(&String::from("hello")), Some("he"));
+/// ```
+#[cfg(not(feature = "no_std"))]
+impl GetChecked<u8> for String
+{
+    type Target = str;
+
+    #[inline]
+    fn checked_target(&self) -> &str
+    {
+        self
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl GetCheckedMut<u8> for String
+{
+    #[inline]
+    fn checked_target_mut(&mut self) -> &mut str
+    {
+        self
+    }
+}
+
+macro_rules! impl_get_checked_str {
+    ($($range:ty),* $(,)?) => {$(
+        /// Range bounds are validated as for `[u8]` and then checked to lie on char boundaries,
+        /// reporting a bound that does not with kind `CharBoundary`.
+        impl GetCheckedSliceIndex<str> for $range
+        {
+            type Output = str;
+
+            #[inline]
+            fn get_checked(self, s: &str) -> Result<&str, IndexError>
+            {
+                let range = str_range(s, self)?;
+                Ok(unsafe { s.get_unchecked(range) })
+            }
+
+            #[inline]
+            fn get_checked_mut(self, s: &mut str) -> Result<&mut str, IndexError>
+            {
+                let range = str_range(s, self)?;
+                Ok(unsafe { s.get_unchecked_mut(range) })
+            }
+        }
+    )*};
+}
+
+impl_get_checked_str! {
+    ops::Range<usize>,
+    ops::RangeTo<usize>,
+    ops::RangeFrom<usize>,
+    ops::RangeFull,
+    ops::RangeInclusive<usize>,
+    ops::RangeToInclusive<usize>,
+}
+
+/// Validates `range` against `s` and returns it as an exclusive range whose bounds lie on char
+/// boundaries.
+#[inline]
+fn str_range<R>(s: &str, range: R) -> Result<ops::Range<usize>, IndexError>
+where R: ops::RangeBounds<usize>
+{
+    let range = try_range(range, s.len())?;
+    match range
+    {
+        | _ if !s.is_char_boundary(range.start) => Err(Error::new(CharBoundary(range.start))),
+        | _ if !s.is_char_boundary(range.end) => Err(Error::new(CharBoundary(range.end))),
+        | _ => Ok(range),
+    }
+}
+
+/// Returns `byte_idx` if it lies on a char boundary of `s`.
+fn check_boundary(s: &str, byte_idx: usize) -> Result<usize, IndexError>
+{
+    match byte_idx
+    {
+        | _ if byte_idx > s.len() => Err(Error::new(Bounds(byte_idx, s.len()))),
+        | _ if !s.is_char_boundary(byte_idx) => Err(Error::new(CharBoundary(byte_idx))),
+        | _ => Ok(byte_idx),
+    }
+}
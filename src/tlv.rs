@@ -0,0 +1,146 @@
+use core::{convert::TryFrom, iter::FusedIterator};
+
+use crate::{Endian, Error, GetChecked, IndexError, IndexErrorKind::EndOverflow, LenPrefix};
+
+/// Layout of the header preceding each value of a tag-length-value encoding.
+///
+/// The header is a tag of width `tag` followed by a length of width `len`, both in the byte order
+/// `endian`. The length counts the bytes of the value that follows the header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TlvConfig
+{
+    /// Width of the tag.
+    pub tag:    LenPrefix,
+    /// Width of the length.
+    pub len:    LenPrefix,
+    /// Byte order of the tag and length.
+    pub endian: Endian,
+}
+
+impl TlvConfig
+{
+    /// Creates a layout with the given tag and length widths and byte order.
+    #[inline]
+    pub const fn new(tag: LenPrefix, len: LenPrefix, endian: Endian) -> Self
+    {
+        TlvConfig { tag, len, endian }
+    }
+}
+
+/// Trait adding checked iteration over tag-length-value records to byte slices.
+pub trait GetTlvChecked
+{
+    /// Returns an iterator over the consecutive tag-length-value records of the slice, laid out
+    /// as described by `config`, yielding the tag and value of each.
+    ///
+    /// The iterator ends after the last record that ends exactly at the end of the slice, or
+    /// after the first error.
+    ///
+    /// # Errors
+    ///
+    /// Each item is an [`IndexError`] of kind `EndRange` containing the end of the truncated
+    /// tag, length or value and the slice length if the record is truncated, in which case the
+    /// range of the error is that of the truncated field, or of kind `EndOverflow` if the end of
+    /// the value overflows.
+    ///
+    /// # Examples
+    /// ```
+    /// # use get_checked::{Endian, GetTlvChecked, IndexErrorKind, LenPrefix, TlvConfig};
+    /// let config = TlvConfig::new(LenPrefix::U8, LenPrefix::U16, Endian::Big);
+    /// let input = [0x01, 0x00, 0x02, b'h', b'i', 0x02, 0x00, 0x00, 0x03, 0x00, 0x04, b'!'];
+    /// let mut records = input.iter_tlv_checked(config);
+    ///
+    /// assert_eq!(records.next(), Some(Ok((1, &b"hi"[..]))));
+    /// assert_eq!(records.next(), Some(Ok((2, &b""[..]))));
+    /// assert_eq!(*records.next().unwrap().unwrap_err().kind(), IndexErrorKind::EndRange(15, 12));
+    /// assert_eq!(records.next(), None);
+    /// ```
+    fn iter_tlv_checked(&self, config: TlvConfig) -> TlvRecords<'_>;
+}
+
+impl GetTlvChecked for [u8]
+{
+    #[inline]
+    fn iter_tlv_checked(&self, config: TlvConfig) -> TlvRecords<'_>
+    {
+        TlvRecords { bytes: self, config, offset: Some(0) }
+    }
+}
+
+/// An iterator over the tag-length-value records of a byte slice.
+///
+/// This struct is created by [`GetTlvChecked::iter_tlv_checked`].
+#[derive(Debug, Clone)]
+pub struct TlvRecords<'a>
+{
+    bytes:  &'a [u8],
+    config: TlvConfig,
+    offset: Option<usize>,
+}
+
+impl<'a> TlvRecords<'a>
+{
+    /// Returns the offset of the next record, or `None` once an error has been returned.
+    #[inline]
+    pub fn offset(&self) -> Option<usize>
+    {
+        self.offset
+    }
+
+    /// Reads the record at `offset`, returning its tag, its value and the offset past it.
+    fn read(&self, offset: usize) -> Result<(u64, &'a [u8], usize), IndexError>
+    {
+        let TlvConfig { tag, len, endian } = self.config;
+        let (tag, offset) = read_uint(self.bytes, offset, tag, endian)?;
+        let (len, offset) = read_uint(self.bytes, offset, len, endian)?;
+        let end = usize::try_from(len)
+            .ok()
+            .and_then(|len| offset.checked_add(len))
+            .ok_or(Error::new(EndOverflow()))?;
+        Ok((tag, self.bytes.get_checked(offset..end)?, end))
+    }
+}
+
+impl<'a> Iterator for TlvRecords<'a>
+{
+    type Item = Result<(u64, &'a [u8]), IndexError>;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        let offset = self.offset.filter(|offset| *offset < self.bytes.len())?;
+        match self.read(offset)
+        {
+            | Ok((tag, value, end)) =>
+            {
+                self.offset = Some(end);
+                Some(Ok((tag, value)))
+            },
+            | Err(err) =>
+            {
+                self.offset = None;
+                Some(Err(err))
+            },
+        }
+    }
+}
+
+impl FusedIterator for TlvRecords<'_> {}
+
+/// Reads the `width`-wide unsigned integer at `offset` in the byte order `endian`, returning it
+/// and the offset past it.
+fn read_uint(
+    bytes: &[u8],
+    offset: usize,
+    width: LenPrefix,
+    endian: Endian,
+) -> Result<(u64, usize), IndexError>
+{
+    let end = offset.checked_add(width.size()).ok_or(Error::new(EndOverflow()))?;
+    let field = bytes.get_checked(offset..end)?;
+    let fold = |acc: u64, b: &u8| acc << 8 | u64::from(*b);
+    Ok(match endian
+    {
+        | Endian::Little => (field.iter().rev().fold(0, fold), end),
+        | Endian::Big => (field.iter().fold(0, fold), end),
+    })
+}
@@ -0,0 +1,224 @@
+use core::{
+    fmt,
+    ops::{Bound, Range, RangeBounds},
+};
+
+use crate::{Error, IndexError, IndexErrorKind::Bounds, try_range};
+
+/// A checked access as requested of a [`Traced`] view.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Access
+{
+    /// Access to the element at an index.
+    Index(usize),
+    /// Access to the subslice between a pair of range bounds.
+    Range(Bound<usize>, Bound<usize>),
+}
+
+/// A record of a single checked access made through a [`Traced`] view.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessRecord
+{
+    /// The requested access.
+    pub access: Access,
+    /// The range of elements returned by the access, or the error it failed with.
+    pub result: Result<Range<usize>, IndexError>,
+}
+
+/// A slice view that reports every checked access made through it, successful or not, to a
+/// caller-provided sink, so the accesses a parser performed can be replayed when triaging bad
+/// input.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{Access, Traced};
+/// use std::ops::Bound;
+///
+/// let input = [3, 1, 2];
+/// let mut log = Vec::new();
+/// {
+///     let mut sink = |record| log.push(record);
+///     let mut view = Traced::new(&input, &mut sink);
+///     let len = *view.get_checked(0).unwrap();
+///     assert!(view.get_range_checked(1..1 + len).is_err());
+/// }
+///
+/// assert_eq!(log[0].access, Access::Index(0));
+/// assert_eq!(log[0].result, Ok(0..1));
+/// assert_eq!(log[1].access, Access::Range(Bound::Included(1), Bound::Excluded(4)));
+/// assert_eq!(
+///     log[1].result.as_ref().unwrap_err().to_string(),
+///     "range end index 4 out of range for slice of length 3"
+/// );
+/// ```
+pub struct Traced<'a, T>
+{
+    slice: &'a [T],
+    sink:  &'a mut dyn FnMut(AccessRecord),
+}
+
+impl<T: fmt::Debug> fmt::Debug for Traced<'_, T>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        f.debug_struct("Traced").field("slice", &self.slice).finish_non_exhaustive()
+    }
+}
+
+impl<'a, T> Traced<'a, T>
+{
+    /// Wraps `slice`, reporting each access to `sink`.
+    #[inline]
+    pub fn new(slice: &'a [T], sink: &'a mut dyn FnMut(AccessRecord)) -> Self
+    {
+        Traced { slice, sink }
+    }
+
+    /// Returns the number of elements in the slice.
+    #[inline]
+    pub fn len(&self) -> usize
+    {
+        self.slice.len()
+    }
+
+    /// Returns `true` if the slice contains no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool
+    {
+        self.slice.is_empty()
+    }
+
+    /// Returns a reference to the element at `index`, reporting the access.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Bounds` if `index` is out of bounds.
+    pub fn get_checked(&mut self, index: usize) -> Result<&'a T, IndexError>
+    {
+        let slice = self.slice;
+        let result = match index
+        {
+            | _ if index < slice.len() => Ok(index..index + 1),
+            | _ => Err(Error::new(Bounds(index, slice.len()))),
+        };
+        self.record(Access::Index(index), result).map(|_| &slice[index])
+    }
+
+    /// Returns the subslice corresponding to `range`, reporting the access.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] describing the invalid range as
+    /// [`get_checked`](crate::GetChecked::get_checked) would.
+    pub fn get_range_checked<R>(&mut self, range: R) -> Result<&'a [T], IndexError>
+    where R: RangeBounds<usize>
+    {
+        let slice = self.slice;
+        let access = Access::Range(range.start_bound().cloned(), range.end_bound().cloned());
+        let result = try_range(range, slice.len());
+        self.record(access, result).map(|range| &slice[range])
+    }
+
+    /// Reports `access` and its result to the sink, returning the result.
+    fn record(
+        &mut self,
+        access: Access,
+        result: Result<Range<usize>, IndexError>,
+    ) -> Result<Range<usize>, IndexError>
+    {
+        (self.sink)(AccessRecord { access, result: result.clone() });
+        result
+    }
+}
+
+/// A sink adapter for [`Traced`] that forwards every successful access but only one in every
+/// `every` failed accesses, so that a bug repeatedly making the same out-of-bounds access in a
+/// hot loop does not flood the log.
+///
+/// The first failure is always forwarded. The failures dropped in between are counted and can be
+/// read with [`suppressed`](Self::suppressed), e.g. to log how many were skipped.
+///
+/// The crate has no global error hook; a [`Traced`] sink is the only place where failed
+/// accesses are reported as they happen, so this is what `Debounced` rate-limits.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{Debounced, Traced};
+/// let input = [1, 2, 3];
+/// let mut errors = Vec::new();
+/// let mut debounced = Debounced::new(100, |record: get_checked::AccessRecord| {
+///     if let Err(err) = record.result
+///     {
+///         errors.push(err.to_string());
+///     }
+/// });
+/// {
+///     let mut sink = |record| debounced.report(record);
+///     let mut view = Traced::new(&input, &mut sink);
+///     for _ in 0..250
+///     {
+///         assert!(view.get_checked(3).is_err());
+///     }
+/// }
+///
+/// assert_eq!((debounced.failures(), debounced.suppressed()), (250, 247));
+/// assert_eq!(errors.len(), 3);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Debounced<F>
+{
+    sink:       F,
+    every:      usize,
+    failures:   usize,
+    suppressed: usize,
+}
+
+impl<F> Debounced<F>
+where F: FnMut(AccessRecord)
+{
+    /// Wraps `sink`, forwarding the first failed access and every `every`th one after it. An
+    /// `every` of zero is treated as one, forwarding every failure.
+    #[inline]
+    pub fn new(every: usize, sink: F) -> Self
+    {
+        Debounced { sink, every: every.max(1), failures: 0, suppressed: 0 }
+    }
+
+    /// Forwards `record` to the wrapped sink if it is a success or a sampled failure, and
+    /// otherwise counts it as suppressed.
+    pub fn report(&mut self, record: AccessRecord)
+    {
+        if record.result.is_err()
+        {
+            self.failures += 1;
+            #[allow(clippy::manual_is_multiple_of)]
+            if (self.failures - 1) % self.every != 0
+            {
+                self.suppressed += 1;
+                return;
+            }
+        }
+        (self.sink)(record);
+    }
+
+    /// Returns the number of failed accesses reported so far, forwarded or not.
+    #[inline]
+    pub fn failures(&self) -> usize
+    {
+        self.failures
+    }
+
+    /// Returns the number of failed accesses that were not forwarded.
+    #[inline]
+    pub fn suppressed(&self) -> usize
+    {
+        self.suppressed
+    }
+
+    /// Consumes the adapter, returning the wrapped sink.
+    #[inline]
+    pub fn into_inner(self) -> F
+    {
+        self.sink
+    }
+}
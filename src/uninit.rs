@@ -0,0 +1,220 @@
+use core::{mem::MaybeUninit, ops::RangeBounds};
+
+use crate::{Error, GetChecked, GetCheckedMut, IndexError, IndexErrorKind::Bounds, try_range};
+
+/// Trait adding checked initialization and access to slices of [`MaybeUninit<T>`].
+///
+/// The slots themselves are indexed by the [`GetChecked`] implementation for slices. These
+/// methods check bounds before any slot is written or assumed to be initialized, but do not
+/// track which slots are initialized; see [`PartialInit`] for a view that does.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{GetCheckedUninit, IndexErrorKind};
+/// use std::mem::MaybeUninit;
+///
+/// let mut buf = [MaybeUninit::<u16>::uninit(); 4];
+/// buf.write_checked(1, 7).unwrap();
+/// buf.write_checked(2, 9).unwrap();
+///
+/// // Slots 1 and 2 were written above.
+/// assert_eq!(unsafe { buf.get_init_range_checked(1..3) }, Ok(&[7, 9][..]));
+///
+/// let err = buf.write_checked(4, 0).unwrap_err();
+/// assert_eq!(*err.kind(), IndexErrorKind::Bounds(4, 4));
+/// ```
+pub trait GetCheckedUninit<T>
+{
+    /// Initializes the slot at `index` with `value`, returning a mutable reference to it.
+    ///
+    /// Any value previously in the slot is overwritten without being dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Bounds` if `index` is out of bounds, in which case
+    /// `value` is dropped.
+    fn write_checked(&mut self, index: usize, value: T) -> Result<&mut T, IndexError>;
+
+    /// Returns the slots within `range` as a slice of initialized values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] describing the invalid range as
+    /// [`get_checked`](crate::GetChecked::get_checked) would.
+    ///
+    /// # Safety
+    ///
+    /// Every slot within `range` must be initialized if `range` is valid.
+    unsafe fn get_init_range_checked<R>(&self, range: R) -> Result<&[T], IndexError>
+    where R: RangeBounds<usize>;
+
+    /// Returns the slots within `range` as a mutable slice of initialized values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] describing the invalid range as
+    /// [`get_checked`](crate::GetChecked::get_checked) would.
+    ///
+    /// # Safety
+    ///
+    /// Every slot within `range` must be initialized if `range` is valid.
+    unsafe fn get_init_range_checked_mut<R>(&mut self, range: R) -> Result<&mut [T], IndexError>
+    where R: RangeBounds<usize>;
+}
+
+impl<T> GetCheckedUninit<T> for [MaybeUninit<T>]
+{
+    #[inline]
+    fn write_checked(&mut self, index: usize, value: T) -> Result<&mut T, IndexError>
+    {
+        Ok(self.get_checked_mut(index)?.write(value))
+    }
+
+    #[inline]
+    unsafe fn get_init_range_checked<R>(&self, range: R) -> Result<&[T], IndexError>
+    where R: RangeBounds<usize>
+    {
+        let slots = &self[try_range(range, self.len())?];
+        Ok(unsafe { &*(slots as *const [MaybeUninit<T>] as *const [T]) })
+    }
+
+    #[inline]
+    unsafe fn get_init_range_checked_mut<R>(&mut self, range: R) -> Result<&mut [T], IndexError>
+    where R: RangeBounds<usize>
+    {
+        let range = try_range(range, self.len())?;
+        let slots = &mut self[range];
+        Ok(unsafe { &mut *(slots as *mut [MaybeUninit<T>] as *mut [T]) })
+    }
+}
+
+/// A view over a buffer of [`MaybeUninit<T>`] whose initialized prefix is tracked by a
+/// watermark.
+///
+/// Checked indexing through [`GetChecked`] only grants access to the initialized prefix, and
+/// reports [`Bounds`](crate::IndexErrorKind::Bounds) errors against the watermark rather than
+/// the capacity of the buffer.
+///
+/// Initialized elements are not dropped when the view is dropped, consistent with
+/// [`MaybeUninit`].
+///
+/// # Examples
+/// ```
+/// # use get_checked::{GetChecked, IndexErrorKind, PartialInit};
+/// use std::mem::MaybeUninit;
+///
+/// let mut buf = [MaybeUninit::<u8>::uninit(); 4];
+/// let mut init = PartialInit::new(&mut buf);
+///
+/// init.push_checked(7).unwrap();
+/// init.push_checked(9).unwrap();
+///
+/// assert_eq!(init.get_checked(1), Ok(&9));
+/// assert_eq!(*init.get_checked(2).unwrap_err().kind(), IndexErrorKind::Bounds(2, 2));
+/// ```
+#[derive(Debug)]
+pub struct PartialInit<'a, T>
+{
+    buf:  &'a mut [MaybeUninit<T>],
+    init: usize,
+}
+
+impl<'a, T> PartialInit<'a, T>
+{
+    /// Creates a view over `buf` with no initialized elements.
+    #[inline]
+    pub fn new(buf: &'a mut [MaybeUninit<T>]) -> Self
+    {
+        PartialInit { buf, init: 0 }
+    }
+
+    /// Creates a view over `buf` whose first `init` elements are initialized.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Bounds` if `init` exceeds the length of `buf`.
+    ///
+    /// # Safety
+    ///
+    /// The first `init` elements of `buf` must be initialized.
+    #[inline]
+    pub unsafe fn with_init(buf: &'a mut [MaybeUninit<T>], init: usize)
+    -> Result<Self, IndexError>
+    {
+        match init
+        {
+            | _ if init > buf.len() => Err(Error::new(Bounds(init, buf.len()))),
+            | _ => Ok(PartialInit { buf, init }),
+        }
+    }
+
+    /// Returns the number of initialized elements.
+    #[inline]
+    pub fn len(&self) -> usize
+    {
+        self.init
+    }
+
+    /// Returns `true` if no elements are initialized.
+    #[inline]
+    pub fn is_empty(&self) -> bool
+    {
+        self.init == 0
+    }
+
+    /// Returns the total number of elements in the underlying buffer.
+    #[inline]
+    pub fn capacity(&self) -> usize
+    {
+        self.buf.len()
+    }
+
+    /// Initializes the element at the watermark with `value` and advances the watermark,
+    /// returning a mutable reference to the written element.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Bounds` if the buffer is full.
+    #[inline]
+    pub fn push_checked(&mut self, value: T) -> Result<&mut T, IndexError>
+    {
+        let len = self.buf.len();
+        let slot = self.buf.get_mut(self.init).ok_or(Error::new(Bounds(self.init, len)))?;
+        self.init += 1;
+        Ok(slot.write(value))
+    }
+
+    /// Returns the initialized prefix as a slice.
+    #[inline]
+    pub fn as_slice(&self) -> &[T]
+    {
+        unsafe { &*(&self.buf[..self.init] as *const [MaybeUninit<T>] as *const [T]) }
+    }
+
+    /// Returns the initialized prefix as a mutable slice.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T]
+    {
+        unsafe { &mut *(&mut self.buf[..self.init] as *mut [MaybeUninit<T>] as *mut [T]) }
+    }
+}
+
+impl<T> GetChecked<T> for PartialInit<'_, T>
+{
+    type Target = [T];
+
+    #[inline]
+    fn checked_target(&self) -> &[T]
+    {
+        self.as_slice()
+    }
+}
+
+impl<T> GetCheckedMut<T> for PartialInit<'_, T>
+{
+    #[inline]
+    fn checked_target_mut(&mut self) -> &mut [T]
+    {
+        self.as_mut_slice()
+    }
+}
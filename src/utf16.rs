@@ -0,0 +1,48 @@
+use core::ops::RangeBounds;
+
+use crate::{Error, IndexError, IndexErrorKind::SurrogateBoundary, try_range};
+
+/// Trait adding surrogate-aware checked slicing to UTF-16 code units.
+pub trait GetUtf16Checked
+{
+    /// Returns the code units within `range`, ensuring that neither end of the range splits a
+    /// surrogate pair.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] describing the invalid range as
+    /// [`get_checked`](crate::GetChecked::get_checked) would, or of kind `SurrogateBoundary`
+    /// containing the offending index if the range splits a surrogate pair.
+    ///
+    /// # Examples
+    /// ```
+    /// # use get_checked::{GetUtf16Checked, IndexErrorKind};
+    /// let v: Vec<u16> = "a😀b".encode_utf16().collect();
+    /// assert_eq!(v.get_utf16_checked(1..3), Ok(&v[1..3]));
+    /// assert_eq!(*v.get_utf16_checked(2..).unwrap_err().kind(), IndexErrorKind::SurrogateBoundary(2));
+    /// ```
+    fn get_utf16_checked<R>(&self, range: R) -> Result<&[u16], IndexError>
+    where R: RangeBounds<usize>;
+}
+
+impl GetUtf16Checked for [u16]
+{
+    fn get_utf16_checked<R>(&self, range: R) -> Result<&[u16], IndexError>
+    where R: RangeBounds<usize>
+    {
+        let range = try_range(range, self.len())?;
+
+        let splits = |i: usize| match (i.checked_sub(1).map(|p| self[p]), self.get(i))
+        {
+            | (Some(0xD800..=0xDBFF), Some(0xDC00..=0xDFFF)) => true,
+            | _ => false,
+        };
+
+        match range
+        {
+            | _ if splits(range.start) => Err(Error::new(SurrogateBoundary(range.start))),
+            | _ if splits(range.end) => Err(Error::new(SurrogateBoundary(range.end))),
+            | _ => Ok(&self[range]),
+        }
+    }
+}
@@ -0,0 +1,365 @@
+use core::{
+    fmt, mem,
+    ops::{Range, RangeBounds},
+};
+use std::{error, vec::Splice};
+
+use crate::{
+    Error, GetChecked, IndexError,
+    IndexErrorKind::{CapacityOverflow, EndOverflow},
+    try_range,
+};
+
+/// Outcome of [`GetCheckedVec::get_or_grow_mut`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Growth
+{
+    /// The index was in bounds and the existing element was returned.
+    Existing,
+
+    /// The vector was grown to contain the index.
+    /// * `0` - length of the vector before growing.
+    Grown(usize),
+}
+
+/// Trait adding checked mutation operations to [`Vec`].
+///
+/// Only available when the `no_std` feature is disabled.
+pub trait GetCheckedVec<T>
+{
+    /// Returns a mutable reference to the element at `index`, growing the vector with default
+    /// (e.g., zero) values up to and including `index` if it is out of bounds.
+    ///
+    /// The returned [`Growth`] reports whether the vector was grown.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `EndOverflow` if `index` is [`usize::MAX`], or
+    /// `CapacityOverflow` if growing the vector would exceed the maximum length for `T`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use get_checked::{GetCheckedVec, Growth};
+    /// let mut v = vec![1u8, 2];
+    ///
+    /// let (elem, growth) = v.get_or_grow_mut(4).unwrap();
+    /// *elem = 5;
+    /// assert_eq!(growth, Growth::Grown(2));
+    /// assert_eq!(v, [1, 2, 0, 0, 5]);
+    ///
+    /// assert_eq!(v.get_or_grow_mut(0).unwrap().1, Growth::Existing);
+    /// ```
+    fn get_or_grow_mut(&mut self, index: usize) -> Result<(&mut T, Growth), IndexError>
+    where T: Default;
+
+    /// Inserts clones of the elements of `slice` at position `index`, shifting all elements
+    /// after it to the right.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `StartRange` if `index` is greater than the length of the
+    /// vector, or `CapacityOverflow` if the new length would exceed the maximum length for `T`,
+    /// in which case the vector is left unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// # use get_checked::GetCheckedVec;
+    /// let mut v = vec![1, 4];
+    /// v.insert_slice_checked(1, &[2, 3]).unwrap();
+    /// assert_eq!(v, [1, 2, 3, 4]);
+    /// assert!(v.insert_slice_checked(5, &[5]).is_err());
+    /// ```
+    fn insert_slice_checked(&mut self, index: usize, slice: &[T]) -> Result<(), IndexError>
+    where T: Clone;
+
+    /// Copies the elements within `range` to the end of the vector.
+    ///
+    /// This is a checked version of [`Vec::extend_from_within`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] describing the invalid range as
+    /// [`get_checked`](crate::GetChecked::get_checked) would, or of kind `CapacityOverflow` if
+    /// the new length would exceed the maximum length for `T`, in which case the vector is left
+    /// unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// # use get_checked::GetCheckedVec;
+    /// let mut v = vec![1, 2, 3];
+    /// v.extend_from_within_checked(..2).unwrap();
+    /// assert_eq!(v, [1, 2, 3, 1, 2]);
+    /// assert!(v.extend_from_within_checked(4..6).is_err());
+    /// ```
+    fn extend_from_within_checked<R>(&mut self, range: R) -> Result<(), IndexError>
+    where
+        R: RangeBounds<usize>,
+        T: Clone;
+
+    /// Creates a splicing iterator that replaces the elements within `range` with the elements
+    /// of `replace_with`.
+    ///
+    /// This is a checked version of [`Vec::splice`] that validates `range` before any element is
+    /// removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] describing the invalid range as
+    /// [`get_checked`](crate::GetChecked::get_checked) would, in which case the vector is left
+    /// unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// # use get_checked::GetCheckedVec;
+    /// let mut v = vec![1, 2, 3, 4];
+    /// let removed: Vec<_> = v.splice_checked(1..3, [7, 8, 9]).unwrap().collect();
+    /// assert_eq!(removed, [2, 3]);
+    /// assert_eq!(v, [1, 7, 8, 9, 4]);
+    /// assert!(v.splice_checked(4..6, []).is_err());
+    /// ```
+    fn splice_checked<R, I>(
+        &mut self,
+        range: R,
+        replace_with: I,
+    ) -> Result<Splice<'_, I::IntoIter>, IndexError>
+    where
+        R: RangeBounds<usize>,
+        I: IntoIterator<Item = T>;
+}
+
+impl<T> GetCheckedVec<T> for Vec<T>
+{
+    fn get_or_grow_mut(&mut self, index: usize) -> Result<(&mut T, Growth), IndexError>
+    where T: Default
+    {
+        let len = self.len();
+        let growth = match index
+        {
+            | _ if index < len => Growth::Existing,
+            | _ =>
+            {
+                let new_len = index.checked_add(1).ok_or(Error::new(EndOverflow()))?;
+                check_capacity::<T>(len, new_len - len)?;
+                self.resize_with(new_len, T::default);
+                Growth::Grown(len)
+            },
+        };
+        Ok((self.get_checked_mut(index)?, growth))
+    }
+
+    fn insert_slice_checked(&mut self, index: usize, slice: &[T]) -> Result<(), IndexError>
+    where T: Clone
+    {
+        let index = try_range(index..index, self.len())?.start;
+        check_capacity::<T>(self.len(), slice.len())?;
+        self.splice(index..index, slice.iter().cloned());
+        Ok(())
+    }
+
+    fn extend_from_within_checked<R>(&mut self, range: R) -> Result<(), IndexError>
+    where
+        R: RangeBounds<usize>,
+        T: Clone,
+    {
+        let range = try_range(range, self.len())?;
+        check_capacity::<T>(self.len(), range.len())?;
+        self.extend_from_within(range);
+        Ok(())
+    }
+
+    fn splice_checked<R, I>(
+        &mut self,
+        range: R,
+        replace_with: I,
+    ) -> Result<Splice<'_, I::IntoIter>, IndexError>
+    where
+        R: RangeBounds<usize>,
+        I: IntoIterator<Item = T>,
+    {
+        let range = try_range(range, self.len())?;
+        Ok(self.splice(range, replace_with))
+    }
+}
+
+/// An error returned by a [`VecWriter`], distinguishing a write over existing elements from an
+/// append.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WriteError
+{
+    /// A positional write did not lie within the elements written so far.
+    Positional(IndexError),
+
+    /// An append would have grown the vector past its maximum length.
+    Append(IndexError),
+}
+
+impl WriteError
+{
+    /// Returns the underlying index error.
+    pub fn index_error(&self) -> &IndexError
+    {
+        match self
+        {
+            | WriteError::Positional(err) | WriteError::Append(err) => err,
+        }
+    }
+}
+
+impl fmt::Display for WriteError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            | WriteError::Positional(err) => write!(f, "positional write failed: {}", err),
+            | WriteError::Append(err) => write!(f, "append failed: {}", err),
+        }
+    }
+}
+
+impl error::Error for WriteError
+{
+    fn source(&self) -> Option<&(dyn error::Error + 'static)>
+    {
+        Some(self.index_error())
+    }
+}
+
+/// A writer that appends to a [`Vec`] and can go back to overwrite what it already wrote, for
+/// encoders that fill in a header once the length of the body is known.
+///
+/// Appends grow the vector, while positional writes must lie within its current length, so a
+/// patch can never silently extend the output.
+///
+/// Only available when the `no_std` feature is disabled.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{VecWriter, WriteError};
+/// let mut out = Vec::new();
+/// let mut w = VecWriter::new(&mut out);
+///
+/// let header = w.append_default_checked(2).unwrap();
+/// w.append_checked(b"hello").unwrap();
+/// let len = (w.len() - header.end) as u16;
+/// w.write_at_checked(header.start, &len.to_be_bytes()).unwrap();
+/// assert_eq!(out, b"\x00\x05hello");
+///
+/// let mut w = VecWriter::new(&mut out);
+/// let err = w.write_at_checked(6, b"!!").unwrap_err();
+/// assert!(matches!(err, WriteError::Positional(_)));
+/// assert_eq!(
+///     err.to_string(),
+///     "positional write failed: range end index 8 out of range for slice of length 7"
+/// );
+/// ```
+#[derive(Debug)]
+pub struct VecWriter<'a, T>
+{
+    vec: &'a mut Vec<T>,
+}
+
+impl<'a, T> VecWriter<'a, T>
+{
+    /// Creates a writer appending to `vec`, after any elements it already holds.
+    #[inline]
+    pub fn new(vec: &'a mut Vec<T>) -> Self
+    {
+        VecWriter { vec }
+    }
+
+    /// Returns the number of elements in the vector, which is the offset of the next append.
+    #[inline]
+    pub fn len(&self) -> usize
+    {
+        self.vec.len()
+    }
+
+    /// Returns `true` if the vector contains no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool
+    {
+        self.vec.is_empty()
+    }
+
+    /// Returns the elements written so far.
+    #[inline]
+    pub fn as_slice(&self) -> &[T]
+    {
+        self.vec
+    }
+
+    /// Appends clones of the elements of `src`, returning the range they were written to.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WriteError::Append`] with an [`IndexError`] of kind `CapacityOverflow` if the
+    /// new length would exceed the maximum length for `T`, in which case the vector is left
+    /// unchanged.
+    pub fn append_checked(&mut self, src: &[T]) -> Result<Range<usize>, WriteError>
+    where T: Clone
+    {
+        let start = self.grow(src.len())?;
+        self.vec.extend_from_slice(src);
+        Ok(start..self.vec.len())
+    }
+
+    /// Appends `n` default (e.g., zero) elements as a placeholder to be overwritten later,
+    /// returning the range they were written to.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WriteError::Append`] with an [`IndexError`] of kind `CapacityOverflow` if the
+    /// new length would exceed the maximum length for `T`, in which case the vector is left
+    /// unchanged.
+    pub fn append_default_checked(&mut self, n: usize) -> Result<Range<usize>, WriteError>
+    where T: Default
+    {
+        let start = self.grow(n)?;
+        self.vec.resize_with(start + n, T::default);
+        Ok(start..self.vec.len())
+    }
+
+    /// Overwrites the elements starting at `offset` with clones of the elements of `src`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WriteError::Positional`] with an [`IndexError`] of kind `EndOverflow` if the end
+    /// of the write overflows, or describing the range `offset..offset + src.len()` as
+    /// [`get_checked`](crate::GetChecked::get_checked) would if it does not lie within the
+    /// vector, in which case the vector is left unchanged.
+    pub fn write_at_checked(&mut self, offset: usize, src: &[T]) -> Result<(), WriteError>
+    where T: Clone
+    {
+        let end = offset
+            .checked_add(src.len())
+            .ok_or(WriteError::Positional(Error::new(EndOverflow())))?;
+        let dst = self.vec.get_checked_mut(offset..end).map_err(WriteError::Positional)?;
+        dst.clone_from_slice(src);
+        Ok(())
+    }
+
+    /// Checks that the vector can grow by `n` elements, returning its current length.
+    fn grow(&self, n: usize) -> Result<usize, WriteError>
+    {
+        check_capacity::<T>(self.vec.len(), n).map_err(WriteError::Append)?;
+        Ok(self.vec.len())
+    }
+}
+
+/// Checks that a vector of `len` elements of `T` can grow by `additional` elements. [`Vec`]
+/// limits its allocation to `isize::MAX` bytes, and zero-sized types never allocate, so their
+/// vectors can hold up to [`usize::MAX`] elements.
+fn check_capacity<T>(len: usize, additional: usize) -> Result<(), IndexError>
+{
+    let max = match mem::size_of::<T>()
+    {
+        | 0 => usize::MAX,
+        | size => isize::MAX as usize / size,
+    };
+    match len.checked_add(additional)
+    {
+        | Some(new_len) if new_len <= max => Ok(()),
+        | _ => Err(Error::new(CapacityOverflow())),
+    }
+}
@@ -0,0 +1,296 @@
+use core::{
+    iter::{FusedIterator, StepBy},
+    slice,
+};
+
+use crate::{
+    Axis, Error, IndexError,
+    IndexErrorKind::{
+        AxisBounds, Bounds, CapacityOverflow, EndOverflow, EndRange, LengthMismatch,
+        StartUnderflow, ZeroChunk,
+    },
+};
+
+/// How [`GetWindowChecked::get_around_checked`] treats a window that extends past either end
+/// of the slice.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EdgePolicy
+{
+    /// Shrinks the window to the part that lies within the slice.
+    Clamp,
+
+    /// Reports an error if the window does not lie entirely within the slice.
+    Strict,
+}
+
+/// Trait adding validated strided windows to slices.
+///
+/// # Examples
+/// ```
+/// # use get_checked::{GetWindowChecked, IndexErrorKind};
+/// let v = [1, 2, 3, 4, 5, 6];
+/// let windows: Vec<_> = v.window_by_checked(1, 2, 2).unwrap().collect();
+/// assert_eq!(windows, [&[2, 3][..], &[4, 5][..]]);
+///
+/// let err = v.window_by_checked(5, 2, 1).unwrap_err();
+/// assert_eq!(*err.kind(), IndexErrorKind::EndRange(7, 6));
+/// ```
+pub trait GetWindowChecked<T>
+{
+    /// Returns an iterator over windows of `len` elements, the first starting at `start` and
+    /// each following one `stride` elements after the previous, for as long as a full window
+    /// fits.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `ZeroChunk` if `len` or `stride` is zero, or
+    /// describing the range `start..start + len` if the first window does not fit.
+    fn window_by_checked(
+        &self,
+        start: usize,
+        len: usize,
+        stride: usize,
+    ) -> Result<StridedWindows<'_, T>, IndexError>;
+
+    /// Returns the elements within `radius` of `center`, i.e. the inclusive range
+    /// `center - radius..=center + radius`, with `policy` deciding how a window that extends
+    /// past either end of the slice is handled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `Bounds` if `center` is out of bounds. With
+    /// [`EdgePolicy::Strict`], also returns `StartUnderflow` containing `center` and `radius` if
+    /// the window starts before the slice, `EndOverflow` if its end overflows, or `EndRange`
+    /// containing the exclusive end and the length if it ends after the slice.
+    ///
+    /// # Examples
+    /// ```
+    /// # use get_checked::{EdgePolicy, GetWindowChecked, IndexErrorKind};
+    /// let lines = ["a", "b", "c", "d", "e"];
+    /// assert_eq!(lines.get_around_checked(2, 1, EdgePolicy::Strict), Ok(&["b", "c", "d"][..]));
+    /// assert_eq!(lines.get_around_checked(0, 2, EdgePolicy::Clamp), Ok(&["a", "b", "c"][..]));
+    ///
+    /// let err = lines.get_around_checked(0, 2, EdgePolicy::Strict).unwrap_err();
+    /// assert_eq!(*err.kind(), IndexErrorKind::StartUnderflow(0, 2));
+    /// ```
+    fn get_around_checked(
+        &self,
+        center: usize,
+        radius: usize,
+        policy: EdgePolicy,
+    ) -> Result<&[T], IndexError>;
+
+    /// Returns an iterator over column `col` of row-major data with rows of `width` elements,
+    /// yielding one element per complete row. Trailing elements that do not form a complete row
+    /// are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `ZeroChunk` if `width` is zero, or `AxisBounds` with
+    /// [`Axis::X`] if `col` is not less than `width`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use get_checked::{Axis, GetWindowChecked, IndexErrorKind};
+    /// // 3x2 grid.
+    /// let grid = [1, 2, 3, 4, 5, 6];
+    /// assert!(grid.column_iter_checked(1, 3).unwrap().eq(&[2, 5]));
+    ///
+    /// let err = grid.column_iter_checked(3, 3).unwrap_err();
+    /// assert_eq!(*err.kind(), IndexErrorKind::AxisBounds(Axis::X, 3, 3));
+    /// ```
+    fn column_iter_checked(
+        &self,
+        col: usize,
+        width: usize,
+    ) -> Result<StepBy<slice::Iter<'_, T>>, IndexError>;
+
+    /// Returns an iterator over mutable references to column `col` of row-major data with rows
+    /// of `width` elements, yielding one element per complete row.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `ZeroChunk` if `width` is zero, or `AxisBounds` with
+    /// [`Axis::X`] if `col` is not less than `width`.
+    fn column_iter_checked_mut(
+        &mut self,
+        col: usize,
+        width: usize,
+    ) -> Result<StepBy<slice::IterMut<'_, T>>, IndexError>;
+
+    /// Copies row-major data with `height` rows of `width` elements into `dst` transposed, so
+    /// that `dst` holds `width` rows of `height` elements.
+    ///
+    /// `dst` is left untouched if an error is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] of kind `CapacityOverflow` if `width * height` overflows, or
+    /// `LengthMismatch` containing `width * height` and the length of whichever of the slice
+    /// and `dst` differs from it.
+    ///
+    /// # Examples
+    /// ```
+    /// # use get_checked::{GetWindowChecked, IndexErrorKind};
+    /// // 3x2 matrix.
+    /// let m = [1, 2, 3, 4, 5, 6];
+    /// let mut t = [0; 6];
+    /// m.transpose_into_checked(&mut t, 3, 2).unwrap();
+    /// assert_eq!(t, [1, 4, 2, 5, 3, 6]);
+    ///
+    /// let err = m.transpose_into_checked(&mut t[..4], 3, 2).unwrap_err();
+    /// assert_eq!(*err.kind(), IndexErrorKind::LengthMismatch(6, 4));
+    /// ```
+    fn transpose_into_checked(
+        &self,
+        dst: &mut [T],
+        width: usize,
+        height: usize,
+    ) -> Result<(), IndexError>
+    where
+        T: Clone;
+}
+
+impl<T> GetWindowChecked<T> for [T]
+{
+    fn window_by_checked(
+        &self,
+        start: usize,
+        len: usize,
+        stride: usize,
+    ) -> Result<StridedWindows<'_, T>, IndexError>
+    {
+        let end = start.checked_add(len).ok_or(Error::new(EndOverflow()))?;
+        match end
+        {
+            | _ if len == 0 || stride == 0 => Err(Error::new(ZeroChunk())),
+            | _ if end > self.len() =>
+            {
+                Err(Error::new(EndRange(end, self.len())).with_range(start, end, self.len()))
+            },
+            | _ => Ok(StridedWindows { slice: &self[start..], len, stride }),
+        }
+    }
+
+    fn get_around_checked(
+        &self,
+        center: usize,
+        radius: usize,
+        policy: EdgePolicy,
+    ) -> Result<&[T], IndexError>
+    {
+        let len = self.len();
+        if center >= len
+        {
+            return Err(Error::new(Bounds(center, len)));
+        }
+
+        let start = center.checked_sub(radius);
+        let end = center.checked_add(radius).and_then(|end| end.checked_add(1));
+        let range = match policy
+        {
+            | EdgePolicy::Clamp => start.unwrap_or(0)..end.map_or(len, |end| end.min(len)),
+            | EdgePolicy::Strict => match (start, end)
+            {
+                | (None, _) => return Err(Error::new(StartUnderflow(center, radius))),
+                | (_, None) => return Err(Error::new(EndOverflow())),
+                | (Some(start), Some(end)) if end > len =>
+                {
+                    return Err(Error::new(EndRange(end, len)).with_range(start, end, len));
+                },
+                | (Some(start), Some(end)) => start..end,
+            },
+        };
+        Ok(&self[range])
+    }
+
+    fn column_iter_checked(
+        &self,
+        col: usize,
+        width: usize,
+    ) -> Result<StepBy<slice::Iter<'_, T>>, IndexError>
+    {
+        let end = column_end(self.len(), col, width)?;
+        Ok(self[col.min(end)..end].iter().step_by(width))
+    }
+
+    fn column_iter_checked_mut(
+        &mut self,
+        col: usize,
+        width: usize,
+    ) -> Result<StepBy<slice::IterMut<'_, T>>, IndexError>
+    {
+        let end = column_end(self.len(), col, width)?;
+        Ok(self[col.min(end)..end].iter_mut().step_by(width))
+    }
+
+    fn transpose_into_checked(
+        &self,
+        dst: &mut [T],
+        width: usize,
+        height: usize,
+    ) -> Result<(), IndexError>
+    where
+        T: Clone,
+    {
+        let len = width.checked_mul(height).ok_or_else(|| Error::new(CapacityOverflow()))?;
+        if let Some(found) = [self.len(), dst.len()].iter().copied().find(|&found| found != len)
+        {
+            return Err(Error::new(LengthMismatch(len, found)));
+        }
+
+        for (i, value) in dst.iter_mut().enumerate()
+        {
+            *value = self[i % height * width + i / height].clone();
+        }
+        Ok(())
+    }
+}
+
+/// Validates a column and returns the end of the last complete row.
+fn column_end(len: usize, col: usize, width: usize) -> Result<usize, IndexError>
+{
+    match col
+    {
+        | _ if width == 0 => Err(Error::new(ZeroChunk())),
+        | _ if col >= width => Err(Error::new(AxisBounds(Axis::X, col, width))),
+        | _ => Ok(len - len % width),
+    }
+}
+
+/// An iterator over strided windows of a slice.
+///
+/// This struct is created by [`GetWindowChecked::window_by_checked`].
+#[derive(Debug, Clone)]
+pub struct StridedWindows<'a, T>
+{
+    slice:  &'a [T],
+    len:    usize,
+    stride: usize,
+}
+
+impl<'a, T> Iterator for StridedWindows<'a, T>
+{
+    type Item = &'a [T];
+
+    fn next(&mut self) -> Option<&'a [T]>
+    {
+        let window = self.slice.get(..self.len)?;
+        self.slice = self.slice.get(self.stride..).unwrap_or(&[]);
+        Some(window)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        let n = match self.slice.len()
+        {
+            | n if n < self.len => 0,
+            | n => (n - self.len) / self.stride + 1,
+        };
+        (n, Some(n))
+    }
+}
+
+impl<T> ExactSizeIterator for StridedWindows<'_, T> {}
+
+impl<T> FusedIterator for StridedWindows<'_, T> {}
@@ -0,0 +1,67 @@
+//! Checks that the `Display` output of every `IndexError` matches the message std panics with
+//! when indexing the same slice with the same index or range.
+#![cfg(not(feature = "no_std"))]
+
+use std::panic::{self, AssertUnwindSafe};
+
+use get_checked::GetChecked;
+
+const LENS: [usize; 4] = [0, 1, 3, 5];
+const POINTS: [usize; 9] = [0, 1, 2, 3, 4, 5, 6, usize::MAX - 1, usize::MAX];
+
+/// Returns the panic message produced by `f`, or `None` if it did not panic.
+fn panic_message<F: FnOnce()>(f: F) -> Option<String>
+{
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    panic::set_hook(hook);
+
+    let payload = result.err()?;
+    payload
+        .downcast_ref::<String>()
+        .cloned()
+        .or_else(|| payload.downcast_ref::<&str>().map(|s| s.to_string()))
+}
+
+/// Asserts that indexing with `index` panics if and only if `get_checked` and `get_checked_mut`
+/// fail, and that the panic message equals the error message.
+fn assert_parity<I>(len: usize, index: I)
+where I: std::slice::SliceIndex<[u8]>
+        + get_checked::GetCheckedSliceIndex<[u8]>
+        + Clone
+        + std::fmt::Debug
+{
+    let v = vec![0u8; len];
+    let expected = panic_message(|| {
+        let _ = &v[index.clone()];
+    });
+    let actual = v.get_checked(index.clone()).err().map(|e| e.to_string());
+    assert_eq!(actual, expected, "len {} index {:?}", len, index);
+
+    let actual = v.clone().get_checked_mut(index.clone()).err().map(|e| e.to_string());
+    assert_eq!(actual, expected, "len {} index {:?} (mut)", len, index);
+}
+
+#[test]
+fn panic_parity()
+{
+    for &len in &LENS
+    {
+        for &a in &POINTS
+        {
+            assert_parity(len, a);
+            assert_parity(len, ..a);
+            assert_parity(len, a..);
+            assert_parity(len, ..=a);
+
+            for &b in &POINTS
+            {
+                assert_parity(len, a..b);
+                assert_parity(len, a..=b);
+            }
+        }
+
+        assert_parity(len, ..);
+    }
+}
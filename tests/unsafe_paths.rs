@@ -0,0 +1,167 @@
+//! Exercises every `unsafe` block in the crate with element types and lengths chosen to hit its
+//! edges, so running the suite under Miri checks the crate's safety claims:
+//! ```sh
+//! cargo +nightly miri test --test unsafe_paths
+//! cargo +nightly miri test --test unsafe_paths --features edge_cases
+//! ```
+//! The `edge_cases` feature adds zero-sized slices of length `isize::MAX` and `usize::MAX` and
+//! a denser set of indices, which is too slow to run under Miri by default.
+#![cfg(not(feature = "no_std"))]
+
+use std::{fmt::Debug, mem::MaybeUninit, slice::SliceIndex};
+
+use get_checked::{
+    GetAsciiChecked, GetChecked, GetCheckedDebug, GetCheckedSliceIndex, GetCheckedUninit,
+    PartialInit,
+};
+
+#[cfg(not(feature = "edge_cases"))]
+const LENS: &[usize] = &[0, 1, 2];
+#[cfg(feature = "edge_cases")]
+const LENS: &[usize] = &[0, 1, 2, 3, 7, 8];
+
+#[cfg(not(feature = "edge_cases"))]
+const POINTS: &[usize] = &[0, 1, 2, 3, usize::MAX];
+#[cfg(feature = "edge_cases")]
+const POINTS: &[usize] = &[0, 1, 2, 3, 4, 7, 8, 9, isize::MAX as usize, usize::MAX - 1, usize::MAX];
+
+/// Checks that checked and unchecked access through `index` return the same element or subslice
+/// as std `get` on `v`.
+fn check<T, I>(v: &mut [T], index: I)
+where I: GetCheckedSliceIndex<[T]>
+        + SliceIndex<[T], Output = <I as GetCheckedSliceIndex<[T]>>::Output>
+        + Clone
+        + Debug
+{
+    let expected: Option<*const <I as SliceIndex<[T]>>::Output> =
+        v.get(index.clone()).map(|r| r as _);
+    assert_eq!(v.get_checked(index.clone()).ok().map(|r| r as *const _), expected, "{:?}", index);
+    assert_eq!(
+        v.get_checked_mut(index.clone()).ok().map(|r| r as *const _),
+        expected,
+        "{:?}",
+        index
+    );
+
+    if expected.is_some()
+    {
+        let actual = unsafe { v.get_unchecked_checked_in_debug(index.clone()) as *const _ };
+        assert_eq!(Some(actual), expected, "{:?}", index);
+        let actual = unsafe { v.get_unchecked_checked_in_debug_mut(index.clone()) as *const _ };
+        assert_eq!(Some(actual), expected, "{:?}", index);
+    }
+}
+
+/// Checks every index form built from `POINTS` against `v`.
+fn check_all<T>(v: &mut [T])
+{
+    check(v, ..);
+    for &a in POINTS
+    {
+        check(v, a);
+        check(v, ..a);
+        check(v, a..);
+        check(v, ..=a);
+
+        for &b in POINTS
+        {
+            check(v, a..b);
+            check(v, a..=b);
+        }
+    }
+}
+
+#[test]
+fn unsafe_paths_sized()
+{
+    for &len in LENS
+    {
+        check_all(&mut (0..len).map(|n| n as u8).collect::<Vec<_>>());
+        check_all(&mut (0..len).map(|n| n.to_string()).collect::<Vec<_>>());
+    }
+}
+
+#[test]
+fn unsafe_paths_zero_sized()
+{
+    for &len in LENS
+    {
+        check_all(&mut vec![(); len]);
+    }
+
+    #[cfg(feature = "edge_cases")]
+    for &len in &[isize::MAX as usize, usize::MAX]
+    {
+        // Zero-sized elements occupy no memory, so any length is valid for a dangling pointer.
+        check_all(unsafe {
+            std::slice::from_raw_parts_mut(std::ptr::NonNull::<()>::dangling().as_ptr(), len)
+        });
+    }
+}
+
+#[test]
+fn unsafe_paths_ascii()
+{
+    let v = b"ab\xffc";
+    for &a in POINTS
+    {
+        for &b in POINTS
+        {
+            let expected =
+                v.get(a..b).and_then(|s| std::str::from_utf8(s).ok()).filter(|s| s.is_ascii());
+            assert_eq!(v.get_ascii_checked(a..b).ok(), expected);
+        }
+    }
+}
+
+#[test]
+fn unsafe_paths_partial_init()
+{
+    let mut buf = [MaybeUninit::<String>::uninit(), MaybeUninit::uninit()];
+    let mut init = PartialInit::new(&mut buf);
+    assert!(init.as_slice().is_empty());
+    assert!(init.as_mut_slice().is_empty());
+
+    init.push_checked("a".to_string()).unwrap();
+    assert_eq!(init.as_slice(), ["a"]);
+    init.as_mut_slice()[0].push('b');
+    assert_eq!(init.get_checked(0).map(|s| s.as_str()), Ok("ab"));
+    assert!(init.get_checked(1).is_err());
+
+    let mut init = unsafe { PartialInit::with_init(&mut buf, 1) }.unwrap();
+    assert_eq!(init.as_mut_slice(), ["ab"]);
+    unsafe { buf[0].assume_init_drop() };
+
+    let mut zst = [MaybeUninit::<()>::uninit(); 3];
+    let mut init = PartialInit::new(&mut zst);
+    for _ in 0..3
+    {
+        init.push_checked(()).unwrap();
+    }
+    assert_eq!(init.as_slice(), [(), (), ()]);
+    assert!(init.push_checked(()).is_err());
+}
+
+#[test]
+fn unsafe_paths_uninit_range()
+{
+    let mut buf = [MaybeUninit::<String>::uninit(), MaybeUninit::uninit(), MaybeUninit::uninit()];
+    buf.write_checked(1, "a".to_string()).unwrap().push('b');
+    buf.write_checked(2, "c".to_string()).unwrap();
+    assert!(buf.write_checked(3, String::new()).is_err());
+
+    unsafe {
+        assert_eq!(buf.get_init_range_checked(1..), Ok(&["ab".to_string(), "c".to_string()][..]));
+        assert_eq!(buf.get_init_range_checked(..0), Ok(&[][..]));
+        assert!(buf.get_init_range_checked(2..4).is_err());
+        buf.get_init_range_checked_mut(2..=2).unwrap()[0].push('d');
+        assert!(buf.get_init_range_checked_mut(4..).is_err());
+        assert_eq!(buf.get_init_range_checked(2..3), Ok(&["cd".to_string()][..]));
+        buf[1].assume_init_drop();
+        buf[2].assume_init_drop();
+    }
+
+    let mut zst = [MaybeUninit::<()>::uninit(); 3];
+    zst.write_checked(0, ()).unwrap();
+    assert_eq!(unsafe { zst.get_init_range_checked_mut(..) }.map(|s| s.len()), Ok(3));
+}